@@ -8,5 +8,7 @@
 
 export * from './associatedInscriptionAccount';
 export * from './inscriptionMetadata';
+export * from './inscriptionRank';
 export * from './inscriptionShard';
+export * from './inscriptionTombstone';
 export * from './mintInscription';
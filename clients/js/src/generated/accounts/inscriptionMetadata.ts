@@ -9,7 +9,6 @@
 import {
   Account,
   Context,
-  Option,
   OptionOrNullable,
   Pda,
   PublicKey,
@@ -22,79 +21,35 @@ import {
   publicKey as toPublicKey,
 } from '@metaplex-foundation/umi';
 import {
-  Serializer,
   array,
+  bool,
   option,
   publicKey as publicKeySerializer,
   string,
-  struct,
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
-  AssociatedInscription,
+  InscriptionMetadataAccountData,
+  getInscriptionMetadataAccountDataSerializer,
+} from '../../hooked';
+import {
+  AssociatedContentTypeArgs,
   AssociatedInscriptionArgs,
-  DataType,
+  AuthorityRoleArgs,
   DataTypeArgs,
-  Key,
   KeyArgs,
+  WriteDelegateArgs,
+  getAssociatedContentTypeSerializer,
   getAssociatedInscriptionSerializer,
+  getAuthorityRoleSerializer,
   getDataTypeSerializer,
   getKeySerializer,
+  getWriteDelegateSerializer,
 } from '../types';
 
 export type InscriptionMetadata = Account<InscriptionMetadataAccountData>;
 
-export type InscriptionMetadataAccountData = {
-  key: Key;
-  inscriptionAccount: PublicKey;
-  bump: number;
-  dataType: DataType;
-  inscriptionRank: bigint;
-  inscriptionBump: Option<number>;
-  updateAuthorities: Array<PublicKey>;
-  associatedInscriptions: Array<AssociatedInscription>;
-  mint: Option<PublicKey>;
-  padding: Array<number>;
-};
-
-export type InscriptionMetadataAccountDataArgs = {
-  key: KeyArgs;
-  inscriptionAccount: PublicKey;
-  bump: number;
-  dataType: DataTypeArgs;
-  inscriptionRank: number | bigint;
-  inscriptionBump: OptionOrNullable<number>;
-  updateAuthorities: Array<PublicKey>;
-  associatedInscriptions: Array<AssociatedInscriptionArgs>;
-  mint: OptionOrNullable<PublicKey>;
-  padding: Array<number>;
-};
-
-export function getInscriptionMetadataAccountDataSerializer(): Serializer<
-  InscriptionMetadataAccountDataArgs,
-  InscriptionMetadataAccountData
-> {
-  return struct<InscriptionMetadataAccountData>(
-    [
-      ['key', getKeySerializer()],
-      ['inscriptionAccount', publicKeySerializer()],
-      ['bump', u8()],
-      ['dataType', getDataTypeSerializer()],
-      ['inscriptionRank', u64()],
-      ['inscriptionBump', option(u8())],
-      ['updateAuthorities', array(publicKeySerializer())],
-      ['associatedInscriptions', array(getAssociatedInscriptionSerializer())],
-      ['mint', option(publicKeySerializer())],
-      ['padding', array(u8(), { size: 7 })],
-    ],
-    { description: 'InscriptionMetadataAccountData' }
-  ) as Serializer<
-    InscriptionMetadataAccountDataArgs,
-    InscriptionMetadataAccountData
-  >;
-}
-
 export function deserializeInscriptionMetadata(
   rawAccount: RpcAccount
 ): InscriptionMetadata {
@@ -180,7 +135,17 @@ export function getInscriptionMetadataGpaBuilder(
       updateAuthorities: Array<PublicKey>;
       associatedInscriptions: Array<AssociatedInscriptionArgs>;
       mint: OptionOrNullable<PublicKey>;
+      frozen: boolean;
       padding: Array<number>;
+      contentHash: OptionOrNullable<Array<number>>;
+      contentLength: number | bigint;
+      authorityRoles: Array<AuthorityRoleArgs>;
+      writeDelegates: Array<WriteDelegateArgs>;
+      authorityThreshold: number;
+      mintUpdateAuthority: OptionOrNullable<PublicKey>;
+      rentPayer: OptionOrNullable<PublicKey>;
+      associatedContentTypes: Array<AssociatedContentTypeArgs>;
+      mintHolder: OptionOrNullable<PublicKey>;
     }>({
       key: [0, getKeySerializer()],
       inscriptionAccount: [1, publicKeySerializer()],
@@ -194,7 +159,20 @@ export function getInscriptionMetadataGpaBuilder(
         array(getAssociatedInscriptionSerializer()),
       ],
       mint: [null, option(publicKeySerializer())],
-      padding: [null, array(u8(), { size: 7 })],
+      frozen: [null, bool()],
+      padding: [null, array(u8(), { size: 6 })],
+      contentHash: [null, option(array(u8(), { size: 32 }))],
+      contentLength: [null, u64()],
+      authorityRoles: [null, array(getAuthorityRoleSerializer())],
+      writeDelegates: [null, array(getWriteDelegateSerializer())],
+      authorityThreshold: [null, u8()],
+      mintUpdateAuthority: [null, option(publicKeySerializer())],
+      rentPayer: [null, option(publicKeySerializer())],
+      associatedContentTypes: [
+        null,
+        array(getAssociatedContentTypeSerializer()),
+      ],
+      mintHolder: [null, option(publicKeySerializer())],
     })
     .deserializeUsing<InscriptionMetadata>((account) =>
       deserializeInscriptionMetadata(account)
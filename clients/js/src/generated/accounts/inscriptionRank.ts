@@ -0,0 +1,191 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type InscriptionRank = Account<InscriptionRankAccountData>;
+
+export type InscriptionRankAccountData = {
+  key: Key;
+  bump: number;
+  inscriptionMetadataAccount: PublicKey;
+};
+
+export type InscriptionRankAccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  inscriptionMetadataAccount: PublicKey;
+};
+
+export function getInscriptionRankAccountDataSerializer(): Serializer<
+  InscriptionRankAccountDataArgs,
+  InscriptionRankAccountData
+> {
+  return struct<InscriptionRankAccountData>(
+    [
+      ['key', getKeySerializer()],
+      ['bump', u8()],
+      ['inscriptionMetadataAccount', publicKeySerializer()],
+    ],
+    { description: 'InscriptionRankAccountData' }
+  ) as Serializer<InscriptionRankAccountDataArgs, InscriptionRankAccountData>;
+}
+
+export function deserializeInscriptionRank(
+  rawAccount: RpcAccount
+): InscriptionRank {
+  return deserializeAccount(
+    rawAccount,
+    getInscriptionRankAccountDataSerializer()
+  );
+}
+
+export async function fetchInscriptionRank(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<InscriptionRank> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'InscriptionRank');
+  return deserializeInscriptionRank(maybeAccount);
+}
+
+export async function safeFetchInscriptionRank(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<InscriptionRank | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeInscriptionRank(maybeAccount) : null;
+}
+
+export async function fetchAllInscriptionRank(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<InscriptionRank[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'InscriptionRank');
+    return deserializeInscriptionRank(maybeAccount);
+  });
+}
+
+export async function safeFetchAllInscriptionRank(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<InscriptionRank[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeInscriptionRank(maybeAccount as RpcAccount)
+    );
+}
+
+export function getInscriptionRankGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      inscriptionMetadataAccount: PublicKey;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      inscriptionMetadataAccount: [2, publicKeySerializer()],
+    })
+    .deserializeUsing<InscriptionRank>((account) =>
+      deserializeInscriptionRank(account)
+    );
+}
+
+export function getInscriptionRankSize(): number {
+  return 34;
+}
+
+export function findInscriptionRankPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    rank: number | bigint;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('Inscription'),
+    string({ size: 'variable' }).serialize('Rank'),
+    publicKeySerializer().serialize(programId),
+    u64().serialize(seeds.rank),
+  ]);
+}
+
+export async function fetchInscriptionRankFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findInscriptionRankPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<InscriptionRank> {
+  return fetchInscriptionRank(
+    context,
+    findInscriptionRankPda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchInscriptionRankFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findInscriptionRankPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<InscriptionRank | null> {
+  return safeFetchInscriptionRank(
+    context,
+    findInscriptionRankPda(context, seeds),
+    options
+  );
+}
@@ -0,0 +1,168 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type InscriptionTombstone = Account<InscriptionTombstoneAccountData>;
+
+export type InscriptionTombstoneAccountData = {
+  key: Key;
+  bump: number;
+  inscriptionAccount: PublicKey;
+  inscriptionRank: bigint;
+  closedSlot: bigint;
+  contentHash: Option<Array<number>>;
+};
+
+export type InscriptionTombstoneAccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  inscriptionAccount: PublicKey;
+  inscriptionRank: number | bigint;
+  closedSlot: number | bigint;
+  contentHash: OptionOrNullable<Array<number>>;
+};
+
+export function getInscriptionTombstoneAccountDataSerializer(): Serializer<
+  InscriptionTombstoneAccountDataArgs,
+  InscriptionTombstoneAccountData
+> {
+  return struct<InscriptionTombstoneAccountData>(
+    [
+      ['key', getKeySerializer()],
+      ['bump', u8()],
+      ['inscriptionAccount', publicKeySerializer()],
+      ['inscriptionRank', u64()],
+      ['closedSlot', u64()],
+      ['contentHash', option(array(u8(), { size: 32 }))],
+    ],
+    { description: 'InscriptionTombstoneAccountData' }
+  ) as Serializer<
+    InscriptionTombstoneAccountDataArgs,
+    InscriptionTombstoneAccountData
+  >;
+}
+
+export function deserializeInscriptionTombstone(
+  rawAccount: RpcAccount
+): InscriptionTombstone {
+  return deserializeAccount(
+    rawAccount,
+    getInscriptionTombstoneAccountDataSerializer()
+  );
+}
+
+export async function fetchInscriptionTombstone(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<InscriptionTombstone> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'InscriptionTombstone');
+  return deserializeInscriptionTombstone(maybeAccount);
+}
+
+export async function safeFetchInscriptionTombstone(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<InscriptionTombstone | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeInscriptionTombstone(maybeAccount)
+    : null;
+}
+
+export async function fetchAllInscriptionTombstone(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<InscriptionTombstone[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'InscriptionTombstone');
+    return deserializeInscriptionTombstone(maybeAccount);
+  });
+}
+
+export async function safeFetchAllInscriptionTombstone(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<InscriptionTombstone[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeInscriptionTombstone(maybeAccount as RpcAccount)
+    );
+}
+
+export function getInscriptionTombstoneGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      inscriptionAccount: PublicKey;
+      inscriptionRank: number | bigint;
+      closedSlot: number | bigint;
+      contentHash: OptionOrNullable<Array<number>>;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      inscriptionAccount: [2, publicKeySerializer()],
+      inscriptionRank: [34, u64()],
+      closedSlot: [42, u64()],
+      contentHash: [50, option(array(u8(), { size: 32 }))],
+    })
+    .deserializeUsing<InscriptionTombstone>((account) =>
+      deserializeInscriptionTombstone(account)
+    );
+}
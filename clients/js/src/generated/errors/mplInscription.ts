@@ -279,6 +279,236 @@ nameToErrorMap.set(
   InvalidInscriptionMetadataAccountError
 );
 
+/** InscriptionFrozen: The inscription is frozen and can no longer be modified. */
+export class InscriptionFrozenError extends ProgramError {
+  readonly name: string = 'InscriptionFrozen';
+
+  readonly code: number = 0x13; // 19
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The inscription is frozen and can no longer be modified.',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x13, InscriptionFrozenError);
+nameToErrorMap.set('InscriptionFrozen', InscriptionFrozenError);
+
+/** InvalidCompressionProgram: Invalid Compression Program */
+export class InvalidCompressionProgramError extends ProgramError {
+  readonly name: string = 'InvalidCompressionProgram';
+
+  readonly code: number = 0x14; // 20
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid Compression Program', program, cause);
+  }
+}
+codeToErrorMap.set(0x14, InvalidCompressionProgramError);
+nameToErrorMap.set('InvalidCompressionProgram', InvalidCompressionProgramError);
+
+/** InvalidCoreAsset: Invalid Core Asset */
+export class InvalidCoreAssetError extends ProgramError {
+  readonly name: string = 'InvalidCoreAsset';
+
+  readonly code: number = 0x15; // 21
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid Core Asset', program, cause);
+  }
+}
+codeToErrorMap.set(0x15, InvalidCoreAssetError);
+nameToErrorMap.set('InvalidCoreAsset', InvalidCoreAssetError);
+
+/** WritePreconditionFailed: The inscription data doesn't match the write precondition. */
+export class WritePreconditionFailedError extends ProgramError {
+  readonly name: string = 'WritePreconditionFailed';
+
+  readonly code: number = 0x16; // 22
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The inscription data doesn\'t match the write precondition.',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x16, WritePreconditionFailedError);
+nameToErrorMap.set('WritePreconditionFailed', WritePreconditionFailedError);
+
+/** CopyTooLarge: Can't copy more than 10 KiB of data in a single call. */
+export class CopyTooLargeError extends ProgramError {
+  readonly name: string = 'CopyTooLarge';
+
+  readonly code: number = 0x17; // 23
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Can\'t copy more than 10 KiB of data in a single call.',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17, CopyTooLargeError);
+nameToErrorMap.set('CopyTooLarge', CopyTooLargeError);
+
+/** InvalidCopyRange: The range to copy is outside of the source account's data. */
+export class InvalidCopyRangeError extends ProgramError {
+  readonly name: string = 'InvalidCopyRange';
+
+  readonly code: number = 0x18; // 24
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The range to copy is outside of the source account\'s data.',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x18, InvalidCopyRangeError);
+nameToErrorMap.set('InvalidCopyRange', InvalidCopyRangeError);
+
+/** InitialSizeTooLarge: Can't initialize an account with more than 10 KiB of data. */
+export class InitialSizeTooLargeError extends ProgramError {
+  readonly name: string = 'InitialSizeTooLarge';
+
+  readonly code: number = 0x19; // 25
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Can\'t initialize an account with more than 10 KiB of data.',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x19, InitialSizeTooLargeError);
+nameToErrorMap.set('InitialSizeTooLarge', InitialSizeTooLargeError);
+
+/** CannotRemoveLastAuthority: Removing the last authority requires explicitly allowing it. */
+export class CannotRemoveLastAuthorityError extends ProgramError {
+  readonly name: string = 'CannotRemoveLastAuthority';
+
+  readonly code: number = 0x1a; // 26
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Removing the last authority requires explicitly allowing it.',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1a, CannotRemoveLastAuthorityError);
+nameToErrorMap.set('CannotRemoveLastAuthority', CannotRemoveLastAuthorityError);
+
+/** InvalidAuthorityThreshold: The threshold can't be greater than the number of authorities. */
+export class InvalidAuthorityThresholdError extends ProgramError {
+  readonly name: string = 'InvalidAuthorityThreshold';
+
+  readonly code: number = 0x1b; // 27
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The threshold can\'t be greater than the number of authorities.',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1b, InvalidAuthorityThresholdError);
+nameToErrorMap.set('InvalidAuthorityThreshold', InvalidAuthorityThresholdError);
+
+/** AuthorityThresholdNotMet: Not enough authorities signed to meet the threshold. */
+export class AuthorityThresholdNotMetError extends ProgramError {
+  readonly name: string = 'AuthorityThresholdNotMet';
+
+  readonly code: number = 0x1c; // 28
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Not enough authorities signed to meet the threshold.',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1c, AuthorityThresholdNotMetError);
+nameToErrorMap.set('AuthorityThresholdNotMet', AuthorityThresholdNotMetError);
+
+/** InscriptionTombstoned: The inscription was closed and its address is reserved by a tombstone. */
+export class InscriptionTombstonedError extends ProgramError {
+  readonly name: string = 'InscriptionTombstoned';
+
+  readonly code: number = 0x1d; // 29
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The inscription was closed and its address is reserved by a tombstone.',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1d, InscriptionTombstonedError);
+nameToErrorMap.set('InscriptionTombstoned', InscriptionTombstonedError);
+
+/** InvalidRentPayer: Rent must be refunded to the account that paid for it. */
+export class InvalidRentPayerError extends ProgramError {
+  readonly name: string = 'InvalidRentPayer';
+
+  readonly code: number = 0x1e; // 30
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Rent must be refunded to the account that paid for it.',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1e, InvalidRentPayerError);
+nameToErrorMap.set('InvalidRentPayer', InvalidRentPayerError);
+
+/** ContentTypeTooLong: The content type can't be longer than 64 characters. */
+export class ContentTypeTooLongError extends ProgramError {
+  readonly name: string = 'ContentTypeTooLong';
+
+  readonly code: number = 0x1f; // 31
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The content type can\'t be longer than 64 characters.',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1f, ContentTypeTooLongError);
+nameToErrorMap.set('ContentTypeTooLong', ContentTypeTooLongError);
+
+/** InvalidAssetMetadata: The asset metadata does not match the compressed asset. */
+export class InvalidAssetMetadataError extends ProgramError {
+  readonly name: string = 'InvalidAssetMetadata';
+
+  readonly code: number = 0x20; // 32
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The asset metadata does not match the compressed asset.',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x20, InvalidAssetMetadataError);
+nameToErrorMap.set('InvalidAssetMetadata', InvalidAssetMetadataError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
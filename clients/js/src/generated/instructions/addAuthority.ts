@@ -26,6 +26,11 @@ import {
   ResolvedAccountsWithIndices,
   getAccountMetasAndSigners,
 } from '../shared';
+import {
+  AuthorityRole,
+  AuthorityRoleArgs,
+  getAuthorityRoleSerializer,
+} from '../types';
 
 // Accounts.
 export type AddAuthorityInstructionAccounts = {
@@ -43,9 +48,13 @@ export type AddAuthorityInstructionAccounts = {
 export type AddAuthorityInstructionData = {
   discriminator: number;
   newAuthority: PublicKey;
+  role: AuthorityRole;
 };
 
-export type AddAuthorityInstructionDataArgs = { newAuthority: PublicKey };
+export type AddAuthorityInstructionDataArgs = {
+  newAuthority: PublicKey;
+  role: AuthorityRoleArgs;
+};
 
 export function getAddAuthorityInstructionDataSerializer(): Serializer<
   AddAuthorityInstructionDataArgs,
@@ -60,6 +69,7 @@ export function getAddAuthorityInstructionDataSerializer(): Serializer<
       [
         ['discriminator', u8()],
         ['newAuthority', publicKeySerializer()],
+        ['role', getAuthorityRoleSerializer()],
       ],
       { description: 'AddAuthorityInstructionData' }
     ),
@@ -42,6 +42,8 @@ export type ClearDataInstructionAccounts = {
   authority?: Signer;
   /** System program */
   systemProgram?: PublicKey | Pda;
+  /** The account that paid the rent, if it isn't the payer. */
+  rentPayer?: PublicKey | Pda;
 };
 
 // Data.
@@ -107,6 +109,7 @@ export function clearData(
       isWritable: false,
       value: input.systemProgram ?? null,
     },
+    rentPayer: { index: 5, isWritable: true, value: input.rentPayer ?? null },
   };
 
   // Arguments.
@@ -18,6 +18,7 @@ import {
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  bool,
   mapSerializer,
   option,
   string,
@@ -42,16 +43,22 @@ export type CloseInstructionAccounts = {
   authority?: Signer;
   /** System program */
   systemProgram?: PublicKey | Pda;
+  /** The account that paid the rent, if it isn't the payer. */
+  rentPayer?: PublicKey | Pda;
+  /** The account to send the lamports to, instead of the payer. */
+  destination?: PublicKey | Pda;
 };
 
 // Data.
 export type CloseInstructionData = {
   discriminator: number;
   associatedTag: Option<string>;
+  tombstone: boolean;
 };
 
 export type CloseInstructionDataArgs = {
   associatedTag: OptionOrNullable<string>;
+  tombstone: boolean;
 };
 
 export function getCloseInstructionDataSerializer(): Serializer<
@@ -63,6 +70,7 @@ export function getCloseInstructionDataSerializer(): Serializer<
       [
         ['discriminator', u8()],
         ['associatedTag', option(string())],
+        ['tombstone', bool()],
       ],
       { description: 'CloseInstructionData' }
     ),
@@ -103,6 +111,12 @@ export function close(
       isWritable: false,
       value: input.systemProgram ?? null,
     },
+    rentPayer: { index: 5, isWritable: true, value: input.rentPayer ?? null },
+    destination: {
+      index: 6,
+      isWritable: true,
+      value: input.destination ?? null,
+    },
   };
 
   // Arguments.
@@ -0,0 +1,155 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CloseAllInstructionAccounts = {
+  /** The account where data is stored. */
+  inscriptionAccount: PublicKey | Pda;
+  /** The account to store the inscription account's metadata in. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The account that will pay for the rent. */
+  payer?: Signer;
+  /** The authority of the inscription account. */
+  authority?: Signer;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+  /** The account that paid the rent, if it isn't the payer. */
+  rentPayer?: PublicKey | Pda;
+  /** The account to send the lamports to, instead of the payer. */
+  destination?: PublicKey | Pda;
+};
+
+// Data.
+export type CloseAllInstructionData = {
+  discriminator: number;
+  tombstone: boolean;
+};
+
+export type CloseAllInstructionDataArgs = { tombstone: boolean };
+
+export function getCloseAllInstructionDataSerializer(): Serializer<
+  CloseAllInstructionDataArgs,
+  CloseAllInstructionData
+> {
+  return mapSerializer<
+    CloseAllInstructionDataArgs,
+    any,
+    CloseAllInstructionData
+  >(
+    struct<CloseAllInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['tombstone', bool()],
+      ],
+      { description: 'CloseAllInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 24 })
+  ) as Serializer<CloseAllInstructionDataArgs, CloseAllInstructionData>;
+}
+
+// Args.
+export type CloseAllInstructionArgs = CloseAllInstructionDataArgs;
+
+// Instruction.
+export function closeAll(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: CloseAllInstructionAccounts & CloseAllInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    inscriptionAccount: {
+      index: 0,
+      isWritable: true,
+      value: input.inscriptionAccount ?? null,
+    },
+    inscriptionMetadataAccount: {
+      index: 1,
+      isWritable: true,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    authority: { index: 3, isWritable: false, value: input.authority ?? null },
+    systemProgram: {
+      index: 4,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    rentPayer: { index: 5, isWritable: true, value: input.rentPayer ?? null },
+    destination: {
+      index: 6,
+      isWritable: true,
+      value: input.destination ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: CloseAllInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCloseAllInstructionDataSerializer().serialize(
+    resolvedArgs as CloseAllInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,118 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CloseStagingBufferInstructionAccounts = {
+  /** The staging buffer to discard. */
+  stagingAccount: PublicKey | Pda;
+  /** The metadata account of the inscription the data was staged for. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The account receiving the rent. */
+  payer?: Signer;
+  /** The authority that staged the data. */
+  authority?: Signer;
+};
+
+// Data.
+export type CloseStagingBufferInstructionData = { discriminator: number };
+
+export type CloseStagingBufferInstructionDataArgs = {};
+
+export function getCloseStagingBufferInstructionDataSerializer(): Serializer<
+  CloseStagingBufferInstructionDataArgs,
+  CloseStagingBufferInstructionData
+> {
+  return mapSerializer<
+    CloseStagingBufferInstructionDataArgs,
+    any,
+    CloseStagingBufferInstructionData
+  >(
+    struct<CloseStagingBufferInstructionData>([['discriminator', u8()]], {
+      description: 'CloseStagingBufferInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 27 })
+  ) as Serializer<
+    CloseStagingBufferInstructionDataArgs,
+    CloseStagingBufferInstructionData
+  >;
+}
+
+// Instruction.
+export function closeStagingBuffer(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: CloseStagingBufferInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    stagingAccount: {
+      index: 0,
+      isWritable: true,
+      value: input.stagingAccount ?? null,
+    },
+    inscriptionMetadataAccount: {
+      index: 1,
+      isWritable: false,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    authority: { index: 3, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCloseStagingBufferInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,129 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CommitHashInstructionAccounts = {
+  /** The account where data is stored. */
+  inscriptionAccount: PublicKey | Pda;
+  /** The account to store the inscription account's metadata in. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The account that will pay for the rent. */
+  payer?: Signer;
+  /** The authority of the inscription account. */
+  authority?: Signer;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CommitHashInstructionData = { discriminator: number };
+
+export type CommitHashInstructionDataArgs = {};
+
+export function getCommitHashInstructionDataSerializer(): Serializer<
+  CommitHashInstructionDataArgs,
+  CommitHashInstructionData
+> {
+  return mapSerializer<
+    CommitHashInstructionDataArgs,
+    any,
+    CommitHashInstructionData
+  >(
+    struct<CommitHashInstructionData>([['discriminator', u8()]], {
+      description: 'CommitHashInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 15 })
+  ) as Serializer<CommitHashInstructionDataArgs, CommitHashInstructionData>;
+}
+
+// Instruction.
+export function commitHash(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: CommitHashInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    inscriptionAccount: {
+      index: 0,
+      isWritable: false,
+      value: input.inscriptionAccount ?? null,
+    },
+    inscriptionMetadataAccount: {
+      index: 1,
+      isWritable: true,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    authority: { index: 3, isWritable: false, value: input.authority ?? null },
+    systemProgram: {
+      index: 4,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCommitHashInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
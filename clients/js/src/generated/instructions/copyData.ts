@@ -0,0 +1,167 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  option,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CopyDataInstructionAccounts = {
+  /** The inscription account to copy data from. */
+  sourceAccount: PublicKey | Pda;
+  /** The account where data is stored. */
+  inscriptionAccount: PublicKey | Pda;
+  /** The account to store the inscription account's metadata in. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The account that will pay for the rent. */
+  payer?: Signer;
+  /** The authority of the inscription account. */
+  authority?: Signer;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CopyDataInstructionData = {
+  discriminator: number;
+  associatedTag: Option<string>;
+  sourceOffset: bigint;
+  length: bigint;
+  offset: bigint;
+};
+
+export type CopyDataInstructionDataArgs = {
+  associatedTag: OptionOrNullable<string>;
+  sourceOffset: number | bigint;
+  length: number | bigint;
+  offset: number | bigint;
+};
+
+export function getCopyDataInstructionDataSerializer(): Serializer<
+  CopyDataInstructionDataArgs,
+  CopyDataInstructionData
+> {
+  return mapSerializer<
+    CopyDataInstructionDataArgs,
+    any,
+    CopyDataInstructionData
+  >(
+    struct<CopyDataInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['associatedTag', option(string())],
+        ['sourceOffset', u64()],
+        ['length', u64()],
+        ['offset', u64()],
+      ],
+      { description: 'CopyDataInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 17 })
+  ) as Serializer<CopyDataInstructionDataArgs, CopyDataInstructionData>;
+}
+
+// Args.
+export type CopyDataInstructionArgs = CopyDataInstructionDataArgs;
+
+// Instruction.
+export function copyData(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: CopyDataInstructionAccounts & CopyDataInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    sourceAccount: {
+      index: 0,
+      isWritable: false,
+      value: input.sourceAccount ?? null,
+    },
+    inscriptionAccount: {
+      index: 1,
+      isWritable: true,
+      value: input.inscriptionAccount ?? null,
+    },
+    inscriptionMetadataAccount: {
+      index: 2,
+      isWritable: true,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    payer: { index: 3, isWritable: true, value: input.payer ?? null },
+    authority: { index: 4, isWritable: false, value: input.authority ?? null },
+    systemProgram: {
+      index: 5,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: CopyDataInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCopyDataInstructionDataSerializer().serialize(
+    resolvedArgs as CopyDataInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,118 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type FreezeInstructionAccounts = {
+  /** The account to store the metadata's metadata in. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The account paying for the transaction and rent. */
+  payer?: Signer;
+  /** The authority of the inscription account. */
+  authority?: Signer;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type FreezeInstructionData = { discriminator: number };
+
+export type FreezeInstructionDataArgs = {};
+
+export function getFreezeInstructionDataSerializer(): Serializer<
+  FreezeInstructionDataArgs,
+  FreezeInstructionData
+> {
+  return mapSerializer<FreezeInstructionDataArgs, any, FreezeInstructionData>(
+    struct<FreezeInstructionData>([['discriminator', u8()]], {
+      description: 'FreezeInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 12 })
+  ) as Serializer<FreezeInstructionDataArgs, FreezeInstructionData>;
+}
+
+// Instruction.
+export function freeze(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: FreezeInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    inscriptionMetadataAccount: {
+      index: 0,
+      isWritable: true,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    payer: { index: 1, isWritable: true, value: input.payer ?? null },
+    authority: { index: 2, isWritable: false, value: input.authority ?? null },
+    systemProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getFreezeInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -7,11 +7,29 @@
  */
 
 export * from './addAuthority';
+export * from './addWriteDelegate';
 export * from './allocate';
 export * from './clearData';
 export * from './close';
+export * from './closeAll';
+export * from './closeStagingBuffer';
+export * from './commitHash';
+export * from './copyData';
 export * from './createShard';
+export * from './freeze';
 export * from './initializeAssociatedInscription';
+export * from './initializeFromAsset';
+export * from './initializeFromCoreAsset';
+export * from './pruneWriteDelegates';
+export * from './reclaimRent';
+export * from './recordInscriptionRank';
 export * from './removeAuthority';
+export * from './replaceAuthority';
+export * from './setAuthorityThreshold';
+export * from './setContentType';
+export * from './setDataType';
 export * from './setMint';
+export * from './syncAuthority';
 export * from './writeData';
+export * from './writeSegments';
+export * from './writeStagingBuffer';
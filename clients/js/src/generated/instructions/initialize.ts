@@ -8,6 +8,8 @@
 
 import {
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
@@ -17,7 +19,9 @@ import {
 import {
   Serializer,
   mapSerializer,
+  option,
   struct,
+  u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import { findInscriptionMetadataPda } from '../accounts';
@@ -42,12 +46,19 @@ export type InitializeInstructionAccounts = {
   authority?: Signer;
   /** System program */
   systemProgram?: PublicKey | Pda;
+  /** The account to record the inscription's rank in, for lookups by rank. */
+  inscriptionRankAccount?: PublicKey | Pda;
 };
 
 // Data.
-export type InitializeInstructionData = { discriminator: number };
+export type InitializeInstructionData = {
+  discriminator: number;
+  initialSize: Option<bigint>;
+};
 
-export type InitializeInstructionDataArgs = {};
+export type InitializeInstructionDataArgs = {
+  initialSize: OptionOrNullable<number | bigint>;
+};
 
 export function getInitializeInstructionDataSerializer(): Serializer<
   InitializeInstructionDataArgs,
@@ -58,17 +69,24 @@ export function getInitializeInstructionDataSerializer(): Serializer<
     any,
     InitializeInstructionData
   >(
-    struct<InitializeInstructionData>([['discriminator', u8()]], {
-      description: 'InitializeInstructionData',
-    }),
+    struct<InitializeInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['initialSize', option(u64())],
+      ],
+      { description: 'InitializeInstructionData' }
+    ),
     (value) => ({ ...value, discriminator: 0 })
   ) as Serializer<InitializeInstructionDataArgs, InitializeInstructionData>;
 }
 
+// Args.
+export type InitializeInstructionArgs = InitializeInstructionDataArgs;
+
 // Instruction.
 export function initialize(
   context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
-  input: InitializeInstructionAccounts
+  input: InitializeInstructionAccounts & InitializeInstructionArgs
 ): TransactionBuilder {
   // Program ID.
   const programId = context.programs.getPublicKey(
@@ -100,8 +118,16 @@ export function initialize(
       isWritable: false,
       value: input.systemProgram ?? null,
     },
+    inscriptionRankAccount: {
+      index: 6,
+      isWritable: true,
+      value: input.inscriptionRankAccount ?? null,
+    },
   };
 
+  // Arguments.
+  const resolvedArgs: InitializeInstructionArgs = { ...input };
+
   // Default values.
   if (!resolvedAccounts.inscriptionMetadataAccount.value) {
     resolvedAccounts.inscriptionMetadataAccount.value =
@@ -135,7 +161,9 @@ export function initialize(
   );
 
   // Data.
-  const data = getInitializeInstructionDataSerializer().serialize({});
+  const data = getInitializeInstructionDataSerializer().serialize(
+    resolvedArgs as InitializeInstructionDataArgs
+  );
 
   // Bytes Created On Chain.
   const bytesCreatedOnChain = 0;
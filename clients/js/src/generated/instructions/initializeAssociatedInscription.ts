@@ -8,6 +8,8 @@
 
 import {
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
@@ -17,8 +19,10 @@ import {
 import {
   Serializer,
   mapSerializer,
+  option,
   string,
   struct,
+  u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
@@ -32,6 +36,7 @@ import {
   expectSome,
   getAccountMetasAndSigners,
 } from '../shared';
+import { DataType, DataTypeArgs, getDataTypeSerializer } from '../types';
 
 // Accounts.
 export type InitializeAssociatedInscriptionInstructionAccounts = {
@@ -53,10 +58,16 @@ export type InitializeAssociatedInscriptionInstructionAccounts = {
 export type InitializeAssociatedInscriptionInstructionData = {
   discriminator: number;
   associationTag: string;
+  initialSize: Option<bigint>;
+  dataType: DataType;
+  contentType: Option<string>;
 };
 
 export type InitializeAssociatedInscriptionInstructionDataArgs = {
   associationTag: string;
+  initialSize: OptionOrNullable<number | bigint>;
+  dataType: DataTypeArgs;
+  contentType: OptionOrNullable<string>;
 };
 
 export function getInitializeAssociatedInscriptionInstructionDataSerializer(): Serializer<
@@ -72,6 +83,9 @@ export function getInitializeAssociatedInscriptionInstructionDataSerializer(): S
       [
         ['discriminator', u8()],
         ['associationTag', string()],
+        ['initialSize', option(u64())],
+        ['dataType', getDataTypeSerializer()],
+        ['contentType', option(string())],
       ],
       { description: 'InitializeAssociatedInscriptionInstructionData' }
     ),
@@ -0,0 +1,232 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InitializeFromAssetInstructionAccounts = {
+  /** The account where data is stored. */
+  assetInscriptionAccount: PublicKey | Pda;
+  /** The account to store the inscription account's metadata in. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The compressed asset id that will be used to derive the PDA. */
+  assetId: PublicKey | Pda;
+  /** The Bubblegum config account of the Merkle tree. */
+  treeConfig: PublicKey | Pda;
+  /** The Merkle tree holding the compressed asset. */
+  merkleTree: PublicKey | Pda;
+  /** The owner of the compressed asset. */
+  leafOwner: PublicKey | Pda;
+  /** The delegate of the compressed asset. */
+  leafDelegate: PublicKey | Pda;
+  /** The shard account for the inscription counter. */
+  inscriptionShardAccount: PublicKey | Pda;
+  /** The account that will pay for the rent. */
+  payer?: Signer;
+  /** The update authority of the asset, either that of its verified collection or the creator or delegate of its private tree. */
+  authority?: Signer;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+  /** SPL Account Compression program */
+  compressionProgram?: PublicKey | Pda;
+  /** The Token Metadata account of the asset's verified collection, if the authority is its update authority. */
+  collectionMetadata?: PublicKey | Pda;
+  /** The account to record the inscription's rank in, for lookups by rank. */
+  inscriptionRankAccount?: PublicKey | Pda;
+};
+
+// Data.
+export type InitializeFromAssetInstructionData = {
+  discriminator: number;
+  root: Array<number>;
+  dataHash: Array<number>;
+  creatorHash: Array<number>;
+  nonce: bigint;
+  index: number;
+  metadata: Uint8Array;
+};
+
+export type InitializeFromAssetInstructionDataArgs = {
+  root: Array<number>;
+  dataHash: Array<number>;
+  creatorHash: Array<number>;
+  nonce: number | bigint;
+  index: number;
+  metadata: Uint8Array;
+};
+
+export function getInitializeFromAssetInstructionDataSerializer(): Serializer<
+  InitializeFromAssetInstructionDataArgs,
+  InitializeFromAssetInstructionData
+> {
+  return mapSerializer<
+    InitializeFromAssetInstructionDataArgs,
+    any,
+    InitializeFromAssetInstructionData
+  >(
+    struct<InitializeFromAssetInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['root', array(u8(), { size: 32 })],
+        ['dataHash', array(u8(), { size: 32 })],
+        ['creatorHash', array(u8(), { size: 32 })],
+        ['nonce', u64()],
+        ['index', u32()],
+        ['metadata', bytes({ size: u32() })],
+      ],
+      { description: 'InitializeFromAssetInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 13 })
+  ) as Serializer<
+    InitializeFromAssetInstructionDataArgs,
+    InitializeFromAssetInstructionData
+  >;
+}
+
+// Args.
+export type InitializeFromAssetInstructionArgs =
+  InitializeFromAssetInstructionDataArgs;
+
+// Instruction.
+export function initializeFromAsset(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: InitializeFromAssetInstructionAccounts &
+    InitializeFromAssetInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    assetInscriptionAccount: {
+      index: 0,
+      isWritable: true,
+      value: input.assetInscriptionAccount ?? null,
+    },
+    inscriptionMetadataAccount: {
+      index: 1,
+      isWritable: true,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    assetId: { index: 2, isWritable: false, value: input.assetId ?? null },
+    treeConfig: {
+      index: 3,
+      isWritable: false,
+      value: input.treeConfig ?? null,
+    },
+    merkleTree: {
+      index: 4,
+      isWritable: false,
+      value: input.merkleTree ?? null,
+    },
+    leafOwner: { index: 5, isWritable: false, value: input.leafOwner ?? null },
+    leafDelegate: {
+      index: 6,
+      isWritable: false,
+      value: input.leafDelegate ?? null,
+    },
+    inscriptionShardAccount: {
+      index: 7,
+      isWritable: true,
+      value: input.inscriptionShardAccount ?? null,
+    },
+    payer: { index: 8, isWritable: true, value: input.payer ?? null },
+    authority: { index: 9, isWritable: false, value: input.authority ?? null },
+    systemProgram: {
+      index: 10,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    compressionProgram: {
+      index: 11,
+      isWritable: false,
+      value: input.compressionProgram ?? null,
+    },
+    collectionMetadata: {
+      index: 12,
+      isWritable: false,
+      value: input.collectionMetadata ?? null,
+    },
+    inscriptionRankAccount: {
+      index: 13,
+      isWritable: true,
+      value: input.inscriptionRankAccount ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: InitializeFromAssetInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'splAccountCompression',
+      'cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInitializeFromAssetInstructionDataSerializer().serialize(
+    resolvedArgs as InitializeFromAssetInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,158 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InitializeFromCoreAssetInstructionAccounts = {
+  /** The account where data is stored. */
+  mintInscriptionAccount: PublicKey | Pda;
+  /** The account to store the inscription account's metadata in. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The mpl-core asset that will be used to derive the PDA. */
+  asset: PublicKey | Pda;
+  /** The collection of the asset, if it is the update authority. */
+  collection?: PublicKey | Pda;
+  /** The shard account for the inscription counter. */
+  inscriptionShardAccount: PublicKey | Pda;
+  /** The account that will pay for the rent. */
+  payer?: Signer;
+  /** The authority of the inscription account. */
+  authority?: Signer;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+  /** The account to record the inscription's rank in, for lookups by rank. */
+  inscriptionRankAccount?: PublicKey | Pda;
+};
+
+// Data.
+export type InitializeFromCoreAssetInstructionData = { discriminator: number };
+
+export type InitializeFromCoreAssetInstructionDataArgs = {};
+
+export function getInitializeFromCoreAssetInstructionDataSerializer(): Serializer<
+  InitializeFromCoreAssetInstructionDataArgs,
+  InitializeFromCoreAssetInstructionData
+> {
+  return mapSerializer<
+    InitializeFromCoreAssetInstructionDataArgs,
+    any,
+    InitializeFromCoreAssetInstructionData
+  >(
+    struct<InitializeFromCoreAssetInstructionData>([['discriminator', u8()]], {
+      description: 'InitializeFromCoreAssetInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 14 })
+  ) as Serializer<
+    InitializeFromCoreAssetInstructionDataArgs,
+    InitializeFromCoreAssetInstructionData
+  >;
+}
+
+// Instruction.
+export function initializeFromCoreAsset(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: InitializeFromCoreAssetInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    mintInscriptionAccount: {
+      index: 0,
+      isWritable: true,
+      value: input.mintInscriptionAccount ?? null,
+    },
+    inscriptionMetadataAccount: {
+      index: 1,
+      isWritable: true,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    asset: { index: 2, isWritable: false, value: input.asset ?? null },
+    collection: {
+      index: 3,
+      isWritable: false,
+      value: input.collection ?? null,
+    },
+    inscriptionShardAccount: {
+      index: 4,
+      isWritable: true,
+      value: input.inscriptionShardAccount ?? null,
+    },
+    payer: { index: 5, isWritable: true, value: input.payer ?? null },
+    authority: { index: 6, isWritable: false, value: input.authority ?? null },
+    systemProgram: {
+      index: 7,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    inscriptionRankAccount: {
+      index: 8,
+      isWritable: true,
+      value: input.inscriptionRankAccount ?? null,
+    },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInitializeFromCoreAssetInstructionDataSerializer().serialize(
+    {}
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -36,7 +36,7 @@ export type InitializeFromMintInstructionAccounts = {
   inscriptionMetadataAccount: PublicKey | Pda;
   /** The mint that will be used to derive the PDA. */
   mintAccount: PublicKey | Pda;
-  /** The metadata for the mint. */
+  /** The metadata for the mint, or the mint itself if it uses Token-2022 metadata. */
   tokenMetadataAccount?: PublicKey | Pda;
   /** The shard account for the inscription counter. */
   inscriptionShardAccount: PublicKey | Pda;
@@ -46,6 +46,10 @@ export type InitializeFromMintInstructionAccounts = {
   authority?: Signer;
   /** System program */
   systemProgram?: PublicKey | Pda;
+  /** The token account holding the NFT, if the authority is the holder. */
+  tokenAccount?: PublicKey | Pda;
+  /** The account to record the inscription's rank in, for lookups by rank. */
+  inscriptionRankAccount?: PublicKey | Pda;
 };
 
 // Data.
@@ -117,6 +121,16 @@ export function initializeFromMint(
       isWritable: false,
       value: input.systemProgram ?? null,
     },
+    tokenAccount: {
+      index: 8,
+      isWritable: false,
+      value: input.tokenAccount ?? null,
+    },
+    inscriptionRankAccount: {
+      index: 9,
+      isWritable: true,
+      value: input.inscriptionRankAccount ?? null,
+    },
   };
 
   // Default values.
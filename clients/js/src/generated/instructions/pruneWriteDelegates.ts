@@ -0,0 +1,108 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type PruneWriteDelegatesInstructionAccounts = {
+  /** The account to store the metadata's metadata in. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The account that will receive the refunded rent. */
+  payer?: Signer;
+};
+
+// Data.
+export type PruneWriteDelegatesInstructionData = { discriminator: number };
+
+export type PruneWriteDelegatesInstructionDataArgs = {};
+
+export function getPruneWriteDelegatesInstructionDataSerializer(): Serializer<
+  PruneWriteDelegatesInstructionDataArgs,
+  PruneWriteDelegatesInstructionData
+> {
+  return mapSerializer<
+    PruneWriteDelegatesInstructionDataArgs,
+    any,
+    PruneWriteDelegatesInstructionData
+  >(
+    struct<PruneWriteDelegatesInstructionData>([['discriminator', u8()]], {
+      description: 'PruneWriteDelegatesInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 21 })
+  ) as Serializer<
+    PruneWriteDelegatesInstructionDataArgs,
+    PruneWriteDelegatesInstructionData
+  >;
+}
+
+// Instruction.
+export function pruneWriteDelegates(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: PruneWriteDelegatesInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    inscriptionMetadataAccount: {
+      index: 0,
+      isWritable: true,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    payer: { index: 1, isWritable: true, value: input.payer ?? null },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getPruneWriteDelegatesInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
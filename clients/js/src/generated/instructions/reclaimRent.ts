@@ -0,0 +1,146 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  option,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ReclaimRentInstructionAccounts = {
+  /** The account to store the inscription account's metadata in. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The inscription, associated inscription or metadata account to reclaim rent from. */
+  targetAccount: PublicKey | Pda;
+  /** The account that will pay for the transaction. */
+  payer?: Signer;
+  /** The authority of the inscription account. */
+  authority?: Signer;
+  /** The account that paid the rent, if it isn't the payer. */
+  rentPayer?: PublicKey | Pda;
+  /** The account to send the lamports to, instead of the payer. */
+  destination?: PublicKey | Pda;
+};
+
+// Data.
+export type ReclaimRentInstructionData = {
+  discriminator: number;
+  associatedTag: Option<string>;
+};
+
+export type ReclaimRentInstructionDataArgs = {
+  associatedTag: OptionOrNullable<string>;
+};
+
+export function getReclaimRentInstructionDataSerializer(): Serializer<
+  ReclaimRentInstructionDataArgs,
+  ReclaimRentInstructionData
+> {
+  return mapSerializer<
+    ReclaimRentInstructionDataArgs,
+    any,
+    ReclaimRentInstructionData
+  >(
+    struct<ReclaimRentInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['associatedTag', option(string())],
+      ],
+      { description: 'ReclaimRentInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 25 })
+  ) as Serializer<ReclaimRentInstructionDataArgs, ReclaimRentInstructionData>;
+}
+
+// Args.
+export type ReclaimRentInstructionArgs = ReclaimRentInstructionDataArgs;
+
+// Instruction.
+export function reclaimRent(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: ReclaimRentInstructionAccounts & ReclaimRentInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    inscriptionMetadataAccount: {
+      index: 0,
+      isWritable: true,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    targetAccount: {
+      index: 1,
+      isWritable: true,
+      value: input.targetAccount ?? null,
+    },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    authority: { index: 3, isWritable: false, value: input.authority ?? null },
+    rentPayer: { index: 4, isWritable: true, value: input.rentPayer ?? null },
+    destination: {
+      index: 5,
+      isWritable: true,
+      value: input.destination ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: ReclaimRentInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getReclaimRentInstructionDataSerializer().serialize(
+    resolvedArgs as ReclaimRentInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,131 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RecordInscriptionRankInstructionAccounts = {
+  /** The metadata account of the inscription. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The account to record the inscription's rank in, for lookups by rank. */
+  inscriptionRankAccount: PublicKey | Pda;
+  /** The account that will pay for the rent. */
+  payer?: Signer;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RecordInscriptionRankInstructionData = { discriminator: number };
+
+export type RecordInscriptionRankInstructionDataArgs = {};
+
+export function getRecordInscriptionRankInstructionDataSerializer(): Serializer<
+  RecordInscriptionRankInstructionDataArgs,
+  RecordInscriptionRankInstructionData
+> {
+  return mapSerializer<
+    RecordInscriptionRankInstructionDataArgs,
+    any,
+    RecordInscriptionRankInstructionData
+  >(
+    struct<RecordInscriptionRankInstructionData>([['discriminator', u8()]], {
+      description: 'RecordInscriptionRankInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 28 })
+  ) as Serializer<
+    RecordInscriptionRankInstructionDataArgs,
+    RecordInscriptionRankInstructionData
+  >;
+}
+
+// Instruction.
+export function recordInscriptionRank(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: RecordInscriptionRankInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    inscriptionMetadataAccount: {
+      index: 0,
+      isWritable: false,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    inscriptionRankAccount: {
+      index: 1,
+      isWritable: true,
+      value: input.inscriptionRankAccount ?? null,
+    },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    systemProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRecordInscriptionRankInstructionDataSerializer().serialize(
+    {}
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -8,6 +8,8 @@
 
 import {
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
@@ -16,7 +18,10 @@ import {
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  bool,
   mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
   struct,
   u8,
 } from '@metaplex-foundation/umi/serializers';
@@ -32,16 +37,23 @@ export type RemoveAuthorityInstructionAccounts = {
   inscriptionMetadataAccount: PublicKey | Pda;
   /** The account paying for the transaction and rent. */
   payer?: Signer;
-  /** The authority of the inscription account to be removed. */
+  /** The authority of the inscription account. */
   authority?: Signer;
   /** System program */
   systemProgram?: PublicKey | Pda;
 };
 
 // Data.
-export type RemoveAuthorityInstructionData = { discriminator: number };
+export type RemoveAuthorityInstructionData = {
+  discriminator: number;
+  target: Option<PublicKey>;
+  allowLastAuthority: boolean;
+};
 
-export type RemoveAuthorityInstructionDataArgs = {};
+export type RemoveAuthorityInstructionDataArgs = {
+  target: OptionOrNullable<PublicKey>;
+  allowLastAuthority: boolean;
+};
 
 export function getRemoveAuthorityInstructionDataSerializer(): Serializer<
   RemoveAuthorityInstructionDataArgs,
@@ -52,9 +64,14 @@ export function getRemoveAuthorityInstructionDataSerializer(): Serializer<
     any,
     RemoveAuthorityInstructionData
   >(
-    struct<RemoveAuthorityInstructionData>([['discriminator', u8()]], {
-      description: 'RemoveAuthorityInstructionData',
-    }),
+    struct<RemoveAuthorityInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['target', option(publicKeySerializer())],
+        ['allowLastAuthority', bool()],
+      ],
+      { description: 'RemoveAuthorityInstructionData' }
+    ),
     (value) => ({ ...value, discriminator: 6 })
   ) as Serializer<
     RemoveAuthorityInstructionDataArgs,
@@ -62,10 +79,13 @@ export function getRemoveAuthorityInstructionDataSerializer(): Serializer<
   >;
 }
 
+// Args.
+export type RemoveAuthorityInstructionArgs = RemoveAuthorityInstructionDataArgs;
+
 // Instruction.
 export function removeAuthority(
   context: Pick<Context, 'payer' | 'programs'>,
-  input: RemoveAuthorityInstructionAccounts
+  input: RemoveAuthorityInstructionAccounts & RemoveAuthorityInstructionArgs
 ): TransactionBuilder {
   // Program ID.
   const programId = context.programs.getPublicKey(
@@ -89,6 +109,9 @@ export function removeAuthority(
     },
   };
 
+  // Arguments.
+  const resolvedArgs: RemoveAuthorityInstructionArgs = { ...input };
+
   // Default values.
   if (!resolvedAccounts.payer.value) {
     resolvedAccounts.payer.value = context.payer;
@@ -114,7 +137,9 @@ export function removeAuthority(
   );
 
   // Data.
-  const data = getRemoveAuthorityInstructionDataSerializer().serialize({});
+  const data = getRemoveAuthorityInstructionDataSerializer().serialize(
+    resolvedArgs as RemoveAuthorityInstructionDataArgs
+  );
 
   // Bytes Created On Chain.
   const bytesCreatedOnChain = 0;
@@ -0,0 +1,150 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ReplaceAuthorityInstructionAccounts = {
+  /** The account to store the metadata's metadata in. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The account paying for the transaction and rent. */
+  payer?: Signer;
+  /** The authority of the inscription account. */
+  authority?: Signer;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ReplaceAuthorityInstructionData = {
+  discriminator: number;
+  target: Option<PublicKey>;
+  newAuthority: PublicKey;
+};
+
+export type ReplaceAuthorityInstructionDataArgs = {
+  target: OptionOrNullable<PublicKey>;
+  newAuthority: PublicKey;
+};
+
+export function getReplaceAuthorityInstructionDataSerializer(): Serializer<
+  ReplaceAuthorityInstructionDataArgs,
+  ReplaceAuthorityInstructionData
+> {
+  return mapSerializer<
+    ReplaceAuthorityInstructionDataArgs,
+    any,
+    ReplaceAuthorityInstructionData
+  >(
+    struct<ReplaceAuthorityInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['target', option(publicKeySerializer())],
+        ['newAuthority', publicKeySerializer()],
+      ],
+      { description: 'ReplaceAuthorityInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 19 })
+  ) as Serializer<
+    ReplaceAuthorityInstructionDataArgs,
+    ReplaceAuthorityInstructionData
+  >;
+}
+
+// Args.
+export type ReplaceAuthorityInstructionArgs =
+  ReplaceAuthorityInstructionDataArgs;
+
+// Instruction.
+export function replaceAuthority(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: ReplaceAuthorityInstructionAccounts & ReplaceAuthorityInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    inscriptionMetadataAccount: {
+      index: 0,
+      isWritable: true,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    payer: { index: 1, isWritable: true, value: input.payer ?? null },
+    authority: { index: 2, isWritable: false, value: input.authority ?? null },
+    systemProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: ReplaceAuthorityInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getReplaceAuthorityInstructionDataSerializer().serialize(
+    resolvedArgs as ReplaceAuthorityInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
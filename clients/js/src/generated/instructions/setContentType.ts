@@ -0,0 +1,168 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  option,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAssociatedInscriptionAccountPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  expectSome,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetContentTypeInstructionAccounts = {
+  /** The associated inscription account to update. */
+  associatedInscriptionAccount?: PublicKey | Pda;
+  /** The account to store the inscription account's metadata in. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The account that will pay for the rent. */
+  payer?: Signer;
+  /** The authority of the inscription account. */
+  authority?: Signer;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetContentTypeInstructionData = {
+  discriminator: number;
+  associationTag: string;
+  contentType: Option<string>;
+};
+
+export type SetContentTypeInstructionDataArgs = {
+  associationTag: string;
+  contentType: OptionOrNullable<string>;
+};
+
+export function getSetContentTypeInstructionDataSerializer(): Serializer<
+  SetContentTypeInstructionDataArgs,
+  SetContentTypeInstructionData
+> {
+  return mapSerializer<
+    SetContentTypeInstructionDataArgs,
+    any,
+    SetContentTypeInstructionData
+  >(
+    struct<SetContentTypeInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['associationTag', string()],
+        ['contentType', option(string())],
+      ],
+      { description: 'SetContentTypeInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 26 })
+  ) as Serializer<
+    SetContentTypeInstructionDataArgs,
+    SetContentTypeInstructionData
+  >;
+}
+
+// Args.
+export type SetContentTypeInstructionArgs = SetContentTypeInstructionDataArgs;
+
+// Instruction.
+export function setContentType(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: SetContentTypeInstructionAccounts & SetContentTypeInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    associatedInscriptionAccount: {
+      index: 0,
+      isWritable: false,
+      value: input.associatedInscriptionAccount ?? null,
+    },
+    inscriptionMetadataAccount: {
+      index: 1,
+      isWritable: true,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    authority: { index: 3, isWritable: false, value: input.authority ?? null },
+    systemProgram: {
+      index: 4,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetContentTypeInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.associatedInscriptionAccount.value) {
+    resolvedAccounts.associatedInscriptionAccount.value =
+      findAssociatedInscriptionAccountPda(context, {
+        associationTag: expectSome(resolvedArgs.associationTag),
+        inscriptionMetadataAccount: expectPublicKey(
+          resolvedAccounts.inscriptionMetadataAccount.value
+        ),
+      });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetContentTypeInstructionDataSerializer().serialize(
+    resolvedArgs as SetContentTypeInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
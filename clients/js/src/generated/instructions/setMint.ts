@@ -6,6 +6,7 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+import { findMetadataPda } from '@metaplex-foundation/mpl-token-metadata';
 import {
   Context,
   Pda,
@@ -16,6 +17,7 @@ import {
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  bool,
   mapSerializer,
   struct,
   u8,
@@ -23,6 +25,7 @@ import {
 import {
   ResolvedAccount,
   ResolvedAccountsWithIndices,
+  expectPublicKey,
   getAccountMetasAndSigners,
 } from '../shared';
 
@@ -38,29 +41,43 @@ export type SetMintInstructionAccounts = {
   payer?: Signer;
   /** System program */
   systemProgram?: PublicKey | Pda;
+  /** The authority of the inscription account or the update authority of the mint. */
+  authority?: Signer;
+  /** The metadata for the mint, or the mint itself if it uses Token-2022 metadata, if the authority is its update authority. */
+  tokenMetadataAccount?: PublicKey | Pda;
 };
 
 // Data.
-export type SetMintInstructionData = { discriminator: number };
+export type SetMintInstructionData = {
+  discriminator: number;
+  backfillInscriptionBump: boolean;
+};
 
-export type SetMintInstructionDataArgs = {};
+export type SetMintInstructionDataArgs = { backfillInscriptionBump: boolean };
 
 export function getSetMintInstructionDataSerializer(): Serializer<
   SetMintInstructionDataArgs,
   SetMintInstructionData
 > {
   return mapSerializer<SetMintInstructionDataArgs, any, SetMintInstructionData>(
-    struct<SetMintInstructionData>([['discriminator', u8()]], {
-      description: 'SetMintInstructionData',
-    }),
+    struct<SetMintInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['backfillInscriptionBump', bool()],
+      ],
+      { description: 'SetMintInstructionData' }
+    ),
     (value) => ({ ...value, discriminator: 10 })
   ) as Serializer<SetMintInstructionDataArgs, SetMintInstructionData>;
 }
 
+// Args.
+export type SetMintInstructionArgs = SetMintInstructionDataArgs;
+
 // Instruction.
 export function setMint(
-  context: Pick<Context, 'payer' | 'programs'>,
-  input: SetMintInstructionAccounts
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: SetMintInstructionAccounts & SetMintInstructionArgs
 ): TransactionBuilder {
   // Program ID.
   const programId = context.programs.getPublicKey(
@@ -91,8 +108,17 @@ export function setMint(
       isWritable: false,
       value: input.systemProgram ?? null,
     },
+    authority: { index: 5, isWritable: false, value: input.authority ?? null },
+    tokenMetadataAccount: {
+      index: 6,
+      isWritable: false,
+      value: input.tokenMetadataAccount ?? null,
+    },
   };
 
+  // Arguments.
+  const resolvedArgs: SetMintInstructionArgs = { ...input };
+
   // Default values.
   if (!resolvedAccounts.payer.value) {
     resolvedAccounts.payer.value = context.payer;
@@ -104,6 +130,11 @@ export function setMint(
     );
     resolvedAccounts.systemProgram.isWritable = false;
   }
+  if (!resolvedAccounts.tokenMetadataAccount.value) {
+    resolvedAccounts.tokenMetadataAccount.value = findMetadataPda(context, {
+      mint: expectPublicKey(resolvedAccounts.mintAccount.value),
+    });
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
@@ -118,7 +149,9 @@ export function setMint(
   );
 
   // Data.
-  const data = getSetMintInstructionDataSerializer().serialize({});
+  const data = getSetMintInstructionDataSerializer().serialize(
+    resolvedArgs as SetMintInstructionDataArgs
+  );
 
   // Bytes Created On Chain.
   const bytesCreatedOnChain = 0;
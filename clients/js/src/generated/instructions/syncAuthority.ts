@@ -0,0 +1,153 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findMetadataPda } from '@metaplex-foundation/mpl-token-metadata';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SyncAuthorityInstructionAccounts = {
+  /** The account to store the inscription account's metadata in. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The mint the inscription was created from. */
+  mintAccount: PublicKey | Pda;
+  /** The metadata for the mint, or the mint itself if it uses Token-2022 metadata. */
+  tokenMetadataAccount?: PublicKey | Pda;
+  /** The token account holding the NFT, to sync the holder of a holder inscription. */
+  tokenAccount?: PublicKey | Pda;
+  /** The account that will pay for the rent, if older metadata accounts need to grow. */
+  payer?: Signer;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+  /** An Admin of an older inscription handing its place over to the current update authority of the mint. */
+  authority?: Signer;
+};
+
+// Data.
+export type SyncAuthorityInstructionData = { discriminator: number };
+
+export type SyncAuthorityInstructionDataArgs = {};
+
+export function getSyncAuthorityInstructionDataSerializer(): Serializer<
+  SyncAuthorityInstructionDataArgs,
+  SyncAuthorityInstructionData
+> {
+  return mapSerializer<
+    SyncAuthorityInstructionDataArgs,
+    any,
+    SyncAuthorityInstructionData
+  >(
+    struct<SyncAuthorityInstructionData>([['discriminator', u8()]], {
+      description: 'SyncAuthorityInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 23 })
+  ) as Serializer<
+    SyncAuthorityInstructionDataArgs,
+    SyncAuthorityInstructionData
+  >;
+}
+
+// Instruction.
+export function syncAuthority(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: SyncAuthorityInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    inscriptionMetadataAccount: {
+      index: 0,
+      isWritable: true,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    mintAccount: {
+      index: 1,
+      isWritable: false,
+      value: input.mintAccount ?? null,
+    },
+    tokenMetadataAccount: {
+      index: 2,
+      isWritable: false,
+      value: input.tokenMetadataAccount ?? null,
+    },
+    tokenAccount: {
+      index: 3,
+      isWritable: false,
+      value: input.tokenAccount ?? null,
+    },
+    payer: { index: 4, isWritable: true, value: input.payer ?? null },
+    systemProgram: {
+      index: 5,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    authority: { index: 6, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.tokenMetadataAccount.value) {
+    resolvedAccounts.tokenMetadataAccount.value = findMetadataPda(context, {
+      mint: expectPublicKey(resolvedAccounts.mintAccount.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSyncAuthorityInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
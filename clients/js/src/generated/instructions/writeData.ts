@@ -32,6 +32,11 @@ import {
   ResolvedAccountsWithIndices,
   getAccountMetasAndSigners,
 } from '../shared';
+import {
+  WritePrecondition,
+  WritePreconditionArgs,
+  getWritePreconditionSerializer,
+} from '../types';
 
 // Accounts.
 export type WriteDataInstructionAccounts = {
@@ -45,6 +50,8 @@ export type WriteDataInstructionAccounts = {
   authority?: Signer;
   /** System program */
   systemProgram?: PublicKey | Pda;
+  /** The staging buffer to take the data from, in place of the value. */
+  stagingAccount?: PublicKey | Pda;
 };
 
 // Data.
@@ -53,12 +60,14 @@ export type WriteDataInstructionData = {
   associatedTag: Option<string>;
   offset: bigint;
   value: Uint8Array;
+  precondition: Option<WritePrecondition>;
 };
 
 export type WriteDataInstructionDataArgs = {
   associatedTag: OptionOrNullable<string>;
   offset: number | bigint;
   value: Uint8Array;
+  precondition: OptionOrNullable<WritePreconditionArgs>;
 };
 
 export function getWriteDataInstructionDataSerializer(): Serializer<
@@ -76,6 +85,7 @@ export function getWriteDataInstructionDataSerializer(): Serializer<
         ['associatedTag', option(string())],
         ['offset', u64()],
         ['value', bytes({ size: u32() })],
+        ['precondition', option(getWritePreconditionSerializer())],
       ],
       { description: 'WriteDataInstructionData' }
     ),
@@ -116,6 +126,11 @@ export function writeData(
       isWritable: false,
       value: input.systemProgram ?? null,
     },
+    stagingAccount: {
+      index: 5,
+      isWritable: true,
+      value: input.stagingAccount ?? null,
+    },
   };
 
   // Arguments.
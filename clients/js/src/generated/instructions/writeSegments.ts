@@ -0,0 +1,148 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  WriteSegment,
+  WriteSegmentArgs,
+  getWriteSegmentSerializer,
+} from '../types';
+
+// Accounts.
+export type WriteSegmentsInstructionAccounts = {
+  /** The account to store the inscription account's metadata in. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The account that will pay for the rent. */
+  payer?: Signer;
+  /** The authority of the inscription account. */
+  authority?: Signer;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type WriteSegmentsInstructionData = {
+  discriminator: number;
+  segments: Array<WriteSegment>;
+};
+
+export type WriteSegmentsInstructionDataArgs = {
+  segments: Array<WriteSegmentArgs>;
+};
+
+export function getWriteSegmentsInstructionDataSerializer(): Serializer<
+  WriteSegmentsInstructionDataArgs,
+  WriteSegmentsInstructionData
+> {
+  return mapSerializer<
+    WriteSegmentsInstructionDataArgs,
+    any,
+    WriteSegmentsInstructionData
+  >(
+    struct<WriteSegmentsInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['segments', array(getWriteSegmentSerializer())],
+      ],
+      { description: 'WriteSegmentsInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 16 })
+  ) as Serializer<
+    WriteSegmentsInstructionDataArgs,
+    WriteSegmentsInstructionData
+  >;
+}
+
+// Args.
+export type WriteSegmentsInstructionArgs = WriteSegmentsInstructionDataArgs;
+
+// Instruction.
+export function writeSegments(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: WriteSegmentsInstructionAccounts & WriteSegmentsInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    inscriptionMetadataAccount: {
+      index: 0,
+      isWritable: true,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    payer: { index: 1, isWritable: true, value: input.payer ?? null },
+    authority: { index: 2, isWritable: false, value: input.authority ?? null },
+    systemProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: WriteSegmentsInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getWriteSegmentsInstructionDataSerializer().serialize(
+    resolvedArgs as WriteSegmentsInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,157 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type WriteStagingBufferInstructionAccounts = {
+  /** The staging buffer where data is stored. */
+  stagingAccount: PublicKey | Pda;
+  /** The metadata account of the inscription the data is staged for. */
+  inscriptionMetadataAccount: PublicKey | Pda;
+  /** The account that will pay for the rent. */
+  payer?: Signer;
+  /** The authority of the inscription account. */
+  authority?: Signer;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type WriteStagingBufferInstructionData = {
+  discriminator: number;
+  offset: bigint;
+  value: Uint8Array;
+};
+
+export type WriteStagingBufferInstructionDataArgs = {
+  offset: number | bigint;
+  value: Uint8Array;
+};
+
+export function getWriteStagingBufferInstructionDataSerializer(): Serializer<
+  WriteStagingBufferInstructionDataArgs,
+  WriteStagingBufferInstructionData
+> {
+  return mapSerializer<
+    WriteStagingBufferInstructionDataArgs,
+    any,
+    WriteStagingBufferInstructionData
+  >(
+    struct<WriteStagingBufferInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['offset', u64()],
+        ['value', bytes({ size: u32() })],
+      ],
+      { description: 'WriteStagingBufferInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 18 })
+  ) as Serializer<
+    WriteStagingBufferInstructionDataArgs,
+    WriteStagingBufferInstructionData
+  >;
+}
+
+// Args.
+export type WriteStagingBufferInstructionArgs =
+  WriteStagingBufferInstructionDataArgs;
+
+// Instruction.
+export function writeStagingBuffer(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: WriteStagingBufferInstructionAccounts &
+    WriteStagingBufferInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    stagingAccount: {
+      index: 0,
+      isWritable: true,
+      value: input.stagingAccount ?? null,
+    },
+    inscriptionMetadataAccount: {
+      index: 1,
+      isWritable: false,
+      value: input.inscriptionMetadataAccount ?? null,
+    },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    authority: { index: 3, isWritable: false, value: input.authority ?? null },
+    systemProgram: {
+      index: 4,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: WriteStagingBufferInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getWriteStagingBufferInstructionDataSerializer().serialize(
+    resolvedArgs as WriteStagingBufferInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
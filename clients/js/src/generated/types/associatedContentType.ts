@@ -0,0 +1,30 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type AssociatedContentType = { tag: string; contentType: string };
+
+export type AssociatedContentTypeArgs = AssociatedContentType;
+
+export function getAssociatedContentTypeSerializer(): Serializer<
+  AssociatedContentTypeArgs,
+  AssociatedContentType
+> {
+  return struct<AssociatedContentType>(
+    [
+      ['tag', string()],
+      ['contentType', string()],
+    ],
+    { description: 'AssociatedContentType' }
+  ) as Serializer<AssociatedContentTypeArgs, AssociatedContentType>;
+}
@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum AuthorityRole {
+  Admin,
+  Writer,
+  Tagger,
+}
+
+export type AuthorityRoleArgs = AuthorityRole;
+
+export function getAuthorityRoleSerializer(): Serializer<
+  AuthorityRoleArgs,
+  AuthorityRole
+> {
+  return scalarEnum<AuthorityRole>(AuthorityRole, {
+    description: 'AuthorityRole',
+  }) as Serializer<AuthorityRoleArgs, AuthorityRole>;
+}
@@ -6,6 +6,11 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './associatedContentType';
 export * from './associatedInscription';
+export * from './authorityRole';
 export * from './dataType';
 export * from './key';
+export * from './writeDelegate';
+export * from './writePrecondition';
+export * from './writeSegment';
@@ -13,6 +13,9 @@ export enum Key {
   InscriptionMetadataAccount,
   MintInscriptionMetadataAccount,
   InscriptionShardAccount,
+  InscriptionRankAccount,
+  InscriptionTombstoneAccount,
+  StagingBufferAccount,
 }
 
 export type KeyArgs = Key;
@@ -0,0 +1,38 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type WriteDelegate = {
+  address: PublicKey;
+  expiresAt: bigint;
+};
+
+export type WriteDelegateArgs = {
+  address: PublicKey;
+  expiresAt: number | bigint;
+};
+
+export function getWriteDelegateSerializer(): Serializer<
+  WriteDelegateArgs,
+  WriteDelegate
+> {
+  return struct<WriteDelegate>(
+    [
+      ['address', publicKeySerializer()],
+      ['expiresAt', i64()],
+    ],
+    { description: 'WriteDelegate' }
+  ) as Serializer<WriteDelegateArgs, WriteDelegate>;
+}
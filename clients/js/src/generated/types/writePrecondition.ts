@@ -0,0 +1,40 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  option,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type WritePrecondition = {
+  expectedLength: Option<bigint>;
+  expectedHash: Option<Array<number>>;
+};
+
+export type WritePreconditionArgs = {
+  expectedLength: OptionOrNullable<number | bigint>;
+  expectedHash: OptionOrNullable<Array<number>>;
+};
+
+export function getWritePreconditionSerializer(): Serializer<
+  WritePreconditionArgs,
+  WritePrecondition
+> {
+  return struct<WritePrecondition>(
+    [
+      ['expectedLength', option(u64())],
+      ['expectedHash', option(array(u8(), { size: 32 }))],
+    ],
+    { description: 'WritePrecondition' }
+  ) as Serializer<WritePreconditionArgs, WritePrecondition>;
+}
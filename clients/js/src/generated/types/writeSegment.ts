@@ -0,0 +1,44 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  option,
+  string,
+  struct,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type WriteSegment = {
+  associatedTag: Option<string>;
+  offset: bigint;
+  value: Uint8Array;
+};
+
+export type WriteSegmentArgs = {
+  associatedTag: OptionOrNullable<string>;
+  offset: number | bigint;
+  value: Uint8Array;
+};
+
+export function getWriteSegmentSerializer(): Serializer<
+  WriteSegmentArgs,
+  WriteSegment
+> {
+  return struct<WriteSegment>(
+    [
+      ['associatedTag', option(string())],
+      ['offset', u64()],
+      ['value', bytes({ size: u32() })],
+    ],
+    { description: 'WriteSegment' }
+  ) as Serializer<WriteSegmentArgs, WriteSegment>;
+}
@@ -0,0 +1,174 @@
+import {
+  Option,
+  OptionOrNullable,
+  PublicKey,
+  none,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  AssociatedContentType,
+  AssociatedContentTypeArgs,
+  AssociatedInscription,
+  AssociatedInscriptionArgs,
+  AuthorityRole,
+  AuthorityRoleArgs,
+  DataType,
+  DataTypeArgs,
+  Key,
+  KeyArgs,
+  WriteDelegate,
+  WriteDelegateArgs,
+  getAssociatedContentTypeSerializer,
+  getAssociatedInscriptionSerializer,
+  getAuthorityRoleSerializer,
+  getDataTypeSerializer,
+  getKeySerializer,
+  getWriteDelegateSerializer,
+} from '../generated/types';
+
+export type InscriptionMetadataAccountData = {
+  key: Key;
+  inscriptionAccount: PublicKey;
+  bump: number;
+  dataType: DataType;
+  inscriptionRank: bigint;
+  inscriptionBump: Option<number>;
+  updateAuthorities: Array<PublicKey>;
+  associatedInscriptions: Array<AssociatedInscription>;
+  mint: Option<PublicKey>;
+  frozen: boolean;
+  padding: Array<number>;
+  contentHash: Option<Array<number>>;
+  contentLength: bigint;
+  authorityRoles: Array<AuthorityRole>;
+  writeDelegates: Array<WriteDelegate>;
+  authorityThreshold: number;
+  mintUpdateAuthority: Option<PublicKey>;
+  rentPayer: Option<PublicKey>;
+  associatedContentTypes: Array<AssociatedContentType>;
+  mintHolder: Option<PublicKey>;
+};
+
+export type InscriptionMetadataAccountDataArgs = {
+  key: KeyArgs;
+  inscriptionAccount: PublicKey;
+  bump: number;
+  dataType: DataTypeArgs;
+  inscriptionRank: number | bigint;
+  inscriptionBump: OptionOrNullable<number>;
+  updateAuthorities: Array<PublicKey>;
+  associatedInscriptions: Array<AssociatedInscriptionArgs>;
+  mint: OptionOrNullable<PublicKey>;
+  frozen: boolean;
+  padding: Array<number>;
+  contentHash: OptionOrNullable<Array<number>>;
+  contentLength: number | bigint;
+  authorityRoles: Array<AuthorityRoleArgs>;
+  writeDelegates: Array<WriteDelegateArgs>;
+  authorityThreshold: number;
+  mintUpdateAuthority: OptionOrNullable<PublicKey>;
+  rentPayer: OptionOrNullable<PublicKey>;
+  associatedContentTypes: Array<AssociatedContentTypeArgs>;
+  mintHolder: OptionOrNullable<PublicKey>;
+};
+
+type Field = [keyof InscriptionMetadataAccountData, Serializer<any, any>];
+
+/** The fields of the original layout that every account has. */
+function getOriginalFields(): Field[] {
+  return [
+    ['key', getKeySerializer()],
+    ['inscriptionAccount', publicKeySerializer()],
+    ['bump', u8()],
+    ['dataType', getDataTypeSerializer()],
+    ['inscriptionRank', u64()],
+    ['inscriptionBump', option(u8())],
+    ['updateAuthorities', array(publicKeySerializer())],
+    ['associatedInscriptions', array(getAssociatedInscriptionSerializer())],
+    ['mint', option(publicKeySerializer())],
+    ['frozen', bool()],
+    ['padding', array(u8(), { size: 6 })],
+  ];
+}
+
+/** The fields appended to the layout, with the values older accounts default to. */
+function getAppendedFields(): [...Field, () => unknown][] {
+  return [
+    ['contentHash', option(array(u8(), { size: 32 })), () => none()],
+    ['contentLength', u64(), () => BigInt(0)],
+    ['authorityRoles', array(getAuthorityRoleSerializer()), () => []],
+    ['writeDelegates', array(getWriteDelegateSerializer()), () => []],
+    ['authorityThreshold', u8(), () => 0],
+    ['mintUpdateAuthority', option(publicKeySerializer()), () => none()],
+    ['rentPayer', option(publicKeySerializer()), () => none()],
+    [
+      'associatedContentTypes',
+      array(getAssociatedContentTypeSerializer()),
+      () => [],
+    ],
+    ['mintHolder', option(publicKeySerializer()), () => none()],
+  ];
+}
+
+export function getInscriptionMetadataAccountDataSerializer(): Serializer<
+  InscriptionMetadataAccountDataArgs,
+  InscriptionMetadataAccountData
+> {
+  const originalFields = getOriginalFields();
+  const appendedFields = getAppendedFields();
+  const serializer = struct<any>(
+    [
+      ...originalFields,
+      ...appendedFields.map(([name, fieldSerializer]) => [
+        name,
+        fieldSerializer,
+      ]),
+    ] as any,
+    { description: 'InscriptionMetadataAccountData' }
+  );
+  const originalSerializer = struct<any>(originalFields as any);
+
+  return {
+    ...serializer,
+    deserialize: (bytes: Uint8Array, offset = 0) => {
+      const [data, originalOffset] = originalSerializer.deserialize(
+        bytes,
+        offset
+      );
+
+      // Older accounts end before the fields that were appended to the layout.
+      let position = originalOffset;
+      appendedFields.forEach(([name, fieldSerializer, getDefault]) => {
+        if (position >= bytes.length) {
+          data[name] = getDefault();
+        } else {
+          [data[name], position] = fieldSerializer.deserialize(bytes, position);
+        }
+      });
+
+      // Authorities from before roles existed are Admins.
+      const missingRoles =
+        data.updateAuthorities.length - data.authorityRoles.length;
+      if (missingRoles > 0) {
+        data.authorityRoles = [
+          ...data.authorityRoles,
+          ...Array(missingRoles).fill(AuthorityRole.Admin),
+        ];
+      }
+
+      return [data as InscriptionMetadataAccountData, position];
+    },
+  } as Serializer<
+    InscriptionMetadataAccountDataArgs,
+    InscriptionMetadataAccountData
+  >;
+}
@@ -1,6 +1,9 @@
 export * from './generated';
+export * from './hooked';
 export * from './plugin';
 export * from './associatedInscription';
 export * from './initialize';
 export * from './initializeFromMint';
 export * from './inscription';
+export * from './inscriptionRank';
+export * from './stagingBuffer';
@@ -1,10 +1,13 @@
 /**
  * This code eclipses the initialize function in clients/js/src/generated/instructions/initialize.ts.
  * It is used to hide the shard retrieval logic from the user, and instead randomly select a shard.
+ * It also records the rank of the inscription, unless the rank account is set to null.
  */
 
 import {
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
@@ -12,6 +15,7 @@ import {
 } from '@metaplex-foundation/umi';
 import { initialize as hiddenInitialize } from './generated/instructions/initialize';
 import { findInscriptionShardPda } from './generated';
+import { findNextInscriptionRankPda } from './inscriptionRank';
 
 // Accounts.
 export type InitializeInstructionAccounts = {
@@ -27,21 +31,28 @@ export type InitializeInstructionAccounts = {
   authority?: Signer;
   /** System program */
   systemProgram?: PublicKey | Pda;
+  /** The account to record the inscription's rank in, for lookups by rank, or null to skip it. */
+  inscriptionRankAccount?: PublicKey | Pda | null;
 };
 
 // Data.
-export type InitializeInstructionData = { discriminator: number };
+export type InitializeInstructionData = {
+  discriminator: number;
+  initialSize: Option<bigint>;
+};
 
 export type InitializeInstructionDataArgs = {
   /** The number of the shard to use. */
   shard?: number;
+  /** The number of bytes to allocate, unless the inscription account was created beforehand. */
+  initialSize?: OptionOrNullable<number | bigint>;
 };
 
 // Instruction.
-export function initialize(
-  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+export async function initialize(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs' | 'rpc'>,
   input: InitializeInstructionAccounts & InitializeInstructionDataArgs
-): TransactionBuilder {
+): Promise<TransactionBuilder> {
   let inscriptionShardAccount;
   if (input.inscriptionShardAccount) {
     inscriptionShardAccount = input.inscriptionShardAccount;
@@ -54,5 +65,16 @@ export function initialize(
     }
     inscriptionShardAccount = findInscriptionShardPda(context, { shardNumber });
   }
-  return hiddenInitialize(context, { inscriptionShardAccount, ...input });
+
+  const inscriptionRankAccount =
+    input.inscriptionRankAccount === undefined
+      ? await findNextInscriptionRankPda(context, inscriptionShardAccount)
+      : input.inscriptionRankAccount ?? undefined;
+
+  return hiddenInitialize(context, {
+    inscriptionShardAccount,
+    ...input,
+    inscriptionRankAccount,
+    initialSize: input.initialSize ?? null,
+  });
 }
@@ -1,6 +1,7 @@
 /**
  * This code eclipses the initializeFromMint function in clients/js/src/generated/instructions/initializeFromMint.ts.
  * It is used to hide the shard retrieval logic from the user, and instead randomly select a shard.
+ * It also records the rank of the inscription, unless the rank account is set to null.
  */
 
 import {
@@ -17,6 +18,7 @@ import {
   findInscriptionMetadataPda,
   findMintInscriptionPda,
 } from './generated';
+import { findNextInscriptionRankPda } from './inscriptionRank';
 
 // Accounts.
 export type InitializeFromMintInstructionAccounts = {
@@ -26,7 +28,7 @@ export type InitializeFromMintInstructionAccounts = {
   inscriptionMetadataAccount?: PublicKey | Pda;
   /** The mint that will be used to derive the PDA. */
   mintAccount: PublicKey | Pda;
-  /** The metadata for the mint. */
+  /** The metadata for the mint, or the mint itself if it uses Token-2022 metadata. */
   tokenMetadataAccount?: PublicKey | Pda;
   /** The shard account for the inscription counter. */
   inscriptionShardAccount?: PublicKey | Pda;
@@ -36,6 +38,10 @@ export type InitializeFromMintInstructionAccounts = {
   authority?: Signer;
   /** System program */
   systemProgram?: PublicKey | Pda;
+  /** The token account holding the NFT, if the authority is the holder. */
+  tokenAccount?: PublicKey | Pda;
+  /** The account to record the inscription's rank in, for lookups by rank, or null to skip it. */
+  inscriptionRankAccount?: PublicKey | Pda | null;
 };
 
 // Data.
@@ -47,11 +53,11 @@ export type InitializeFromMintInstructionDataArgs = {
 };
 
 // Instruction.
-export function initializeFromMint(
-  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+export async function initializeFromMint(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs' | 'rpc'>,
   input: InitializeFromMintInstructionAccounts &
     InitializeFromMintInstructionDataArgs
-): TransactionBuilder {
+): Promise<TransactionBuilder> {
   let inscriptionAccount;
   if (input.mintInscriptionAccount) {
     inscriptionAccount = input.mintInscriptionAccount;
@@ -82,6 +88,12 @@ export function initializeFromMint(
     }
     inscriptionShardAccount = findInscriptionShardPda(context, { shardNumber });
   }
+
+  const inscriptionRankAccount =
+    input.inscriptionRankAccount === undefined
+      ? await findNextInscriptionRankPda(context, inscriptionShardAccount)
+      : input.inscriptionRankAccount ?? undefined;
+
   return hiddenInitialize(context, {
     mintInscriptionAccount: inscriptionAccount,
     inscriptionMetadataAccount,
@@ -91,5 +103,7 @@ export function initializeFromMint(
     payer: input.payer,
     authority: input.authority,
     systemProgram: input.systemProgram,
+    tokenAccount: input.tokenAccount,
+    inscriptionRankAccount,
   });
 }
@@ -0,0 +1,19 @@
+import { Context, Pda, PublicKey } from '@metaplex-foundation/umi';
+import { fetchInscriptionShard, findInscriptionRankPda } from './generated';
+
+/**
+ * Find the rank account of the next inscription created with the shard, from its current count.
+ * The shard can move on before a transaction using it lands, in which case that transaction fails.
+ */
+export async function findNextInscriptionRankPda(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  inscriptionShardAccount: PublicKey | Pda
+): Promise<Pda> {
+  const { count, shardNumber } = await fetchInscriptionShard(
+    context,
+    inscriptionShardAccount
+  );
+  return findInscriptionRankPda(context, {
+    rank: count * BigInt(32) + BigInt(shardNumber),
+  });
+}
@@ -0,0 +1,24 @@
+import { Context, Pda, PublicKey } from '@metaplex-foundation/umi';
+import {
+  string,
+  publicKey as publicKeySerializer,
+} from '@metaplex-foundation/umi/serializers';
+
+export function findStagingBufferPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    inscriptionMetadataAccount: PublicKey | Pda;
+    authority: PublicKey | Pda;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplInscription',
+    '1NSCRfGeyo7wPUazGbaPBUsTM49e1k2aXewHGARfzSo'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('Inscription'),
+    string({ size: 'variable' }).serialize('Staging'),
+    publicKeySerializer().serialize(seeds.inscriptionMetadataAccount),
+    publicKeySerializer().serialize(seeds.authority),
+  ]);
+}
@@ -1,6 +1,21 @@
 /* eslint-disable import/no-extraneous-dependencies */
 import { createUmi as basecreateUmi } from '@metaplex-foundation/umi-bundle-tests';
-import { PublicKey, Umi, publicKey } from '@metaplex-foundation/umi';
+import {
+  PublicKey,
+  Signer,
+  Umi,
+  none,
+  publicKey,
+} from '@metaplex-foundation/umi';
+import {
+  array,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { createAccount } from '@metaplex-foundation/mpl-toolbox';
 import pMap from 'p-map';
 import {
   mplInscription,
@@ -41,3 +56,108 @@ async function createShardIdempotent(umi: Umi, shardNumber: number) {
     }).sendAndConfirm(umi);
   }
 }
+
+// Creates a Token 2022 mint with the metadata pointer and token metadata
+// extensions, both pointing at the mint itself.
+export async function createToken2022MintWithMetadata(
+  umi: Umi,
+  mint: Signer,
+  metadata: { name: string; symbol: string; uri: string }
+) {
+  // Mint (82) + padding (83) + account type (1) + metadata pointer (4 + 64).
+  const space = 234;
+  // Token metadata TLV entry (4 + 32 + 32 + strings + empty vec).
+  const metadataSpace =
+    4 +
+    64 +
+    4 +
+    metadata.name.length +
+    4 +
+    metadata.symbol.length +
+    4 +
+    metadata.uri.length +
+    4;
+  const lamports = await umi.rpc.getRent(space + metadataSpace);
+
+  const initializeMetadataPointer = struct([
+    ['discriminator', u8()],
+    ['metadataPointerDiscriminator', u8()],
+    ['authority', publicKeySerializer()],
+    ['metadataAddress', publicKeySerializer()],
+  ]).serialize({
+    discriminator: 39,
+    metadataPointerDiscriminator: 0,
+    authority: umi.identity.publicKey,
+    metadataAddress: mint.publicKey,
+  });
+
+  const initializeMint = struct([
+    ['discriminator', u8()],
+    ['decimals', u8()],
+    ['mintAuthority', publicKeySerializer()],
+    ['freezeAuthority', option(publicKeySerializer())],
+  ]).serialize({
+    discriminator: 20,
+    decimals: 0,
+    mintAuthority: umi.identity.publicKey,
+    freezeAuthority: none(),
+  });
+
+  const initializeTokenMetadata = struct([
+    ['discriminator', array(u8(), { size: 8 })],
+    ['name', string()],
+    ['symbol', string()],
+    ['uri', string()],
+  ]).serialize({
+    discriminator: [210, 225, 30, 162, 88, 184, 77, 141],
+    ...metadata,
+  });
+
+  await createAccount(umi, {
+    newAccount: mint,
+    lamports,
+    space,
+    programId: SPL_TOKEN_2022_PROGRAM_ID,
+  })
+    .add({
+      instruction: {
+        programId: SPL_TOKEN_2022_PROGRAM_ID,
+        keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+        data: initializeMetadataPointer,
+      },
+      signers: [],
+      bytesCreatedOnChain: 0,
+    })
+    .add({
+      instruction: {
+        programId: SPL_TOKEN_2022_PROGRAM_ID,
+        keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+        data: initializeMint,
+      },
+      signers: [],
+      bytesCreatedOnChain: 0,
+    })
+    .add({
+      instruction: {
+        programId: SPL_TOKEN_2022_PROGRAM_ID,
+        keys: [
+          { pubkey: mint.publicKey, isSigner: false, isWritable: true },
+          {
+            pubkey: umi.identity.publicKey,
+            isSigner: false,
+            isWritable: false,
+          },
+          { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+          {
+            pubkey: umi.identity.publicKey,
+            isSigner: true,
+            isWritable: false,
+          },
+        ],
+        data: initializeTokenMetadata,
+      },
+      signers: [umi.identity],
+      bytesCreatedOnChain: 0,
+    })
+    .sendAndConfirm(umi);
+}
@@ -1,15 +1,19 @@
 import test from 'ava';
 import { PublicKey, generateSigner } from '@metaplex-foundation/umi';
 import {
+  AuthorityRole,
   DataType,
   InscriptionMetadata,
   Key,
   MPL_INSCRIPTION_PROGRAM_ID,
   addAuthority,
+  close,
   fetchInscriptionMetadata,
   findInscriptionMetadataPda,
   initialize,
+  initializeAssociatedInscription,
   removeAuthority,
+  replaceAuthority,
   writeData,
 } from '../src';
 import { createUmi } from './_setup';
@@ -25,9 +29,11 @@ test('it can add an authority to an inscription', async (t) => {
   });
 
   // When we create a new account.
-  await initialize(umi, {
-    inscriptionAccount,
-  }).sendAndConfirm(umi);
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
 
   // Then an account was created with the correct data.
   let inscriptionMetadata = await fetchInscriptionMetadata(
@@ -53,6 +59,7 @@ test('it can add an authority to an inscription', async (t) => {
   await addAuthority(umi, {
     inscriptionMetadataAccount,
     newAuthority: authority.publicKey,
+    role: AuthorityRole.Admin,
   }).sendAndConfirm(umi);
 
   inscriptionMetadata = await fetchInscriptionMetadata(
@@ -78,9 +85,11 @@ test('it can make an inscription immutable', async (t) => {
   });
 
   // When we create a new account.
-  await initialize(umi, {
-    inscriptionAccount,
-  }).sendAndConfirm(umi);
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
 
   // Then an account was created with the correct data.
   let inscriptionMetadata = await fetchInscriptionMetadata(
@@ -105,6 +114,8 @@ test('it can make an inscription immutable', async (t) => {
 
   await removeAuthority(umi, {
     inscriptionMetadataAccount,
+    target: null,
+    allowLastAuthority: true,
   }).sendAndConfirm(umi);
 
   inscriptionMetadata = await fetchInscriptionMetadata(
@@ -125,6 +136,254 @@ test('it can make an inscription immutable', async (t) => {
     value: Buffer.from('{"description": "This will fail!"'),
     associatedTag: null,
     offset: 0,
+    precondition: null,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { name: 'InvalidAuthority' });
+});
+
+test('it cannot remove the last authority without allowing it', async (t) => {
+  // Given a Umi instance and a new inscription.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  // When we remove the only authority without allowing it.
+  const promise = removeAuthority(umi, {
+    inscriptionMetadataAccount,
+    target: null,
+    allowLastAuthority: false,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'CannotRemoveLastAuthority' });
+});
+
+test('it can remove another authority from an inscription', async (t) => {
+  // Given a Umi instance and an inscription with two authorities.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const authority = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      addAuthority(umi, {
+        inscriptionMetadataAccount,
+        newAuthority: authority.publicKey,
+        role: AuthorityRole.Admin,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the first authority removes the second one.
+  await removeAuthority(umi, {
+    inscriptionMetadataAccount,
+    target: authority.publicKey,
+    allowLastAuthority: false,
+  }).sendAndConfirm(umi);
+
+  // Then only the first authority remains.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.InscriptionMetadataAccount,
+    updateAuthorities: [umi.identity.publicKey],
+  });
+});
+
+test('it can replace an authority of an inscription', async (t) => {
+  // Given a Umi instance and a new inscription.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const authority = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  // When we hand the inscription over to a new authority.
+  await replaceAuthority(umi, {
+    inscriptionMetadataAccount,
+    target: null,
+    newAuthority: authority.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the new authority replaced the old one.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.InscriptionMetadataAccount,
+    updateAuthorities: [authority.publicKey],
+  });
+
+  // And the old authority can no longer write to the inscription.
+  const promise = writeData(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    value: Buffer.from('{"description": "This will fail!"'),
+    associatedTag: null,
+    offset: 0,
+    precondition: null,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { name: 'InvalidAuthority' });
+});
+
+test('a writer can write to an inscription but not manage it', async (t) => {
+  // Given a Umi instance and an inscription with a writer authority.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const writer = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      addAuthority(umi, {
+        inscriptionMetadataAccount,
+        newAuthority: writer.publicKey,
+        role: AuthorityRole.Writer,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.InscriptionMetadataAccount,
+    updateAuthorities: [umi.identity.publicKey, writer.publicKey],
+    authorityRoles: [AuthorityRole.Admin, AuthorityRole.Writer],
+  });
+
+  // When the writer writes to the inscription.
+  await writeData(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    authority: writer,
+    value: Buffer.from('Hello, world!'),
+    associatedTag: null,
+    offset: 0,
+    precondition: null,
+  }).sendAndConfirm(umi);
+
+  // Then the data was written.
+  const jsonData = await umi.rpc.getAccount(inscriptionAccount.publicKey);
+  if (jsonData.exists) {
+    t.is(Buffer.from(jsonData.data).toString('utf8'), 'Hello, world!');
+  } else {
+    t.fail('Inscription account does not exist');
+  }
+
+  // And the writer can't add authorities or close the inscription.
+  const addPromise = addAuthority(umi, {
+    inscriptionMetadataAccount,
+    authority: writer,
+    newAuthority: generateSigner(umi).publicKey,
+    role: AuthorityRole.Writer,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(addPromise, { name: 'InvalidAuthority' });
+
+  const closePromise = close(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    authority: writer,
+    associatedTag: null,
+    tombstone: false,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(closePromise, { name: 'InvalidAuthority' });
+});
+
+test('a tagger can create associated inscriptions but not write', async (t) => {
+  // Given a Umi instance and an inscription with a tagger authority.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const tagger = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      addAuthority(umi, {
+        inscriptionMetadataAccount,
+        newAuthority: tagger.publicKey,
+        role: AuthorityRole.Tagger,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the tagger creates an associated inscription.
+  await initializeAssociatedInscription(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    authority: tagger,
+    associationTag: 'image',
+    initialSize: null,
+    dataType: DataType.Uninitialized,
+    contentType: null,
+  }).sendAndConfirm(umi);
+
+  // Then the associated inscription was added.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.is(inscriptionMetadata.associatedInscriptions.length, 1);
+
+  // And the tagger can't write to the inscription.
+  const promise = writeData(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    authority: tagger,
+    value: Buffer.from('Hello, world!'),
+    associatedTag: null,
+    offset: 0,
+    precondition: null,
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(promise, { name: 'InvalidAuthority' });
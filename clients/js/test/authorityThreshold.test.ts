@@ -0,0 +1,250 @@
+import test from 'ava';
+import { generateSigner } from '@metaplex-foundation/umi';
+import {
+  AuthorityRole,
+  InscriptionMetadata,
+  Key,
+  addAuthority,
+  addWriteDelegate,
+  allocate,
+  fetchInscriptionMetadata,
+  findInscriptionMetadataPda,
+  freeze,
+  initialize,
+  setAuthorityThreshold,
+  writeData,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it needs enough authorities to sign once a threshold is set', async (t) => {
+  // Given a Umi instance and an inscription with two admins.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const coAuthority = generateSigner(umi);
+  const newAuthority = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      addAuthority(umi, {
+        inscriptionMetadataAccount,
+        newAuthority: coAuthority.publicKey,
+        role: AuthorityRole.Admin,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we require both of them to sign.
+  await setAuthorityThreshold(umi, {
+    inscriptionMetadataAccount,
+    threshold: 2,
+  }).sendAndConfirm(umi);
+
+  // Then a single authority can no longer add an authority.
+  const promise = addAuthority(umi, {
+    inscriptionMetadataAccount,
+    newAuthority: newAuthority.publicKey,
+    role: AuthorityRole.Writer,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { name: 'AuthorityThresholdNotMet' });
+
+  // But both of them can.
+  await addAuthority(umi, {
+    inscriptionMetadataAccount,
+    newAuthority: newAuthority.publicKey,
+    role: AuthorityRole.Writer,
+  })
+    .addRemainingAccounts({ signer: coAuthority, isWritable: false })
+    .sendAndConfirm(umi);
+
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.InscriptionMetadataAccount,
+    updateAuthorities: [
+      umi.identity.publicKey,
+      coAuthority.publicKey,
+      newAuthority.publicKey,
+    ],
+    authorityThreshold: 2,
+  });
+});
+
+test('it cannot set a threshold above the number of authorities', async (t) => {
+  // Given a Umi instance and an inscription with a single authority.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  // When we require two authorities to sign.
+  const promise = setAuthorityThreshold(umi, {
+    inscriptionMetadataAccount,
+    threshold: 2,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidAuthorityThreshold' });
+});
+
+test('it needs enough authorities to sign to freeze an inscription', async (t) => {
+  // Given a Umi instance and an inscription with two admins and a threshold of two.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const coAuthority = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      addAuthority(umi, {
+        inscriptionMetadataAccount,
+        newAuthority: coAuthority.publicKey,
+        role: AuthorityRole.Admin,
+      })
+    )
+    .add(
+      setAuthorityThreshold(umi, {
+        inscriptionMetadataAccount,
+        threshold: 2,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When a single authority tries to freeze the inscription.
+  const promise = freeze(umi, {
+    inscriptionMetadataAccount,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'AuthorityThresholdNotMet' });
+
+  // But both of them can.
+  await freeze(umi, {
+    inscriptionMetadataAccount,
+  })
+    .addRemainingAccounts({ signer: coAuthority, isWritable: false })
+    .sendAndConfirm(umi);
+
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    frozen: true,
+  });
+});
+
+test('it needs enough authorities to sign to shrink an inscription', async (t) => {
+  // Given a Umi instance and an inscription with data, two admins and a threshold of two.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const coAuthority = generateSigner(umi);
+  const delegate = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      writeData(umi, {
+        inscriptionAccount: inscriptionAccount.publicKey,
+        inscriptionMetadataAccount,
+        value: Buffer.from('Hello, world!'),
+        associatedTag: null,
+        offset: 0,
+        precondition: null,
+      })
+    )
+    .add(
+      addAuthority(umi, {
+        inscriptionMetadataAccount,
+        newAuthority: coAuthority.publicKey,
+        role: AuthorityRole.Admin,
+      })
+    )
+    .add(
+      addWriteDelegate(umi, {
+        inscriptionMetadataAccount,
+        delegate: delegate.publicKey,
+        expiresAt: BigInt(Math.floor(Date.now() / 1000) + 3600),
+      })
+    )
+    .add(
+      setAuthorityThreshold(umi, {
+        inscriptionMetadataAccount,
+        threshold: 2,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the write delegate tries to shrink the inscription.
+  const delegatePromise = allocate(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    authority: delegate,
+    associatedTag: null,
+    targetSize: 0,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(delegatePromise, { name: 'InvalidAuthority' });
+
+  // And a single authority cannot shrink it either.
+  const promise = allocate(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    associatedTag: null,
+    targetSize: 0,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { name: 'AuthorityThresholdNotMet' });
+
+  // But both of them can.
+  await allocate(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    associatedTag: null,
+    targetSize: 0,
+  })
+    .addRemainingAccounts({ signer: coAuthority, isWritable: false })
+    .sendAndConfirm(umi);
+
+  const account = await umi.rpc.getAccount(inscriptionAccount.publicKey);
+  if (account.exists) {
+    t.is(account.data.length, 0);
+  } else {
+    t.fail('Inscription account does not exist');
+  }
+});
@@ -11,6 +11,7 @@ import {
 } from '@metaplex-foundation/mpl-token-metadata';
 import test from 'ava';
 import {
+  DataType,
   MPL_INSCRIPTION_PROGRAM_ID,
   clearData,
   findAssociatedInscriptionPda,
@@ -38,7 +39,7 @@ test('it can clear JSON data from an inscription account', async (t) => {
 
   // When we create a new account.
   builder = builder.add(
-    initialize(umi, {
+    await initialize(umi, {
       inscriptionAccount,
     })
   );
@@ -53,6 +54,7 @@ test('it can clear JSON data from an inscription account', async (t) => {
       ),
       associatedTag: null,
       offset: 0,
+      precondition: null,
     })
   );
 
@@ -103,7 +105,7 @@ test('it can write JSON data to a mint inscription account', async (t) => {
 
   // When we create a new account.
   builder = builder.add(
-    initializeFromMint(umi, {
+    await initializeFromMint(umi, {
       mintInscriptionAccount: inscriptionAccount[0],
       mintAccount: mint.publicKey,
     })
@@ -119,6 +121,7 @@ test('it can write JSON data to a mint inscription account', async (t) => {
       ),
       associatedTag: null,
       offset: 0,
+      precondition: null,
     })
   );
 
@@ -157,7 +160,7 @@ test('it can write JSON data to an inscription account with a separate authority
 
   // When we create a new account.
   builder = builder.add(
-    initialize(umi, {
+    await initialize(umi, {
       inscriptionAccount,
       authority,
     })
@@ -174,6 +177,7 @@ test('it can write JSON data to an inscription account with a separate authority
       ),
       associatedTag: null,
       offset: 0,
+      precondition: null,
     })
   );
 
@@ -212,7 +216,7 @@ test('it can write Image data to an associated inscription account', async (t) =
 
   // When we create a new account.
   builder = builder.add(
-    initialize(umi, {
+    await initialize(umi, {
       inscriptionAccount,
     })
   );
@@ -226,6 +230,9 @@ test('it can write Image data to an associated inscription account', async (t) =
     initializeAssociatedInscription(umi, {
       inscriptionAccount: inscriptionAccount.publicKey,
       associationTag: 'image',
+      initialSize: null,
+      dataType: DataType.Uninitialized,
+      contentType: null,
     })
   );
 
@@ -248,6 +255,7 @@ test('it can write Image data to an associated inscription account', async (t) =
         value: chunk,
         associatedTag: 'image',
         offset: i,
+        precondition: null,
       }).sendAndConfirm(umi, { confirm: { commitment: 'finalized' } })
     );
   }
@@ -301,7 +309,7 @@ test('it can write Image data to an associated mint inscription account', async
 
   // When we create a new account.
   builder = builder.add(
-    initializeFromMint(umi, {
+    await initializeFromMint(umi, {
       mintInscriptionAccount: inscriptionAccount[0],
       mintAccount: mint.publicKey,
     })
@@ -316,6 +324,9 @@ test('it can write Image data to an associated mint inscription account', async
     initializeAssociatedInscription(umi, {
       inscriptionAccount: inscriptionAccount[0],
       associationTag: 'image',
+      initialSize: null,
+      dataType: DataType.Uninitialized,
+      contentType: null,
     })
   );
 
@@ -338,6 +349,7 @@ test('it can write Image data to an associated mint inscription account', async
         value: chunk,
         associatedTag: 'image',
         offset: i,
+        precondition: null,
       }).sendAndConfirm(umi)
     );
   }
@@ -0,0 +1,295 @@
+import {
+  generateSigner,
+  none,
+  percentAmount,
+  sol,
+  some,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  TokenStandard,
+  createV1,
+  mintV1,
+  mplTokenMetadata,
+} from '@metaplex-foundation/mpl-token-metadata';
+import {
+  DataType,
+  InscriptionMetadata,
+  InscriptionTombstone,
+  Key,
+  close,
+  closeAll,
+  fetchInscriptionMetadata,
+  fetchInscriptionTombstone,
+  findAssociatedInscriptionPda,
+  findInscriptionMetadataPda,
+  findMintInscriptionPda,
+  initialize,
+  initializeAssociatedInscription,
+  initializeFromMint,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can close an Inscription account', async (t) => {
+  // Given a Umi instance and a new inscription.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  // When we close it without a tombstone.
+  await close(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    associatedTag: null,
+    tombstone: false,
+  }).sendAndConfirm(umi);
+
+  // Then both accounts are gone.
+  t.false(await umi.rpc.accountExists(inscriptionAccount.publicKey));
+  t.false(await umi.rpc.accountExists(inscriptionMetadataAccount[0]));
+});
+
+test('it can leave a tombstone when closing an Inscription account', async (t) => {
+  // Given a Umi instance and a new inscription.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  // When we close it with a tombstone.
+  await close(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    associatedTag: null,
+    tombstone: true,
+  }).sendAndConfirm(umi);
+
+  // Then the inscription account is gone.
+  t.false(await umi.rpc.accountExists(inscriptionAccount.publicKey));
+
+  // And the tombstone keeps the rank of the inscription.
+  const tombstone = await fetchInscriptionTombstone(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(tombstone, <InscriptionTombstone>{
+    key: Key.InscriptionTombstoneAccount,
+    bump: inscriptionMetadataAccount[1],
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionRank: inscriptionMetadata.inscriptionRank,
+    contentHash: none(),
+  });
+
+  // And the inscription can't be created again.
+  const promise = (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { name: 'InscriptionTombstoned' });
+});
+
+test('it cannot initialize a tombstoned Mint Inscription account', async (t) => {
+  // Given a Umi instance and a new NFT.
+  const umi = await createUmi();
+  umi.use(mplTokenMetadata());
+
+  const mint = generateSigner(umi);
+  await createV1(umi, {
+    mint,
+    name: 'My NFT',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+    sellerFeeBasisPoints: percentAmount(5.5),
+    tokenStandard: TokenStandard.NonFungible,
+  }).sendAndConfirm(umi);
+
+  await mintV1(umi, {
+    mint: mint.publicKey,
+    tokenStandard: TokenStandard.NonFungible,
+  }).sendAndConfirm(umi);
+
+  const inscriptionAccount = findMintInscriptionPda(umi, {
+    mint: mint.publicKey,
+  });
+  const inscriptionMetadataAccount = findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount[0],
+  });
+
+  // And a Mint Inscription that was closed with a tombstone.
+  await (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+    })
+  ).sendAndConfirm(umi);
+
+  await close(umi, {
+    inscriptionAccount,
+    inscriptionMetadataAccount,
+    associatedTag: null,
+    tombstone: true,
+  }).sendAndConfirm(umi);
+
+  // When we try to inscribe the mint again.
+  const promise = (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InscriptionTombstoned' });
+});
+
+test('it refunds the rent payer when closing a sponsored Inscription account', async (t) => {
+  // Given a Umi instance that sponsors an inscription for another authority.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const owner = generateSigner(umi);
+  await umi.rpc.airdrop(owner.publicKey, sol(1));
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+      authority: owner,
+    })
+  ).sendAndConfirm(umi);
+
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.InscriptionMetadataAccount,
+    updateAuthorities: [owner.publicKey],
+    rentPayer: some(umi.identity.publicKey),
+  });
+
+  // When the owner closes it without refunding the sponsor.
+  const promise = close(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    payer: owner,
+    authority: owner,
+    associatedTag: null,
+    tombstone: false,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidRentPayer' });
+
+  // And when the owner closes it and refunds the sponsor.
+  const balanceBefore = await umi.rpc.getBalance(umi.identity.publicKey);
+
+  await close(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    payer: owner,
+    authority: owner,
+    rentPayer: umi.identity.publicKey,
+    associatedTag: null,
+    tombstone: false,
+  }).sendAndConfirm(umi);
+
+  // Then the sponsor got the rent back.
+  const balanceAfter = await umi.rpc.getBalance(umi.identity.publicKey);
+  t.true(balanceAfter.basisPoints > balanceBefore.basisPoints);
+  t.false(await umi.rpc.accountExists(inscriptionMetadataAccount[0]));
+});
+
+test('it can close an Inscription account with its associated inscriptions', async (t) => {
+  // Given a Umi instance and an inscription with two associated inscriptions.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const destination = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      initializeAssociatedInscription(umi, {
+        inscriptionAccount: inscriptionAccount.publicKey,
+        associationTag: 'image',
+        initialSize: null,
+        dataType: DataType.Uninitialized,
+        contentType: null,
+      })
+    )
+    .add(
+      initializeAssociatedInscription(umi, {
+        inscriptionAccount: inscriptionAccount.publicKey,
+        associationTag: 'json',
+        initialSize: null,
+        dataType: DataType.Uninitialized,
+        contentType: null,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  const associatedInscriptionAccounts = ['image', 'json'].map(
+    (associatedTag) =>
+      findAssociatedInscriptionPda(umi, {
+        associated_tag: associatedTag,
+        inscriptionMetadataAccount,
+      })[0]
+  );
+
+  // When we close everything at once, sending the lamports elsewhere.
+  await closeAll(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    destination: destination.publicKey,
+    tombstone: false,
+  })
+    .addRemainingAccounts(
+      associatedInscriptionAccounts.map((pubkey) => ({
+        pubkey,
+        isWritable: true,
+      }))
+    )
+    .sendAndConfirm(umi);
+
+  // Then every account is gone.
+  t.false(await umi.rpc.accountExists(inscriptionAccount.publicKey));
+  t.false(await umi.rpc.accountExists(inscriptionMetadataAccount[0]));
+  t.false(await umi.rpc.accountExists(associatedInscriptionAccounts[0]));
+  t.false(await umi.rpc.accountExists(associatedInscriptionAccounts[1]));
+
+  // And the destination received the lamports.
+  const balance = await umi.rpc.getBalance(destination.publicKey);
+  t.true(balance.basisPoints > BigInt(0));
+});
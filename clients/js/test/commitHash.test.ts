@@ -0,0 +1,125 @@
+import test from 'ava';
+import { createHash } from 'crypto';
+import {
+  TransactionBuilder,
+  generateSigner,
+  none,
+  some,
+} from '@metaplex-foundation/umi';
+import {
+  InscriptionMetadata,
+  commitHash,
+  fetchInscriptionMetadata,
+  findInscriptionMetadataPda,
+  initialize,
+  writeData,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can commit to the hash of an inscription', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  const data = Buffer.from('{"description": "A bread! But on-chain!"}');
+
+  let builder = new TransactionBuilder();
+
+  // When we create a new account with data.
+  builder = builder.add(
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  );
+
+  builder = builder.add(
+    writeData(umi, {
+      inscriptionAccount: inscriptionAccount.publicKey,
+      inscriptionMetadataAccount,
+      value: data,
+      associatedTag: null,
+      offset: 0,
+      precondition: null,
+    })
+  );
+
+  // And commit to its hash.
+  builder = builder.add(
+    commitHash(umi, {
+      inscriptionAccount: inscriptionAccount.publicKey,
+      inscriptionMetadataAccount,
+    })
+  );
+
+  await builder.sendAndConfirm(umi);
+
+  // Then the hash and length of the data were recorded.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    contentHash: some(Array.from(createHash('sha256').update(data).digest())),
+    contentLength: BigInt(data.length),
+  });
+});
+
+test('it invalidates the hash commitment when the data is written', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  // And an inscription with a hash commitment.
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      writeData(umi, {
+        inscriptionAccount: inscriptionAccount.publicKey,
+        inscriptionMetadataAccount,
+        value: Buffer.from('Hello, world!'),
+        associatedTag: null,
+        offset: 0,
+        precondition: null,
+      })
+    )
+    .add(
+      commitHash(umi, {
+        inscriptionAccount: inscriptionAccount.publicKey,
+        inscriptionMetadataAccount,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we write new data.
+  await writeData(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    value: Buffer.from('Goodbye!'),
+    associatedTag: null,
+    offset: 0,
+    precondition: null,
+  }).sendAndConfirm(umi);
+
+  // Then the hash commitment was cleared.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    contentHash: none(),
+    contentLength: BigInt(0),
+  });
+});
@@ -0,0 +1,157 @@
+import test from 'ava';
+import {
+  TransactionBuilder,
+  generateSigner,
+  publicKey,
+} from '@metaplex-foundation/umi';
+import {
+  DataType,
+  copyData,
+  findAssociatedInscriptionPda,
+  findInscriptionMetadataPda,
+  initialize,
+  initializeAssociatedInscription,
+  writeData,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can copy data from an inscription to an associated inscription', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  const associatedInscriptionAccount = findAssociatedInscriptionPda(umi, {
+    associated_tag: 'thumbnail',
+    inscriptionMetadataAccount,
+  });
+
+  let builder = new TransactionBuilder();
+
+  // When we create a new account with data and an associated inscription.
+  builder = builder.add(
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  );
+
+  builder = builder.add(
+    writeData(umi, {
+      inscriptionAccount: inscriptionAccount.publicKey,
+      inscriptionMetadataAccount,
+      value: Buffer.from('Hello, world!'),
+      associatedTag: null,
+      offset: 0,
+      precondition: null,
+    })
+  );
+
+  builder = builder.add(
+    initializeAssociatedInscription(umi, {
+      inscriptionAccount: inscriptionAccount.publicKey,
+      associationTag: 'thumbnail',
+      initialSize: null,
+      dataType: DataType.Uninitialized,
+      contentType: null,
+    })
+  );
+
+  // And copy part of the data into the associated inscription.
+  builder = builder.add(
+    copyData(umi, {
+      sourceAccount: inscriptionAccount.publicKey,
+      inscriptionAccount: associatedInscriptionAccount,
+      inscriptionMetadataAccount,
+      associatedTag: 'thumbnail',
+      sourceOffset: 7,
+      length: 5,
+      offset: 0,
+    })
+  );
+
+  await builder.sendAndConfirm(umi);
+
+  // Then the data was copied.
+  const thumbnailData = await umi.rpc.getAccount(
+    publicKey(associatedInscriptionAccount)
+  );
+  if (thumbnailData.exists) {
+    t.is(Buffer.from(thumbnailData.data).toString('utf8'), 'world');
+  } else {
+    t.fail('Associated inscription account does not exist');
+  }
+});
+
+test('it cannot copy data outside of the source range', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      writeData(umi, {
+        inscriptionAccount: inscriptionAccount.publicKey,
+        inscriptionMetadataAccount,
+        value: Buffer.from('Hello, world!'),
+        associatedTag: null,
+        offset: 0,
+        precondition: null,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we copy past the end of the source data.
+  const promise = copyData(umi, {
+    sourceAccount: inscriptionAccount.publicKey,
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    associatedTag: null,
+    sourceOffset: 7,
+    length: 10,
+    offset: 13,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidCopyRange' });
+});
+
+test('it cannot copy more than 10 KiB in a single call', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  // When we copy more than the realloc limit.
+  const promise = copyData(umi, {
+    sourceAccount: inscriptionAccount.publicKey,
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    associatedTag: null,
+    sourceOffset: 0,
+    length: 10 * 1024 + 1,
+    offset: 0,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'CopyTooLarge' });
+});
@@ -0,0 +1,119 @@
+import test from 'ava';
+import { TransactionBuilder, generateSigner } from '@metaplex-foundation/umi';
+import {
+  AuthorityRole,
+  InscriptionMetadata,
+  Key,
+  addAuthority,
+  fetchInscriptionMetadata,
+  findInscriptionMetadataPda,
+  freeze,
+  initialize,
+  writeData,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can freeze an inscription', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  let builder = new TransactionBuilder();
+
+  // When we create a new account with data.
+  builder = builder.add(
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  );
+
+  builder = builder.add(
+    writeData(umi, {
+      inscriptionAccount: inscriptionAccount.publicKey,
+      inscriptionMetadataAccount,
+      value: Buffer.from('Hello, world!'),
+      associatedTag: null,
+      offset: 0,
+      precondition: null,
+    })
+  );
+
+  // And freeze it.
+  builder = builder.add(
+    freeze(umi, {
+      inscriptionMetadataAccount,
+    })
+  );
+
+  await builder.sendAndConfirm(umi);
+
+  // Then the inscription is frozen.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.InscriptionMetadataAccount,
+    bump: inscriptionMetadataAccount[1],
+    frozen: true,
+    updateAuthorities: [umi.identity.publicKey],
+  });
+});
+
+test('it cannot modify a frozen inscription', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const authority = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  // And a frozen inscription.
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      freeze(umi, {
+        inscriptionMetadataAccount,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we try to write data to it.
+  const writePromise = writeData(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    value: Buffer.from('Hello, world!'),
+    associatedTag: null,
+    offset: 0,
+    precondition: null,
+  }).sendAndConfirm(umi);
+
+  // Then the write fails.
+  await t.throwsAsync(writePromise, { name: 'InscriptionFrozen' });
+
+  // And adding an authority fails.
+  const authorityPromise = addAuthority(umi, {
+    inscriptionMetadataAccount,
+    newAuthority: authority.publicKey,
+    role: AuthorityRole.Admin,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(authorityPromise, { name: 'InscriptionFrozen' });
+
+  // And freezing it again fails.
+  const freezePromise = freeze(umi, {
+    inscriptionMetadataAccount,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(freezePromise, { name: 'InscriptionFrozen' });
+});
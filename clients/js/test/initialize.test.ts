@@ -1,6 +1,7 @@
 /* eslint-disable no-await-in-loop */
-import { generateSigner, none } from '@metaplex-foundation/umi';
+import { generateSigner, none, sol } from '@metaplex-foundation/umi';
 import test from 'ava';
+import { createAccount } from '@metaplex-foundation/mpl-toolbox';
 import {
   AssociatedInscription,
   DataType,
@@ -8,10 +9,13 @@ import {
   Key,
   MPL_INSCRIPTION_PROGRAM_ID,
   fetchInscriptionMetadata,
+  fetchInscriptionRank,
   fetchInscriptionShard,
   findInscriptionMetadataPda,
+  findInscriptionRankPda,
   findInscriptionShardPda,
   initialize,
+  recordInscriptionRank,
 } from '../src';
 import { createUmi } from './_setup';
 
@@ -33,10 +37,12 @@ test('it can initialize an Inscription account', async (t) => {
   );
 
   // When we create a new account.
-  await initialize(umi, {
-    inscriptionAccount,
-    inscriptionShardAccount,
-  }).sendAndConfirm(umi);
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+      inscriptionShardAccount,
+    })
+  ).sendAndConfirm(umi);
 
   // Then an account was created with the correct data.
   const inscriptionMetadata = await fetchInscriptionMetadata(
@@ -94,10 +100,12 @@ test('it can initialize multiple Inscription accounts', async (t) => {
     );
 
     // When we create a new account.
-    await initialize(umi, {
-      inscriptionAccount: inscriptionAccount[i],
-      inscriptionShardAccount,
-    }).sendAndConfirm(umi);
+    await (
+      await initialize(umi, {
+        inscriptionAccount: inscriptionAccount[i],
+        inscriptionShardAccount,
+      })
+    ).sendAndConfirm(umi);
 
     // Then an account was created with the correct data.
     const inscriptionMetadata = await fetchInscriptionMetadata(
@@ -153,11 +161,13 @@ test('it can initialize an Inscription account with separate authority', async (
   );
 
   // When we create a new account.
-  await initialize(umi, {
-    inscriptionAccount,
-    inscriptionShardAccount,
-    authority,
-  }).sendAndConfirm(umi);
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+      inscriptionShardAccount,
+      authority,
+    })
+  ).sendAndConfirm(umi);
 
   // Then an account was created with the correct data.
   const inscriptionMetadata = await fetchInscriptionMetadata(
@@ -191,3 +201,171 @@ test('it can initialize an Inscription account with separate authority', async (
     });
   }
 });
+
+test('it can initialize an Inscription account with a preallocated size', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  // When we create a new account with an initial size.
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+      initialSize: 10240,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then the account was created with that many bytes.
+  const jsonData = await umi.rpc.getAccount(inscriptionAccount.publicKey);
+  if (jsonData.exists) {
+    t.like(jsonData, {
+      owner: MPL_INSCRIPTION_PROGRAM_ID,
+      data: new Uint8Array(10240),
+    });
+  } else {
+    t.fail('Inscription account does not exist');
+  }
+});
+
+test('it can initialize a pre-created Inscription account larger than 10 KiB', async (t) => {
+  // Given a Umi instance and a 100 KiB account created for the program.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const space = 100 * 1024;
+
+  await createAccount(umi, {
+    newAccount: inscriptionAccount,
+    lamports: await umi.rpc.getRent(space),
+    space,
+    programId: MPL_INSCRIPTION_PROGRAM_ID,
+  }).sendAndConfirm(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  // When we initialize it as an inscription.
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then the account keeps its size.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.InscriptionMetadataAccount,
+    inscriptionAccount: inscriptionAccount.publicKey,
+    updateAuthorities: [umi.identity.publicKey],
+  });
+
+  const jsonData = await umi.rpc.getAccount(inscriptionAccount.publicKey);
+  if (jsonData.exists) {
+    t.like(jsonData, {
+      owner: MPL_INSCRIPTION_PROGRAM_ID,
+      data: new Uint8Array(space),
+    });
+  } else {
+    t.fail('Inscription account does not exist');
+  }
+});
+
+test('it cannot initialize an Inscription account larger than 10 KiB', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  // When we create a new account with an initial size above the limit.
+  const promise = (
+    await initialize(umi, {
+      inscriptionAccount,
+      initialSize: 10241,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InitialSizeTooLarge' });
+});
+
+test('it records the rank of an Inscription account by default', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  // When we create a new account without passing a rank account.
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then the rank account of its rank points to the inscription's metadata.
+  const { inscriptionRank: rank } = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+  const inscriptionRankAccount = findInscriptionRankPda(umi, { rank });
+  const inscriptionRank = await fetchInscriptionRank(
+    umi,
+    inscriptionRankAccount
+  );
+
+  t.like(inscriptionRank, {
+    key: Key.InscriptionRankAccount,
+    bump: inscriptionRankAccount[1],
+    inscriptionMetadataAccount: inscriptionMetadataAccount[0],
+  });
+});
+
+test('anyone can record the rank of an existing Inscription account', async (t) => {
+  // Given a Umi instance and an inscription created without a rank account.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+      inscriptionRankAccount: null,
+    })
+  ).sendAndConfirm(umi);
+
+  const { inscriptionRank: rank } = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+  const inscriptionRankAccount = findInscriptionRankPda(umi, { rank });
+
+  // When someone else records its rank.
+  const payer = generateSigner(umi);
+  await umi.rpc.airdrop(payer.publicKey, sol(1));
+
+  await recordInscriptionRank(umi, {
+    inscriptionMetadataAccount,
+    inscriptionRankAccount,
+    payer,
+  }).sendAndConfirm(umi);
+
+  // Then the rank account points to the inscription's metadata.
+  const inscriptionRank = await fetchInscriptionRank(
+    umi,
+    inscriptionRankAccount
+  );
+
+  t.like(inscriptionRank, {
+    key: Key.InscriptionRankAccount,
+    bump: inscriptionRankAccount[1],
+    inscriptionMetadataAccount: inscriptionMetadataAccount[0],
+  });
+});
@@ -7,6 +7,7 @@ import {
   mplTokenMetadata,
 } from '@metaplex-foundation/mpl-token-metadata';
 import {
+  AssociatedInscription,
   DataType,
   InscriptionMetadata,
   Key,
@@ -20,6 +21,9 @@ import {
   initialize,
   initializeAssociatedInscription,
   initializeFromMint,
+  setContentType,
+  setDataType,
+  writeData,
 } from '../src';
 import { createUmi } from './_setup';
 
@@ -41,10 +45,12 @@ test('it can initialize an Associated Inscription account', async (t) => {
   );
 
   // When we create a new account.
-  await initialize(umi, {
-    inscriptionAccount,
-    inscriptionShardAccount,
-  }).sendAndConfirm(umi);
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+      inscriptionShardAccount,
+    })
+  ).sendAndConfirm(umi);
 
   const shardDataAfter = await fetchInscriptionShard(
     umi,
@@ -61,6 +67,9 @@ test('it can initialize an Associated Inscription account', async (t) => {
   await initializeAssociatedInscription(umi, {
     inscriptionAccount: inscriptionAccount.publicKey,
     associationTag: 'image',
+    initialSize: null,
+    dataType: DataType.Uninitialized,
+    contentType: null,
   }).sendAndConfirm(umi);
 
   const inscriptionMetadata = await fetchInscriptionMetadata(
@@ -82,6 +91,7 @@ test('it can initialize an Associated Inscription account', async (t) => {
         dataType: DataType.Uninitialized,
       },
     ],
+    associatedContentTypes: [],
   });
 
   const data = await umi.rpc.getAccount(associatedInscriptionAccount[0]);
@@ -136,10 +146,12 @@ test('it can initialize an Associated Inscription account on a Mint', async (t)
   // const asset = await fetchDigitalAsset(umi, mint.publicKey);
 
   // When we create a new account.
-  await initializeFromMint(umi, {
-    mintAccount: mint.publicKey,
-    inscriptionShardAccount,
-  }).sendAndConfirm(umi);
+  await (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+      inscriptionShardAccount,
+    })
+  ).sendAndConfirm(umi);
 
   const shardDataAfter = await fetchInscriptionShard(
     umi,
@@ -156,6 +168,9 @@ test('it can initialize an Associated Inscription account on a Mint', async (t)
   await initializeAssociatedInscription(umi, {
     inscriptionAccount: inscriptionAccount[0],
     associationTag: 'image',
+    initialSize: null,
+    dataType: DataType.Uninitialized,
+    contentType: null,
   }).sendAndConfirm(umi);
 
   const inscriptionMetadata = await fetchInscriptionMetadata(
@@ -177,6 +192,7 @@ test('it can initialize an Associated Inscription account on a Mint', async (t)
         dataType: DataType.Uninitialized,
       },
     ],
+    associatedContentTypes: [],
   });
 
   const data = await umi.rpc.getAccount(associatedInscriptionAccount[0]);
@@ -193,3 +209,223 @@ test('it can initialize an Associated Inscription account on a Mint', async (t)
   );
   t.is(shardDataAfter.count, shardDataLast.count);
 });
+
+test('it can initialize an Associated Inscription account with a preallocated size', async (t) => {
+  // Given a Umi instance and an initialized inscription.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  const associatedInscriptionAccount = findAssociatedInscriptionPda(umi, {
+    associated_tag: 'image',
+    inscriptionMetadataAccount,
+  });
+
+  // When we create an Associated Inscription account with an initial size.
+  await initializeAssociatedInscription(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    associationTag: 'image',
+    initialSize: 4096,
+    dataType: DataType.Uninitialized,
+    contentType: null,
+  }).sendAndConfirm(umi);
+
+  // Then the account was created with that many bytes.
+  const data = await umi.rpc.getAccount(associatedInscriptionAccount[0]);
+  if (data.exists) {
+    t.like(data, {
+      owner: MPL_INSCRIPTION_PROGRAM_ID,
+      data: new Uint8Array(4096),
+    });
+  } else {
+    t.fail('Associated inscription account does not exist');
+  }
+});
+
+test('it can declare the content type of Associated Inscriptions', async (t) => {
+  // Given a Umi instance and an initialized inscription.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  const thumbnailAccount = findAssociatedInscriptionPda(umi, {
+    associated_tag: 'thumbnail',
+    inscriptionMetadataAccount,
+  });
+  const traitsAccount = findAssociatedInscriptionPda(umi, {
+    associated_tag: 'traits',
+    inscriptionMetadataAccount,
+  });
+
+  // When we create Associated Inscriptions with their content types.
+  await initializeAssociatedInscription(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    associationTag: 'thumbnail',
+    initialSize: null,
+    dataType: DataType.Binary,
+    contentType: 'image/webp',
+  })
+    .add(
+      initializeAssociatedInscription(umi, {
+        inscriptionAccount: inscriptionAccount.publicKey,
+        associationTag: 'traits',
+        initialSize: null,
+        dataType: DataType.Uninitialized,
+        contentType: null,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And we update the content type of one of them later.
+  await setContentType(umi, {
+    associatedInscriptionAccount: traitsAccount,
+    inscriptionMetadataAccount,
+    associationTag: 'traits',
+    contentType: 'application/json',
+  }).sendAndConfirm(umi);
+
+  // Then the metadata lists both content types.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    associatedInscriptions: [
+      <AssociatedInscription>{
+        tag: 'thumbnail',
+        bump: thumbnailAccount[1],
+        dataType: DataType.Binary,
+      },
+      <AssociatedInscription>{
+        tag: 'traits',
+        bump: traitsAccount[1],
+        dataType: DataType.Uninitialized,
+      },
+    ],
+    associatedContentTypes: [
+      { tag: 'thumbnail', contentType: 'image/webp' },
+      { tag: 'traits', contentType: 'application/json' },
+    ],
+  });
+
+  // And content types can't be too long.
+  const promise = setContentType(umi, {
+    associatedInscriptionAccount: traitsAccount,
+    inscriptionMetadataAccount,
+    associationTag: 'traits',
+    contentType: 'application/'.padEnd(65, 'x'),
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { name: 'ContentTypeTooLong' });
+
+  // And clearing the content type works too.
+  await setContentType(umi, {
+    associatedInscriptionAccount: thumbnailAccount,
+    inscriptionMetadataAccount,
+    associationTag: 'thumbnail',
+    contentType: null,
+  }).sendAndConfirm(umi);
+
+  const updatedMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+  t.deepEqual(updatedMetadata.associatedContentTypes, [
+    { tag: 'traits', contentType: 'application/json' },
+  ]);
+});
+
+test('it labels an Associated Inscription as JSON with SetDataType once written', async (t) => {
+  // Given a Umi instance and an inscription.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  const inscriptionMetadataAccount = findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+  const associatedInscriptionAccount = findAssociatedInscriptionPda(umi, {
+    associationTag: 'traits',
+    inscriptionMetadataAccount: inscriptionMetadataAccount[0],
+  });
+
+  // When we create an Associated Inscription labeled as JSON.
+  const promise = initializeAssociatedInscription(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    associationTag: 'traits',
+    initialSize: null,
+    dataType: DataType.Json,
+    contentType: 'application/json',
+  }).sendAndConfirm(umi);
+
+  // Then it fails because there is no data to validate yet.
+  await t.throwsAsync(promise, { name: 'InvalidJson' });
+
+  // When we create it without a data type, write the JSON and then label it.
+  await initializeAssociatedInscription(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    associationTag: 'traits',
+    initialSize: null,
+    dataType: DataType.Uninitialized,
+    contentType: 'application/json',
+  })
+    .add(
+      writeData(umi, {
+        inscriptionAccount: associatedInscriptionAccount,
+        inscriptionMetadataAccount,
+        value: Buffer.from('{"background": "blue"}'),
+        associatedTag: 'traits',
+        offset: 0,
+        precondition: null,
+      })
+    )
+    .add(
+      setDataType(umi, {
+        inscriptionAccount: associatedInscriptionAccount,
+        inscriptionMetadataAccount,
+        associatedTag: 'traits',
+        dataType: DataType.Json,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the Associated Inscription is labeled as JSON.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    associatedInscriptions: [
+      <AssociatedInscription>{
+        tag: 'traits',
+        bump: associatedInscriptionAccount[1],
+        dataType: DataType.Json,
+      },
+    ],
+  });
+});
@@ -0,0 +1,387 @@
+import {
+  PublicKey,
+  Signer,
+  Umi,
+  generateSigner,
+  none,
+  percentAmount,
+  publicKey,
+  publicKeyBytes,
+  sol,
+  some,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  MetadataArgsArgs,
+  createTree,
+  findLeafAssetIdPda,
+  findTreeConfigPda,
+  getMerkleProof,
+  getMerkleRoot,
+  getMetadataArgsSerializer,
+  hashLeaf,
+  hashMetadataCreators,
+  hashMetadataData,
+  mintToCollectionV1,
+  mintV1,
+  mplBubblegum,
+} from '@metaplex-foundation/mpl-bubblegum';
+import {
+  createNft,
+  findMetadataPda,
+  mplTokenMetadata,
+} from '@metaplex-foundation/mpl-token-metadata';
+import {
+  InscriptionMetadata,
+  Key,
+  fetchInscriptionMetadata,
+  fetchInscriptionRank,
+  findInscriptionMetadataPda,
+  findInscriptionRankPda,
+  findInscriptionShardPda,
+  findMintInscriptionPda,
+  findNextInscriptionRankPda,
+  initializeFromAsset,
+} from '../src';
+import { createUmi } from './_setup';
+
+const MAX_DEPTH = 5;
+
+async function createCompressedNft(
+  umi: Umi,
+  input: {
+    treeCreator: Signer;
+    isPublic: boolean;
+    minter?: Signer;
+    leafOwner?: PublicKey;
+    collectionMint?: PublicKey;
+  }
+) {
+  const merkleTree = generateSigner(umi);
+  await (
+    await createTree(umi, {
+      merkleTree,
+      maxDepth: MAX_DEPTH,
+      maxBufferSize: 8,
+      treeCreator: input.treeCreator,
+      payer: input.treeCreator,
+      public: input.isPublic,
+    })
+  ).sendAndConfirm(umi);
+
+  const minter = input.minter ?? input.treeCreator;
+  const leafOwner = input.leafOwner ?? minter.publicKey;
+  const metadata: MetadataArgsArgs = {
+    name: 'My Compressed NFT',
+    uri: 'https://example.com/my-cnft.json',
+    sellerFeeBasisPoints: 500,
+    collection: none(),
+    creators: [],
+  };
+
+  if (input.collectionMint) {
+    await mintToCollectionV1(umi, {
+      leafOwner,
+      merkleTree: merkleTree.publicKey,
+      metadata: {
+        ...metadata,
+        collection: some({ key: input.collectionMint, verified: false }),
+      },
+      collectionMint: input.collectionMint,
+      treeCreatorOrDelegate: minter,
+      payer: minter,
+    }).sendAndConfirm(umi);
+
+    // The collection is verified when minting into it.
+    metadata.collection = some({ key: input.collectionMint, verified: true });
+  } else {
+    await mintV1(umi, {
+      leafOwner,
+      merkleTree: merkleTree.publicKey,
+      metadata,
+      treeCreatorOrDelegate: minter,
+      payer: minter,
+    }).sendAndConfirm(umi);
+  }
+
+  return {
+    merkleTree: merkleTree.publicKey,
+    leafOwner,
+    metadata,
+  };
+}
+
+async function initializeCompressedNft(
+  umi: Umi,
+  input: {
+    merkleTree: PublicKey;
+    leafOwner: PublicKey;
+    metadata: MetadataArgsArgs;
+    authority?: Signer;
+    collectionMetadata?: PublicKey;
+    root?: PublicKey;
+    dataHash?: Uint8Array;
+  }
+) {
+  // The tree only holds the first leaf.
+  const [assetId] = findLeafAssetIdPda(umi, {
+    merkleTree: input.merkleTree,
+    leafIndex: 0,
+  });
+  const leaf = publicKey(
+    hashLeaf(umi, {
+      merkleTree: input.merkleTree,
+      owner: input.leafOwner,
+      leafIndex: 0,
+      metadata: input.metadata,
+    })
+  );
+
+  const assetInscriptionAccount = findMintInscriptionPda(umi, {
+    mint: assetId,
+  });
+
+  const inscriptionShardAccount = findInscriptionShardPda(umi, {
+    shardNumber: 0,
+  });
+
+  return initializeFromAsset(umi, {
+    assetInscriptionAccount,
+    inscriptionMetadataAccount: findInscriptionMetadataPda(umi, {
+      inscriptionAccount: assetInscriptionAccount[0],
+    }),
+    assetId,
+    treeConfig: findTreeConfigPda(umi, { merkleTree: input.merkleTree }),
+    merkleTree: input.merkleTree,
+    leafOwner: input.leafOwner,
+    leafDelegate: input.leafOwner,
+    inscriptionShardAccount,
+    inscriptionRankAccount: await findNextInscriptionRankPda(
+      umi,
+      inscriptionShardAccount
+    ),
+    authority: input.authority,
+    collectionMetadata: input.collectionMetadata,
+    root: Array.from(
+      publicKeyBytes(input.root ?? getMerkleRoot([leaf], MAX_DEPTH))
+    ),
+    dataHash: Array.from(input.dataHash ?? hashMetadataData(input.metadata)),
+    creatorHash: Array.from(hashMetadataCreators(input.metadata.creators)),
+    nonce: 0,
+    index: 0,
+    metadata: getMetadataArgsSerializer().serialize(input.metadata),
+  }).addRemainingAccounts(
+    getMerkleProof([leaf], MAX_DEPTH, leaf).map((pubkey) => ({
+      pubkey,
+      isWritable: false,
+    }))
+  );
+}
+
+test('the creator of a private tree can inscribe a compressed NFT', async (t) => {
+  // Given a compressed NFT in a private tree created by the identity.
+  const umi = (await createUmi()).use(mplBubblegum());
+  const { merkleTree, leafOwner, metadata } = await createCompressedNft(umi, {
+    treeCreator: umi.identity,
+    isPublic: false,
+  });
+
+  // When the tree creator inscribes it with a valid proof.
+  await (
+    await initializeCompressedNft(umi, {
+      merkleTree,
+      leafOwner,
+      metadata,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then the inscription is created for the asset.
+  const [assetId] = findLeafAssetIdPda(umi, { merkleTree, leafIndex: 0 });
+  const inscriptionAccount = findMintInscriptionPda(umi, { mint: assetId });
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    findInscriptionMetadataPda(umi, {
+      inscriptionAccount: inscriptionAccount[0],
+    })
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.MintInscriptionMetadataAccount,
+    inscriptionAccount: inscriptionAccount[0],
+    updateAuthorities: [umi.identity.publicKey],
+    mint: some(assetId),
+  });
+
+  // And its rank is recorded.
+  const inscriptionRank = await fetchInscriptionRank(
+    umi,
+    findInscriptionRankPda(umi, { rank: inscriptionMetadata.inscriptionRank })
+  );
+  t.is(
+    inscriptionRank.inscriptionMetadataAccount,
+    inscriptionMetadata.publicKey
+  );
+});
+
+test('it cannot inscribe a compressed NFT with an invalid root', async (t) => {
+  // Given a compressed NFT owned by the identity.
+  const umi = (await createUmi()).use(mplBubblegum());
+  const { merkleTree, leafOwner, metadata } = await createCompressedNft(umi, {
+    treeCreator: umi.identity,
+    isPublic: false,
+  });
+
+  // When the tree creator tries to inscribe it against a root the tree never had.
+  const promise = (
+    await initializeCompressedNft(umi, {
+      merkleTree,
+      leafOwner,
+      metadata,
+      root: generateSigner(umi).publicKey,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then the proof is rejected.
+  await t.throwsAsync(promise);
+});
+
+test('the owner of a compressed NFT cannot inscribe it', async (t) => {
+  // Given a compressed NFT in a private tree created by the identity.
+  const umi = (await createUmi()).use(mplBubblegum());
+  const owner = generateSigner(umi);
+  await umi.rpc.airdrop(owner.publicKey, sol(1));
+
+  // And owned by someone else.
+  const { merkleTree, leafOwner, metadata } = await createCompressedNft(umi, {
+    treeCreator: umi.identity,
+    isPublic: false,
+    leafOwner: owner.publicKey,
+  });
+
+  // When the owner tries to inscribe it.
+  const promise = (
+    await initializeCompressedNft(umi, {
+      merkleTree,
+      leafOwner,
+      metadata,
+      authority: owner,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then the owner is not accepted as an authority.
+  await t.throwsAsync(promise, { name: 'InvalidAuthority' });
+});
+
+test('the creator of a public tree cannot inscribe compressed NFTs minted by others', async (t) => {
+  // Given a public tree created by the identity.
+  const umi = (await createUmi()).use(mplBubblegum());
+  const minter = generateSigner(umi);
+  await umi.rpc.airdrop(minter.publicKey, sol(1));
+
+  // And a compressed NFT that someone else minted into it.
+  const { merkleTree, leafOwner, metadata } = await createCompressedNft(umi, {
+    treeCreator: umi.identity,
+    isPublic: true,
+    minter,
+  });
+
+  // When the tree creator tries to inscribe it.
+  const promise = (
+    await initializeCompressedNft(umi, {
+      merkleTree,
+      leafOwner,
+      metadata,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then the tree creator is not accepted as an authority.
+  await t.throwsAsync(promise, { name: 'InvalidAuthority' });
+});
+
+test('the update authority of the collection can inscribe a compressed NFT', async (t) => {
+  // Given a collection NFT owned by the identity.
+  const umi = (await createUmi()).use(mplBubblegum()).use(mplTokenMetadata());
+  const collectionMint = generateSigner(umi);
+  await createNft(umi, {
+    mint: collectionMint,
+    name: 'My Collection',
+    uri: 'https://example.com/my-collection.json',
+    sellerFeeBasisPoints: percentAmount(5),
+    isCollection: true,
+  }).sendAndConfirm(umi);
+
+  // And a compressed NFT in that collection owned by someone else.
+  const treeCreator = generateSigner(umi);
+  await umi.rpc.airdrop(treeCreator.publicKey, sol(1));
+  const { merkleTree, leafOwner, metadata } = await createCompressedNft(umi, {
+    treeCreator,
+    isPublic: false,
+    collectionMint: collectionMint.publicKey,
+  });
+
+  // When the tree creator tries to inscribe it.
+  const treeCreatorPromise = (
+    await initializeCompressedNft(umi, {
+      merkleTree,
+      leafOwner,
+      metadata,
+      authority: treeCreator,
+      collectionMetadata: findMetadataPda(umi, {
+        mint: collectionMint.publicKey,
+      })[0],
+    })
+  ).sendAndConfirm(umi);
+
+  // Then only the collection's update authority is accepted.
+  await t.throwsAsync(treeCreatorPromise, { name: 'InvalidAuthority' });
+
+  // When the update authority of the collection inscribes it.
+  await (
+    await initializeCompressedNft(umi, {
+      merkleTree,
+      leafOwner,
+      metadata,
+      collectionMetadata: findMetadataPda(umi, {
+        mint: collectionMint.publicKey,
+      })[0],
+    })
+  ).sendAndConfirm(umi);
+
+  // Then the update authority is recorded on the inscription.
+  const [assetId] = findLeafAssetIdPda(umi, { merkleTree, leafIndex: 0 });
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    findInscriptionMetadataPda(umi, {
+      inscriptionAccount: findMintInscriptionPda(umi, { mint: assetId })[0],
+    })
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    updateAuthorities: [umi.identity.publicKey],
+  });
+});
+
+test('it cannot inscribe a compressed NFT with metadata that does not match the leaf', async (t) => {
+  // Given a compressed NFT in a private tree owned by someone else.
+  const umi = (await createUmi()).use(mplBubblegum());
+  const treeCreator = generateSigner(umi);
+  await umi.rpc.airdrop(treeCreator.publicKey, sol(1));
+  const { merkleTree, leafOwner, metadata } = await createCompressedNft(umi, {
+    treeCreator,
+    isPublic: false,
+  });
+
+  // When the tree creator inscribes it with metadata that was not minted.
+  const promise = (
+    await initializeCompressedNft(umi, {
+      merkleTree,
+      leafOwner,
+      metadata: { ...metadata, name: 'Not My Compressed NFT' },
+      authority: treeCreator,
+      dataHash: hashMetadataData(metadata),
+    })
+  ).sendAndConfirm(umi);
+
+  // Then the metadata is rejected.
+  await t.throwsAsync(promise, { name: 'InvalidAssetMetadata' });
+});
@@ -0,0 +1,220 @@
+import {
+  PublicKey,
+  Signer,
+  Umi,
+  generateSigner,
+  some,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  createCollectionV1,
+  createV1,
+  mplCore,
+} from '@metaplex-foundation/mpl-core';
+import {
+  InscriptionMetadata,
+  Key,
+  fetchInscriptionMetadata,
+  fetchInscriptionRank,
+  findInscriptionMetadataPda,
+  findInscriptionRankPda,
+  findInscriptionShardPda,
+  findMintInscriptionPda,
+  findNextInscriptionRankPda,
+  initializeFromCoreAsset,
+} from '../src';
+import { createUmi } from './_setup';
+
+async function createCollection(umi: Umi, updateAuthority?: PublicKey) {
+  const collection = generateSigner(umi);
+  await createCollectionV1(umi, {
+    collection,
+    name: 'My Collection',
+    uri: 'https://example.com/my-collection.json',
+    updateAuthority,
+  }).sendAndConfirm(umi);
+
+  return collection.publicKey;
+}
+
+async function createAsset(
+  umi: Umi,
+  input: {
+    updateAuthority?: PublicKey;
+    collection?: PublicKey;
+    authority?: Signer;
+  }
+) {
+  const asset = generateSigner(umi);
+  await createV1(umi, {
+    asset,
+    name: 'My Asset',
+    uri: 'https://example.com/my-asset.json',
+    ...input,
+  }).sendAndConfirm(umi);
+
+  return asset.publicKey;
+}
+
+test('the update authority of an asset can inscribe it', async (t) => {
+  // Given an mpl-core asset whose update authority is the identity.
+  const umi = (await createUmi()).use(mplCore());
+  const asset = await createAsset(umi, {
+    updateAuthority: umi.identity.publicKey,
+  });
+
+  const mintInscriptionAccount = findMintInscriptionPda(umi, { mint: asset });
+  const inscriptionMetadataAccount = findInscriptionMetadataPda(umi, {
+    inscriptionAccount: mintInscriptionAccount[0],
+  });
+
+  // When we inscribe the asset, recording its rank.
+  const inscriptionShardAccount = findInscriptionShardPda(umi, {
+    shardNumber: 0,
+  });
+  await initializeFromCoreAsset(umi, {
+    mintInscriptionAccount,
+    inscriptionMetadataAccount,
+    asset,
+    inscriptionShardAccount,
+    inscriptionRankAccount: await findNextInscriptionRankPda(
+      umi,
+      inscriptionShardAccount
+    ),
+  }).sendAndConfirm(umi);
+
+  // Then the inscription is created for the asset.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.MintInscriptionMetadataAccount,
+    inscriptionAccount: mintInscriptionAccount[0],
+    updateAuthorities: [umi.identity.publicKey],
+    mint: some(asset),
+  });
+
+  // And its rank is recorded.
+  const inscriptionRank = await fetchInscriptionRank(
+    umi,
+    findInscriptionRankPda(umi, { rank: inscriptionMetadata.inscriptionRank })
+  );
+  t.is(
+    inscriptionRank.inscriptionMetadataAccount,
+    inscriptionMetadataAccount[0]
+  );
+});
+
+test('the update authority of the collection can inscribe an asset in it', async (t) => {
+  // Given an mpl-core asset in a collection whose update authority is the identity.
+  const umi = (await createUmi()).use(mplCore());
+  const collection = await createCollection(umi);
+  const asset = await createAsset(umi, { collection });
+
+  const mintInscriptionAccount = findMintInscriptionPda(umi, { mint: asset });
+  const inscriptionMetadataAccount = findInscriptionMetadataPda(umi, {
+    inscriptionAccount: mintInscriptionAccount[0],
+  });
+
+  // When we inscribe the asset through its collection.
+  await initializeFromCoreAsset(umi, {
+    mintInscriptionAccount,
+    inscriptionMetadataAccount,
+    asset,
+    collection,
+    inscriptionShardAccount: findInscriptionShardPda(umi, { shardNumber: 0 }),
+  }).sendAndConfirm(umi);
+
+  // Then the inscription is created for the asset.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.MintInscriptionMetadataAccount,
+    updateAuthorities: [umi.identity.publicKey],
+    mint: some(asset),
+  });
+});
+
+test('it cannot inscribe an asset with a collection it is not in', async (t) => {
+  // Given an mpl-core asset in a collection.
+  const umi = (await createUmi()).use(mplCore());
+  const collection = await createCollection(umi);
+  const asset = await createAsset(umi, { collection });
+
+  // And another collection with the same update authority.
+  const otherCollection = await createCollection(umi);
+
+  const mintInscriptionAccount = findMintInscriptionPda(umi, { mint: asset });
+
+  // When we try to inscribe the asset with the other collection.
+  const promise = initializeFromCoreAsset(umi, {
+    mintInscriptionAccount,
+    inscriptionMetadataAccount: findInscriptionMetadataPda(umi, {
+      inscriptionAccount: mintInscriptionAccount[0],
+    }),
+    asset,
+    collection: otherCollection,
+    inscriptionShardAccount: findInscriptionShardPda(umi, { shardNumber: 0 }),
+  }).sendAndConfirm(umi);
+
+  // Then the collection is rejected.
+  await t.throwsAsync(promise, { name: 'InvalidCoreAsset' });
+});
+
+test('it cannot inscribe an asset without being its update authority', async (t) => {
+  // Given an mpl-core asset whose update authority is someone else.
+  const umi = (await createUmi()).use(mplCore());
+  const asset = await createAsset(umi, {
+    updateAuthority: generateSigner(umi).publicKey,
+  });
+
+  const mintInscriptionAccount = findMintInscriptionPda(umi, { mint: asset });
+
+  // When the identity tries to inscribe the asset.
+  const promise = initializeFromCoreAsset(umi, {
+    mintInscriptionAccount,
+    inscriptionMetadataAccount: findInscriptionMetadataPda(umi, {
+      inscriptionAccount: mintInscriptionAccount[0],
+    }),
+    asset,
+    inscriptionShardAccount: findInscriptionShardPda(umi, { shardNumber: 0 }),
+  }).sendAndConfirm(umi);
+
+  // Then the authority is rejected.
+  await t.throwsAsync(promise, { name: 'InvalidAuthority' });
+});
+
+test('it cannot inscribe an asset without being the update authority of its collection', async (t) => {
+  // Given an mpl-core asset in a collection whose update authority is someone else.
+  const umi = (await createUmi()).use(mplCore());
+  const collectionAuthority = generateSigner(umi);
+  const collection = await createCollection(
+    umi,
+    collectionAuthority.publicKey
+  );
+  const asset = await createAsset(umi, {
+    collection,
+    authority: collectionAuthority,
+  });
+
+  const mintInscriptionAccount = findMintInscriptionPda(umi, { mint: asset });
+
+  // When the identity tries to inscribe the asset.
+  const promise = initializeFromCoreAsset(umi, {
+    mintInscriptionAccount,
+    inscriptionMetadataAccount: findInscriptionMetadataPda(umi, {
+      inscriptionAccount: mintInscriptionAccount[0],
+    }),
+    asset,
+    collection,
+    inscriptionShardAccount: findInscriptionShardPda(umi, { shardNumber: 0 }),
+  }).sendAndConfirm(umi);
+
+  // Then the authority is rejected.
+  await t.throwsAsync(promise, { name: 'InvalidAuthority' });
+});
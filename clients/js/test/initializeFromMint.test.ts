@@ -7,7 +7,11 @@ import {
   mplTokenMetadata,
 } from '@metaplex-foundation/mpl-token-metadata';
 import { publicKey as publicKeySerializer } from '@metaplex-foundation/umi/serializers';
-import { SPL_ASSOCIATED_TOKEN_PROGRAM_ID } from '@metaplex-foundation/mpl-toolbox';
+import {
+  SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedToken,
+  findAssociatedTokenPda,
+} from '@metaplex-foundation/mpl-toolbox';
 import {
   AssociatedInscription,
   DataType,
@@ -15,13 +19,19 @@ import {
   Key,
   MPL_INSCRIPTION_PROGRAM_ID,
   fetchInscriptionMetadata,
+  fetchInscriptionRank,
   fetchInscriptionShard,
   findInscriptionMetadataPda,
+  findInscriptionRankPda,
   findInscriptionShardPda,
   findMintInscriptionPda,
   initializeFromMint,
 } from '../src';
-import { SPL_TOKEN_2022_PROGRAM_ID, createUmi } from './_setup';
+import {
+  SPL_TOKEN_2022_PROGRAM_ID,
+  createToken2022MintWithMetadata,
+  createUmi,
+} from './_setup';
 
 test('it can initialize a Mint Inscription account', async (t) => {
   // Given a Umi instance and a new signer.
@@ -60,10 +70,12 @@ test('it can initialize a Mint Inscription account', async (t) => {
   // const asset = await fetchDigitalAsset(umi, mint.publicKey);
 
   // When we create a new account.
-  await initializeFromMint(umi, {
-    mintAccount: mint.publicKey,
-    inscriptionShardAccount,
-  }).sendAndConfirm(umi);
+  await (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+      inscriptionShardAccount,
+    })
+  ).sendAndConfirm(umi);
 
   // Then an account was created with the correct data.
   const inscriptionMetadata = await fetchInscriptionMetadata(
@@ -147,10 +159,12 @@ test('it can initialize a Mint Inscription account from SPL Token 2022', async (
   // const asset = await fetchDigitalAsset(umi, mint.publicKey);
 
   // When we create a new account.
-  await initializeFromMint(umi, {
-    mintAccount: mint.publicKey,
-    inscriptionShardAccount,
-  }).sendAndConfirm(umi);
+  await (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+      inscriptionShardAccount,
+    })
+  ).sendAndConfirm(umi);
 
   // Then an account was created with the correct data.
   const inscriptionMetadata = await fetchInscriptionMetadata(
@@ -216,11 +230,13 @@ test('it cannot initialize a Mint Inscription account if it is not the update au
   // const asset = await fetchDigitalAsset(umi, mint.publicKey);
 
   // When we create a new account.
-  const promise = initializeFromMint(umi, {
-    mintAccount: mint.publicKey,
-    authority,
-    inscriptionShardAccount,
-  }).sendAndConfirm(umi);
+  const promise = (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+      authority,
+      inscriptionShardAccount,
+    })
+  ).sendAndConfirm(umi);
 
   await t.throwsAsync(promise, { name: 'InvalidAuthority' });
 
@@ -271,11 +287,13 @@ test('it can initialize a Mint Inscription account with separate authority', asy
   });
 
   // When we create a new account.
-  await initializeFromMint(umi, {
-    mintAccount: mint.publicKey,
-    authority,
-    inscriptionShardAccount,
-  }).sendAndConfirm(umi);
+  await (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+      authority,
+      inscriptionShardAccount,
+    })
+  ).sendAndConfirm(umi);
 
   // Then an account was created with the correct data.
   const inscriptionMetadata = await fetchInscriptionMetadata(
@@ -309,3 +327,376 @@ test('it can initialize a Mint Inscription account with separate authority', asy
     });
   }
 });
+
+test('it can initialize a Mint Inscription account as the holder of the NFT', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  umi.use(mplTokenMetadata());
+
+  const holder = generateSigner(umi);
+
+  const mint = generateSigner(umi);
+  await createV1(umi, {
+    mint,
+    name: 'My NFT',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+    sellerFeeBasisPoints: percentAmount(5.5),
+    tokenStandard: TokenStandard.NonFungible,
+  }).sendAndConfirm(umi);
+
+  // And the NFT is held by a different wallet than the update authority.
+  await mintV1(umi, {
+    mint: mint.publicKey,
+    tokenStandard: TokenStandard.NonFungible,
+    tokenOwner: holder.publicKey,
+  }).sendAndConfirm(umi);
+
+  const tokenAccount = findAssociatedTokenPda(umi, {
+    mint: mint.publicKey,
+    owner: holder.publicKey,
+  });
+
+  const inscriptionAccount = findMintInscriptionPda(umi, {
+    mint: mint.publicKey,
+  });
+  const inscriptionMetadataAccount = findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount[0],
+  });
+
+  // When the holder creates a new account with their token account.
+  await (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+      authority: holder,
+      tokenAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then an account was created with the holder recorded next to the update authority.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.MintInscriptionMetadataAccount,
+    inscriptionAccount: inscriptionAccount[0],
+    bump: inscriptionMetadataAccount[1],
+    updateAuthorities: [umi.identity.publicKey],
+    mint: some(mint.publicKey),
+    mintUpdateAuthority: some(umi.identity.publicKey),
+    mintHolder: some(holder.publicKey),
+  });
+});
+
+test('it cannot initialize a Mint Inscription account with an empty token account', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  umi.use(mplTokenMetadata());
+
+  const holder = generateSigner(umi);
+
+  const mint = generateSigner(umi);
+  await createV1(umi, {
+    mint,
+    name: 'My NFT',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+    sellerFeeBasisPoints: percentAmount(5.5),
+    tokenStandard: TokenStandard.NonFungible,
+  }).sendAndConfirm(umi);
+
+  await mintV1(umi, {
+    mint: mint.publicKey,
+    tokenStandard: TokenStandard.NonFungible,
+  }).sendAndConfirm(umi);
+
+  // And a wallet with an empty token account for the mint.
+  await createAssociatedToken(umi, {
+    mint: mint.publicKey,
+    owner: holder.publicKey,
+  }).sendAndConfirm(umi);
+
+  const tokenAccount = findAssociatedTokenPda(umi, {
+    mint: mint.publicKey,
+    owner: holder.publicKey,
+  });
+
+  const inscriptionShardAccount = findInscriptionShardPda(umi, {
+    shardNumber: 0,
+  });
+  const shardDataBefore = await fetchInscriptionShard(
+    umi,
+    inscriptionShardAccount
+  );
+
+  // When the wallet tries to create a new account.
+  const promise = (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+      authority: holder,
+      inscriptionShardAccount,
+      tokenAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'NotEnoughTokens' });
+
+  const shardDataAfter = await fetchInscriptionShard(
+    umi,
+    inscriptionShardAccount
+  );
+  t.is(shardDataBefore.count, shardDataAfter.count);
+});
+
+test('it cannot initialize a Mint Inscription account from a Fungible token', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  umi.use(mplTokenMetadata());
+
+  const mint = generateSigner(umi);
+  await createV1(umi, {
+    mint,
+    name: 'My Fungible',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+    sellerFeeBasisPoints: percentAmount(5.5),
+    tokenStandard: TokenStandard.Fungible,
+  }).sendAndConfirm(umi);
+
+  const inscriptionShardAccount = findInscriptionShardPda(umi, {
+    shardNumber: 0,
+  });
+  const shardDataBefore = await fetchInscriptionShard(
+    umi,
+    inscriptionShardAccount
+  );
+
+  // When we create a new account.
+  const promise = (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+      inscriptionShardAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidTokenStandard' });
+
+  const shardDataAfter = await fetchInscriptionShard(
+    umi,
+    inscriptionShardAccount
+  );
+  t.is(shardDataBefore.count, shardDataAfter.count);
+});
+
+test('it cannot initialize a Mint Inscription account from a Fungible Asset', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  umi.use(mplTokenMetadata());
+
+  const mint = generateSigner(umi);
+  await createV1(umi, {
+    mint,
+    name: 'My Fungible Asset',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+    sellerFeeBasisPoints: percentAmount(5.5),
+    tokenStandard: TokenStandard.FungibleAsset,
+  }).sendAndConfirm(umi);
+
+  // When we create a new account.
+  const promise = (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidTokenStandard' });
+});
+
+test('it cannot initialize a Mint Inscription account from a Fungible SPL Token 2022', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  umi.use(mplTokenMetadata());
+
+  const mint = generateSigner(umi);
+  await createV1(umi, {
+    mint,
+    name: 'My Fungible',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+    sellerFeeBasisPoints: percentAmount(5.5),
+    splTokenProgram: SPL_TOKEN_2022_PROGRAM_ID,
+    tokenStandard: TokenStandard.Fungible,
+  }).sendAndConfirm(umi);
+
+  // When we create a new account.
+  const promise = (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidTokenStandard' });
+});
+
+test('it can initialize a Mint Inscription account from a Programmable NFT', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  umi.use(mplTokenMetadata());
+
+  const mint = generateSigner(umi);
+  await createV1(umi, {
+    mint,
+    name: 'My Programmable NFT',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+    sellerFeeBasisPoints: percentAmount(5.5),
+    tokenStandard: TokenStandard.ProgrammableNonFungible,
+  }).sendAndConfirm(umi);
+
+  await mintV1(umi, {
+    mint: mint.publicKey,
+    tokenStandard: TokenStandard.ProgrammableNonFungible,
+  }).sendAndConfirm(umi);
+
+  const inscriptionAccount = findMintInscriptionPda(umi, {
+    mint: mint.publicKey,
+  });
+  const inscriptionMetadataAccount = findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount[0],
+  });
+
+  // When we create a new account.
+  await (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then an account was created with the correct data.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.MintInscriptionMetadataAccount,
+    inscriptionAccount: inscriptionAccount[0],
+    updateAuthorities: [umi.identity.publicKey],
+    mint: some(mint.publicKey),
+  });
+});
+
+test('it can initialize a Mint Inscription account from Token 2022 metadata', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const mint = generateSigner(umi);
+
+  // And a Token 2022 mint that stores its own metadata.
+  await createToken2022MintWithMetadata(umi, mint, {
+    name: 'My NFT',
+    symbol: 'NFT',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+  });
+
+  const inscriptionAccount = findMintInscriptionPda(umi, {
+    mint: mint.publicKey,
+  });
+  const inscriptionMetadataAccount = findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount[0],
+  });
+
+  // When we create a new account using the mint as the metadata account.
+  await (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+      tokenMetadataAccount: mint.publicKey,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then an account was created with the correct data.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.MintInscriptionMetadataAccount,
+    inscriptionAccount: inscriptionAccount[0],
+    bump: inscriptionMetadataAccount[1],
+    updateAuthorities: [umi.identity.publicKey],
+    mint: some(mint.publicKey),
+  });
+});
+
+test('it cannot initialize a Mint Inscription account from Token 2022 metadata if it is not the update authority', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const mint = generateSigner(umi);
+  const authority = generateSigner(umi);
+
+  // And a Token 2022 mint that stores its own metadata.
+  await createToken2022MintWithMetadata(umi, mint, {
+    name: 'My NFT',
+    symbol: 'NFT',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+  });
+
+  // When we create a new account with a different authority.
+  const promise = (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+      tokenMetadataAccount: mint.publicKey,
+      authority,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidAuthority' });
+});
+
+test('it records the rank of a Mint Inscription account by default', async (t) => {
+  // Given a Umi instance and a new NFT.
+  const umi = await createUmi();
+  umi.use(mplTokenMetadata());
+
+  const mint = generateSigner(umi);
+  await createV1(umi, {
+    mint,
+    name: 'My NFT',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+    sellerFeeBasisPoints: percentAmount(5.5),
+    tokenStandard: TokenStandard.NonFungible,
+  }).sendAndConfirm(umi);
+
+  const inscriptionAccount = findMintInscriptionPda(umi, {
+    mint: mint.publicKey,
+  });
+  const inscriptionMetadataAccount = findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount[0],
+  });
+
+  // When we create the inscription without passing a rank account.
+  await (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+    })
+  ).sendAndConfirm(umi);
+
+  // Then the rank account of its rank points to the inscription's metadata.
+  const { inscriptionRank: rank } = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+  const inscriptionRankAccount = findInscriptionRankPda(umi, { rank });
+  const inscriptionRank = await fetchInscriptionRank(
+    umi,
+    inscriptionRankAccount
+  );
+
+  t.like(inscriptionRank, {
+    key: Key.InscriptionRankAccount,
+    bump: inscriptionRankAccount[1],
+    inscriptionMetadataAccount: inscriptionMetadataAccount[0],
+  });
+});
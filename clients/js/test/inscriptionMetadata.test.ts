@@ -0,0 +1,114 @@
+import { generateSigner, none } from '@metaplex-foundation/umi';
+import {
+  array,
+  bool,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import test from 'ava';
+import {
+  AuthorityRole,
+  DataType,
+  InscriptionMetadataAccountData,
+  Key,
+  getAssociatedInscriptionSerializer,
+  getDataTypeSerializer,
+  getInscriptionMetadataAccountDataSerializer,
+  getKeySerializer,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can deserialize inscription metadata in the original layout', async (t) => {
+  // Given inscription metadata serialized in the original layout.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi).publicKey;
+  const legacyData = struct<any>([
+    ['key', getKeySerializer()],
+    ['inscriptionAccount', publicKeySerializer()],
+    ['bump', u8()],
+    ['dataType', getDataTypeSerializer()],
+    ['inscriptionRank', u64()],
+    ['inscriptionBump', option(u8())],
+    ['updateAuthorities', array(publicKeySerializer())],
+    ['associatedInscriptions', array(getAssociatedInscriptionSerializer())],
+    ['mint', option(publicKeySerializer())],
+    ['padding', array(u8(), { size: 7 })],
+  ]).serialize({
+    key: Key.InscriptionMetadataAccount,
+    inscriptionAccount,
+    bump: 254,
+    dataType: DataType.Binary,
+    inscriptionRank: 7,
+    inscriptionBump: null,
+    updateAuthorities: [umi.identity.publicKey],
+    associatedInscriptions: [],
+    mint: null,
+    padding: Array(7).fill(0),
+  });
+
+  // When we deserialize it.
+  const [inscriptionMetadata] =
+    getInscriptionMetadataAccountDataSerializer().deserialize(legacyData);
+
+  // Then the original fields are read and the appended ones are defaulted.
+  t.like(inscriptionMetadata, <InscriptionMetadataAccountData>{
+    key: Key.InscriptionMetadataAccount,
+    inscriptionAccount,
+    inscriptionRank: BigInt(7),
+    updateAuthorities: [umi.identity.publicKey],
+    frozen: false,
+    contentHash: none(),
+    contentLength: BigInt(0),
+    authorityRoles: [AuthorityRole.Admin],
+    writeDelegates: [],
+    authorityThreshold: 0,
+    mintUpdateAuthority: none(),
+    rentPayer: none(),
+    associatedContentTypes: [],
+    mintHolder: none(),
+  });
+});
+
+test('it can round-trip inscription metadata in the current layout', async (t) => {
+  // Given inscription metadata with every appended field set.
+  const umi = await createUmi();
+  const serializer = getInscriptionMetadataAccountDataSerializer();
+  const data = serializer.serialize({
+    key: Key.MintInscriptionMetadataAccount,
+    inscriptionAccount: generateSigner(umi).publicKey,
+    bump: 254,
+    dataType: DataType.Json,
+    inscriptionRank: 7,
+    inscriptionBump: 253,
+    updateAuthorities: [umi.identity.publicKey],
+    associatedInscriptions: [],
+    mint: generateSigner(umi).publicKey,
+    frozen: true,
+    padding: Array(6).fill(0),
+    contentHash: Array(32).fill(1),
+    contentLength: 32,
+    authorityRoles: [AuthorityRole.Writer],
+    writeDelegates: [],
+    authorityThreshold: 1,
+    mintUpdateAuthority: umi.identity.publicKey,
+    rentPayer: umi.identity.publicKey,
+    associatedContentTypes: [],
+    mintHolder: umi.identity.publicKey,
+  });
+
+  // When we deserialize it.
+  const [inscriptionMetadata, offset] = serializer.deserialize(data);
+
+  // Then every field is read back.
+  t.is(offset, data.length);
+  t.like(inscriptionMetadata, <InscriptionMetadataAccountData>{
+    frozen: true,
+    contentLength: BigInt(32),
+    authorityRoles: [AuthorityRole.Writer],
+    authorityThreshold: 1,
+  });
+  t.deepEqual(serializer.serialize(inscriptionMetadata), data);
+});
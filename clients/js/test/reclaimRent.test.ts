@@ -0,0 +1,182 @@
+import test from 'ava';
+import { generateSigner, sol } from '@metaplex-foundation/umi';
+import { transferSol } from '@metaplex-foundation/mpl-toolbox';
+import {
+  AuthorityRole,
+  DataType,
+  Key,
+  addAuthority,
+  findInscriptionMetadataPda,
+  getInscriptionMetadataAccountDataSerializer,
+  initialize,
+  reclaimRent,
+  removeAuthority,
+  writeData,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can reclaim rent from a shrunk metadata account', async (t) => {
+  // Given a Umi instance and an inscription whose metadata account shrunk.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const authority = generateSigner(umi);
+  const destination = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      addAuthority(umi, {
+        inscriptionMetadataAccount,
+        newAuthority: authority.publicKey,
+        role: AuthorityRole.Writer,
+      })
+    )
+    .add(
+      removeAuthority(umi, {
+        inscriptionMetadataAccount,
+        target: authority.publicKey,
+        allowLastAuthority: false,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we reclaim the excess rent to a destination.
+  await reclaimRent(umi, {
+    inscriptionMetadataAccount,
+    targetAccount: inscriptionMetadataAccount,
+    destination: destination.publicKey,
+    associatedTag: null,
+  }).sendAndConfirm(umi);
+
+  // Then the destination received the excess lamports.
+  const balance = await umi.rpc.getBalance(destination.publicKey);
+  t.true(balance.basisPoints > BigInt(0));
+
+  // And the metadata account is still rent exempt.
+  const account = await umi.rpc.getAccount(inscriptionMetadataAccount[0]);
+  if (account.exists) {
+    const rent = await umi.rpc.getRent(account.data.length);
+    t.is(account.lamports.basisPoints, rent.basisPoints);
+  } else {
+    t.fail('Metadata account does not exist');
+  }
+});
+
+test('it cannot reclaim rent from an unrelated account', async (t) => {
+  // Given a Umi instance and two inscriptions.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const otherInscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      await initialize(umi, {
+        inscriptionAccount: otherInscriptionAccount,
+        // Both inscriptions can land in the same shard, so only the first records its rank.
+        inscriptionRankAccount: null,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we try to reclaim rent from the other inscription.
+  const promise = reclaimRent(umi, {
+    inscriptionMetadataAccount,
+    targetAccount: otherInscriptionAccount.publicKey,
+    associatedTag: null,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'DerivedKeyInvalid' });
+});
+
+test('it cannot reclaim rent through forged metadata', async (t) => {
+  // Given a victim inscription holding more lamports than it needs.
+  const umi = await createUmi();
+  const victimOwner = generateSigner(umi);
+  const victimInscription = generateSigner(umi);
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount: victimInscription,
+      authority: victimOwner,
+    })
+  )
+    .add(
+      transferSol(umi, {
+        destination: victimInscription.publicKey,
+        amount: sol(1),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And an attacker inscription holding metadata bytes that name the attacker.
+  const attackerInscription = generateSigner(umi);
+  const attackerMetadataAccount = findInscriptionMetadataPda(umi, {
+    inscriptionAccount: attackerInscription.publicKey,
+  });
+  const serializer = getInscriptionMetadataAccountDataSerializer();
+  const forgedMetadata = serializer.serialize({
+    key: Key.InscriptionMetadataAccount,
+    inscriptionAccount: victimInscription.publicKey,
+    bump: 255,
+    dataType: DataType.Uninitialized,
+    inscriptionRank: 0,
+    inscriptionBump: null,
+    updateAuthorities: [umi.identity.publicKey],
+    associatedInscriptions: [],
+    mint: null,
+    frozen: false,
+    padding: Array(6).fill(0),
+    contentHash: null,
+    contentLength: 0,
+    authorityRoles: [AuthorityRole.Admin],
+    writeDelegates: [],
+    authorityThreshold: 0,
+    mintUpdateAuthority: null,
+    rentPayer: null,
+    associatedContentTypes: [],
+    mintHolder: null,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount: attackerInscription,
+    })
+  )
+    .add(
+      writeData(umi, {
+        inscriptionAccount: attackerInscription.publicKey,
+        inscriptionMetadataAccount: attackerMetadataAccount,
+        value: forgedMetadata,
+        associatedTag: null,
+        offset: 0,
+        precondition: null,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the attacker passes their inscription as the metadata of the victim.
+  const promise = reclaimRent(umi, {
+    inscriptionMetadataAccount: attackerInscription.publicKey,
+    targetAccount: victimInscription.publicKey,
+    associatedTag: null,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'DerivedKeyInvalid' });
+});
@@ -0,0 +1,187 @@
+import test from 'ava';
+import { TransactionBuilder, generateSigner } from '@metaplex-foundation/umi';
+import {
+  DataType,
+  InscriptionMetadata,
+  Key,
+  fetchInscriptionMetadata,
+  findAssociatedInscriptionPda,
+  findInscriptionMetadataPda,
+  initialize,
+  initializeAssociatedInscription,
+  setDataType,
+  writeData,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can set the JSON data type on an inscription', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  let builder = new TransactionBuilder();
+
+  // When we create a new account with valid JSON data.
+  builder = builder.add(
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  );
+
+  builder = builder.add(
+    writeData(umi, {
+      inscriptionAccount: inscriptionAccount.publicKey,
+      inscriptionMetadataAccount,
+      value: Buffer.from(
+        '{"description": "A bread! But on-chain!", "external_url": "https://breadheads.io"}'
+      ),
+      associatedTag: null,
+      offset: 0,
+      precondition: null,
+    })
+  );
+
+  // And set the data type.
+  builder = builder.add(
+    setDataType(umi, {
+      inscriptionAccount: inscriptionAccount.publicKey,
+      inscriptionMetadataAccount,
+      associatedTag: null,
+      dataType: DataType.Json,
+    })
+  );
+
+  await builder.sendAndConfirm(umi);
+
+  // Then the data type was recorded.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.InscriptionMetadataAccount,
+    bump: inscriptionMetadataAccount[1],
+    dataType: DataType.Json,
+    updateAuthorities: [umi.identity.publicKey],
+  });
+});
+
+test('it cannot set the JSON data type on invalid JSON', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  let builder = new TransactionBuilder();
+
+  // When we create a new account with truncated JSON data.
+  builder = builder.add(
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  );
+
+  builder = builder.add(
+    writeData(umi, {
+      inscriptionAccount: inscriptionAccount.publicKey,
+      inscriptionMetadataAccount,
+      value: Buffer.from('{"description": "This will fail!"'),
+      associatedTag: null,
+      offset: 0,
+      precondition: null,
+    })
+  );
+
+  await builder.sendAndConfirm(umi);
+
+  // Then setting the JSON data type fails.
+  const promise = setDataType(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    associatedTag: null,
+    dataType: DataType.Json,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { name: 'InvalidJson' });
+
+  // And the data type is unchanged.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    dataType: DataType.Uninitialized,
+  });
+});
+
+test('it can set the data type on an associated inscription', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  let builder = new TransactionBuilder();
+
+  // When we create a new account and an associated inscription.
+  builder = builder.add(
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  );
+
+  builder = builder.add(
+    initializeAssociatedInscription(umi, {
+      inscriptionAccount: inscriptionAccount.publicKey,
+      associationTag: 'image',
+      initialSize: null,
+      dataType: DataType.Uninitialized,
+      contentType: null,
+    })
+  );
+
+  const associatedInscriptionAccount = findAssociatedInscriptionPda(umi, {
+    associated_tag: 'image',
+    inscriptionMetadataAccount,
+  });
+
+  // And set the associated data type.
+  builder = builder.add(
+    setDataType(umi, {
+      inscriptionAccount: associatedInscriptionAccount,
+      inscriptionMetadataAccount,
+      associatedTag: 'image',
+      dataType: DataType.Binary,
+    })
+  );
+
+  await builder.sendAndConfirm(umi);
+
+  // Then only the associated inscription's data type was recorded.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    dataType: DataType.Uninitialized,
+    associatedInscriptions: [
+      {
+        tag: 'image',
+        bump: associatedInscriptionAccount[1],
+        dataType: DataType.Binary,
+      },
+    ],
+  });
+});
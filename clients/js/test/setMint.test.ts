@@ -8,6 +8,7 @@ import test from 'ava';
 import {
   TokenStandard,
   createV1,
+  findMetadataPda,
   mintV1,
   mplTokenMetadata,
 } from '@metaplex-foundation/mpl-token-metadata';
@@ -23,9 +24,14 @@ import {
   findMintInscriptionPda,
   initialize,
   initializeFromMint,
+  removeAuthority,
   setMint,
 } from '../src';
-import { createUmi, SPL_TOKEN_2022_PROGRAM_ID } from './_setup';
+import {
+  createUmi,
+  createToken2022MintWithMetadata,
+  SPL_TOKEN_2022_PROGRAM_ID,
+} from './_setup';
 
 test('it can set the mint on a Mint Inscription account', async (t) => {
   // Given a Umi instance and a new signer.
@@ -57,7 +63,7 @@ test('it can set the mint on a Mint Inscription account', async (t) => {
 
   // When we create a new account.
   builder = builder.append(
-    initializeFromMint(umi, {
+    await initializeFromMint(umi, {
       mintAccount: mint.publicKey,
     })
   );
@@ -68,6 +74,7 @@ test('it can set the mint on a Mint Inscription account', async (t) => {
       mintInscriptionAccount: inscriptionAccount,
       inscriptionMetadataAccount,
       mintAccount: mint.publicKey,
+      backfillInscriptionBump: false,
     })
   );
 
@@ -120,7 +127,7 @@ test('it cannot set the mint on an Inscription account that is not derived from
 
   // When we create a new account.
   builder = builder.append(
-    initialize(umi, {
+    await initialize(umi, {
       inscriptionAccount,
     })
   );
@@ -131,6 +138,7 @@ test('it cannot set the mint on an Inscription account that is not derived from
       mintInscriptionAccount: inscriptionAccount.publicKey,
       inscriptionMetadataAccount,
       mintAccount: mint.publicKey,
+      backfillInscriptionBump: false,
     })
   );
 
@@ -184,7 +192,7 @@ test('it cannot set the wrong mint on a Mint Inscription account', async (t) =>
 
   // When we create a new account.
   builder = builder.append(
-    initializeFromMint(umi, {
+    await initializeFromMint(umi, {
       mintAccount: mint.publicKey,
     })
   );
@@ -195,6 +203,7 @@ test('it cannot set the wrong mint on a Mint Inscription account', async (t) =>
       mintInscriptionAccount: inscriptionAccount,
       inscriptionMetadataAccount,
       mintAccount: wrongMint.publicKey,
+      backfillInscriptionBump: false,
     })
   );
 
@@ -246,7 +255,7 @@ test('it can mint from SPL Token 2022', async (t) => {
 
   // When we create a new account.
   builder = builder.append(
-    initializeFromMint(umi, {
+    await initializeFromMint(umi, {
       mintAccount: mint.publicKey,
     })
   );
@@ -257,6 +266,7 @@ test('it can mint from SPL Token 2022', async (t) => {
       mintInscriptionAccount: inscriptionAccount,
       inscriptionMetadataAccount,
       mintAccount: mint.publicKey,
+      backfillInscriptionBump: false,
     })
   );
 
@@ -278,3 +288,162 @@ test('it can mint from SPL Token 2022', async (t) => {
     mint: some(mint.publicKey),
   });
 });
+
+test('it cannot set the mint without being an authority', async (t) => {
+  // Given a Umi instance and a Mint Inscription.
+  const umi = await createUmi();
+  umi.use(mplTokenMetadata());
+
+  const mint = generateSigner(umi);
+  await createV1(umi, {
+    mint,
+    name: 'My NFT',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+    sellerFeeBasisPoints: percentAmount(5.5),
+    tokenStandard: TokenStandard.NonFungible,
+  }).sendAndConfirm(umi);
+
+  await mintV1(umi, {
+    mint: mint.publicKey,
+    tokenStandard: TokenStandard.NonFungible,
+  }).sendAndConfirm(umi);
+
+  const inscriptionAccount = findMintInscriptionPda(umi, {
+    mint: mint.publicKey,
+  });
+  const inscriptionMetadataAccount = findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount[0],
+  });
+
+  await (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+    })
+  ).sendAndConfirm(umi);
+
+  // When a stranger sets the mint.
+  const promise = setMint(umi, {
+    mintInscriptionAccount: inscriptionAccount,
+    inscriptionMetadataAccount,
+    mintAccount: mint.publicKey,
+    authority: generateSigner(umi),
+    backfillInscriptionBump: true,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidAuthority' });
+});
+
+test('the update authority of the mint can set the mint and backfill the inscription bump', async (t) => {
+  // Given a Umi instance and a Mint Inscription.
+  const umi = await createUmi();
+  umi.use(mplTokenMetadata());
+
+  const mint = generateSigner(umi);
+  await createV1(umi, {
+    mint,
+    name: 'My NFT',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+    sellerFeeBasisPoints: percentAmount(5.5),
+    tokenStandard: TokenStandard.NonFungible,
+  }).sendAndConfirm(umi);
+
+  const inscriptionAccount = findMintInscriptionPda(umi, {
+    mint: mint.publicKey,
+  });
+  const inscriptionMetadataAccount = findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount[0],
+  });
+
+  // And the update authority of the mint is no longer one of its authorities.
+  await (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+    })
+  )
+    .add(
+      removeAuthority(umi, {
+        inscriptionMetadataAccount,
+        target: null,
+        allowLastAuthority: true,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the update authority of the mint sets the mint with a backfill.
+  await setMint(umi, {
+    mintInscriptionAccount: inscriptionAccount,
+    inscriptionMetadataAccount,
+    mintAccount: mint.publicKey,
+    tokenMetadataAccount: findMetadataPda(umi, { mint: mint.publicKey }),
+    backfillInscriptionBump: true,
+  }).sendAndConfirm(umi);
+
+  // Then the inscription bump is recorded.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.MintInscriptionMetadataAccount,
+    inscriptionBump: some(inscriptionAccount[1]),
+    updateAuthorities: [],
+    mint: some(mint.publicKey),
+  });
+});
+
+test('the update authority of a Token 2022 mint with embedded metadata can set the mint', async (t) => {
+  // Given a Umi instance and a Token 2022 mint that stores its own metadata.
+  const umi = await createUmi();
+  const mint = generateSigner(umi);
+
+  await createToken2022MintWithMetadata(umi, mint, {
+    name: 'My NFT',
+    symbol: 'NFT',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+  });
+
+  const inscriptionAccount = findMintInscriptionPda(umi, {
+    mint: mint.publicKey,
+  });
+  const inscriptionMetadataAccount = findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount[0],
+  });
+
+  // And a Mint Inscription whose authorities no longer include the update authority.
+  await (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+      tokenMetadataAccount: mint.publicKey,
+    })
+  )
+    .add(
+      removeAuthority(umi, {
+        inscriptionMetadataAccount,
+        target: null,
+        allowLastAuthority: true,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the update authority sets the mint using the mint as its metadata.
+  await setMint(umi, {
+    mintInscriptionAccount: inscriptionAccount,
+    inscriptionMetadataAccount,
+    mintAccount: mint.publicKey,
+    tokenMetadataAccount: mint.publicKey,
+    backfillInscriptionBump: true,
+  }).sendAndConfirm(umi);
+
+  // Then the mint and the inscription bump are recorded.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    inscriptionBump: some(inscriptionAccount[1]),
+    mint: some(mint.publicKey),
+  });
+});
@@ -0,0 +1,180 @@
+import test from 'ava';
+import { generateSigner, percentAmount, some } from '@metaplex-foundation/umi';
+import {
+  TokenStandard,
+  createV1,
+  mintV1,
+  mplTokenMetadata,
+  transferV1,
+  updateV1,
+} from '@metaplex-foundation/mpl-token-metadata';
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  InscriptionMetadata,
+  Key,
+  fetchInscriptionMetadata,
+  findInscriptionMetadataPda,
+  findMintInscriptionPda,
+  initializeFromMint,
+  syncAuthority,
+  writeData,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('anyone can sync a Mint Inscription with the update authority of the mint', async (t) => {
+  // Given a Umi instance and a Mint Inscription.
+  const umi = await createUmi();
+  umi.use(mplTokenMetadata());
+
+  const mint = generateSigner(umi);
+  await createV1(umi, {
+    mint,
+    name: 'My NFT',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+    sellerFeeBasisPoints: percentAmount(5.5),
+    tokenStandard: TokenStandard.NonFungible,
+  }).sendAndConfirm(umi);
+
+  await mintV1(umi, {
+    mint: mint.publicKey,
+    tokenStandard: TokenStandard.NonFungible,
+  }).sendAndConfirm(umi);
+
+  const inscriptionAccount = findMintInscriptionPda(umi, {
+    mint: mint.publicKey,
+  });
+  const inscriptionMetadataAccount = findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount[0],
+  });
+
+  await (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+    })
+  ).sendAndConfirm(umi);
+
+  // When the update authority of the mint changes.
+  const newUpdateAuthority = generateSigner(umi);
+  await updateV1(umi, {
+    mint: mint.publicKey,
+    newUpdateAuthority: some(newUpdateAuthority.publicKey),
+  }).sendAndConfirm(umi);
+
+  // And the inscription is synced.
+  await syncAuthority(umi, {
+    inscriptionMetadataAccount,
+    mintAccount: mint.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the inscription follows the new update authority.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.MintInscriptionMetadataAccount,
+    updateAuthorities: [newUpdateAuthority.publicKey],
+    mintUpdateAuthority: some(newUpdateAuthority.publicKey),
+  });
+});
+
+test('the holder of a Mint Inscription follows the NFT when it is transferred', async (t) => {
+  // Given a Umi instance and an NFT held by a different wallet than the update authority.
+  const umi = await createUmi();
+  umi.use(mplTokenMetadata());
+
+  const holder = generateSigner(umi);
+  const newHolder = generateSigner(umi);
+
+  const mint = generateSigner(umi);
+  await createV1(umi, {
+    mint,
+    name: 'My NFT',
+    uri: 'https://arweave.net/LcjCf-NDr5bhCJ0YMKGlc8m8qT_J6TDWtIuW8lbu0-A',
+    sellerFeeBasisPoints: percentAmount(5.5),
+    tokenStandard: TokenStandard.NonFungible,
+  }).sendAndConfirm(umi);
+
+  await mintV1(umi, {
+    mint: mint.publicKey,
+    tokenStandard: TokenStandard.NonFungible,
+    tokenOwner: holder.publicKey,
+  }).sendAndConfirm(umi);
+
+  const inscriptionAccount = findMintInscriptionPda(umi, {
+    mint: mint.publicKey,
+  });
+  const inscriptionMetadataAccount = findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount[0],
+  });
+
+  // And a Mint Inscription created by the holder.
+  await (
+    await initializeFromMint(umi, {
+      mintAccount: mint.publicKey,
+      authority: holder,
+      tokenAccount: findAssociatedTokenPda(umi, {
+        mint: mint.publicKey,
+        owner: holder.publicKey,
+      }),
+    })
+  ).sendAndConfirm(umi);
+
+  // When the holder transfers the NFT.
+  await transferV1(umi, {
+    mint: mint.publicKey,
+    authority: holder,
+    tokenOwner: holder.publicKey,
+    destinationOwner: newHolder.publicKey,
+    tokenStandard: TokenStandard.NonFungible,
+  }).sendAndConfirm(umi);
+
+  // And the inscription is synced with the new token account.
+  await syncAuthority(umi, {
+    inscriptionMetadataAccount,
+    mintAccount: mint.publicKey,
+    tokenAccount: findAssociatedTokenPda(umi, {
+      mint: mint.publicKey,
+      owner: newHolder.publicKey,
+    }),
+  }).sendAndConfirm(umi);
+
+  // Then the inscription follows the new holder.
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.MintInscriptionMetadataAccount,
+    updateAuthorities: [umi.identity.publicKey],
+    mintHolder: some(newHolder.publicKey),
+  });
+
+  // And the old holder can no longer write to it.
+  const promise = writeData(umi, {
+    inscriptionAccount,
+    inscriptionMetadataAccount,
+    authority: holder,
+    value: Buffer.from('Hello, world!'),
+    associatedTag: null,
+    offset: 0,
+    precondition: null,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { name: 'InvalidAuthority' });
+
+  // But the new holder can.
+  await writeData(umi, {
+    inscriptionAccount,
+    inscriptionMetadataAccount,
+    authority: newHolder,
+    value: Buffer.from('Hello, world!'),
+    associatedTag: null,
+    offset: 0,
+    precondition: null,
+  }).sendAndConfirm(umi);
+
+  t.pass();
+});
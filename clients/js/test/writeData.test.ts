@@ -2,6 +2,7 @@ import {
   TransactionBuilder,
   generateSigner,
   percentAmount,
+  some,
 } from '@metaplex-foundation/umi';
 import {
   TokenStandard,
@@ -10,7 +11,9 @@ import {
   mplTokenMetadata,
 } from '@metaplex-foundation/mpl-token-metadata';
 import test from 'ava';
+import { createHash } from 'crypto';
 import {
+  DataType,
   MPL_INSCRIPTION_PROGRAM_ID,
   allocate,
   findAssociatedInscriptionPda,
@@ -38,7 +41,7 @@ test('it can write JSON data to an inscription account', async (t) => {
 
   // When we create a new account.
   builder = builder.add(
-    initialize(umi, {
+    await initialize(umi, {
       inscriptionAccount,
     })
   );
@@ -53,6 +56,7 @@ test('it can write JSON data to an inscription account', async (t) => {
       ),
       associatedTag: null,
       offset: 0,
+      precondition: null,
     })
   );
 
@@ -105,7 +109,7 @@ test('it can write JSON data to a mint inscription account', async (t) => {
 
   // When we create a new account.
   builder = builder.add(
-    initializeFromMint(umi, {
+    await initializeFromMint(umi, {
       mintAccount: mint.publicKey,
     })
   );
@@ -120,6 +124,7 @@ test('it can write JSON data to a mint inscription account', async (t) => {
       ),
       associatedTag: null,
       offset: 0,
+      precondition: null,
     })
   );
 
@@ -156,7 +161,7 @@ test('it can write JSON data to an inscription account with a separate authority
 
   // When we create a new account.
   builder = builder.add(
-    initialize(umi, {
+    await initialize(umi, {
       inscriptionAccount,
       authority,
     })
@@ -173,6 +178,7 @@ test('it can write JSON data to an inscription account with a separate authority
       ),
       associatedTag: null,
       offset: 0,
+      precondition: null,
     })
   );
 
@@ -208,7 +214,7 @@ test('it can write JSON data to an inscription account in multiple batches', asy
 
   // When we create a new account.
   builder = builder.add(
-    initialize(umi, {
+    await initialize(umi, {
       inscriptionAccount,
     })
   );
@@ -221,6 +227,7 @@ test('it can write JSON data to an inscription account in multiple batches', asy
       value: Buffer.from('{"description": "A bread! But on-chain!"'),
       associatedTag: null,
       offset: 0,
+      precondition: null,
     })
   );
 
@@ -231,6 +238,7 @@ test('it can write JSON data to an inscription account in multiple batches', asy
       value: Buffer.from(', "external_url":'),
       associatedTag: null,
       offset: '{"description": "A bread! But on-chain!"'.length,
+      precondition: null,
     })
   );
 
@@ -242,6 +250,7 @@ test('it can write JSON data to an inscription account in multiple batches', asy
       associatedTag: null,
       offset: '{"description": "A bread! But on-chain!", "external_url":'
         .length,
+      precondition: null,
     })
   );
 
@@ -277,7 +286,7 @@ test('it can write Image data to an associated inscription account', async (t) =
 
   // When we create a new account.
   builder = builder.add(
-    initialize(umi, {
+    await initialize(umi, {
       inscriptionAccount,
     })
   );
@@ -291,6 +300,9 @@ test('it can write Image data to an associated inscription account', async (t) =
     initializeAssociatedInscription(umi, {
       inscriptionAccount: inscriptionAccount.publicKey,
       associationTag: 'image',
+      initialSize: null,
+      dataType: DataType.Uninitialized,
+      contentType: null,
     })
   );
 
@@ -312,6 +324,7 @@ test('it can write Image data to an associated inscription account', async (t) =
         value: chunk,
         associatedTag: 'image',
         offset: i,
+        precondition: null,
       }).sendAndConfirm(umi)
     );
   }
@@ -359,7 +372,7 @@ test('it can write Image data to an associated mint inscription account', async
 
   // When we create a new account.
   builder = builder.add(
-    initializeFromMint(umi, {
+    await initializeFromMint(umi, {
       mintAccount: mint.publicKey,
     })
   );
@@ -373,6 +386,9 @@ test('it can write Image data to an associated mint inscription account', async
     initializeAssociatedInscription(umi, {
       inscriptionAccount: inscriptionAccount[0],
       associationTag: 'image',
+      initialSize: null,
+      dataType: DataType.Uninitialized,
+      contentType: null,
     })
   );
 
@@ -394,6 +410,7 @@ test('it can write Image data to an associated mint inscription account', async
         value: chunk,
         associatedTag: 'image',
         offset: i,
+        precondition: null,
       }).sendAndConfirm(umi)
     );
   }
@@ -424,7 +441,7 @@ test('it can write Image data to an associated inscription account, with preallo
 
   // When we create a new account.
   builder = builder.add(
-    initialize(umi, {
+    await initialize(umi, {
       inscriptionAccount,
     })
   );
@@ -438,6 +455,9 @@ test('it can write Image data to an associated inscription account, with preallo
     initializeAssociatedInscription(umi, {
       inscriptionAccount: inscriptionAccount.publicKey,
       associationTag: 'image',
+      initialSize: null,
+      dataType: DataType.Uninitialized,
+      contentType: null,
     })
   );
 
@@ -476,6 +496,7 @@ test('it can write Image data to an associated inscription account, with preallo
         value: chunk,
         associatedTag: 'image',
         offset: i,
+        precondition: null,
       }).sendAndConfirm(umi)
     );
   }
@@ -492,3 +513,112 @@ test('it can write Image data to an associated inscription account, with preallo
     });
   }
 });
+
+test('it can write data when the precondition holds', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  // And an inscription with some data.
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      writeData(umi, {
+        inscriptionAccount: inscriptionAccount.publicKey,
+        inscriptionMetadataAccount,
+        value: Buffer.from('Hello, world!'),
+        associatedTag: null,
+        offset: 0,
+        precondition: null,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we overwrite part of the data expecting its current state.
+  await writeData(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    value: Buffer.from('Solana'),
+    associatedTag: null,
+    offset: 7,
+    precondition: some({
+      expectedLength: some(13),
+      expectedHash: some(
+        Array.from(createHash('sha256').update('world!').digest())
+      ),
+    }),
+  }).sendAndConfirm(umi);
+
+  // Then the data was written.
+  const jsonData = await umi.rpc.getAccount(inscriptionAccount.publicKey);
+  if (jsonData.exists) {
+    t.is(Buffer.from(jsonData.data).toString('utf8'), 'Hello, Solana');
+  } else {
+    t.fail('Inscription account does not exist');
+  }
+});
+
+test('it cannot write data when the precondition fails', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  // And an inscription with some data.
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      writeData(umi, {
+        inscriptionAccount: inscriptionAccount.publicKey,
+        inscriptionMetadataAccount,
+        value: Buffer.from('Hello, world!'),
+        associatedTag: null,
+        offset: 0,
+        precondition: null,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we write expecting a stale length.
+  const lengthPromise = writeData(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    value: Buffer.from('Solana'),
+    associatedTag: null,
+    offset: 7,
+    precondition: some({ expectedLength: some(5), expectedHash: null }),
+  }).sendAndConfirm(umi);
+
+  // Then the write fails.
+  await t.throwsAsync(lengthPromise, { name: 'WritePreconditionFailed' });
+
+  // And writing with a stale hash fails too.
+  const hashPromise = writeData(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    value: Buffer.from('Solana'),
+    associatedTag: null,
+    offset: 7,
+    precondition: some({
+      expectedLength: null,
+      expectedHash: some(
+        Array.from(createHash('sha256').update('Solana').digest())
+      ),
+    }),
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(hashPromise, { name: 'WritePreconditionFailed' });
+});
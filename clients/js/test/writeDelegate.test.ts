@@ -0,0 +1,315 @@
+import test from 'ava';
+import { generateSigner, sol } from '@metaplex-foundation/umi';
+import {
+  AuthorityRole,
+  InscriptionMetadata,
+  Key,
+  WriteDelegate,
+  addAuthority,
+  addWriteDelegate,
+  copyData,
+  fetchInscriptionMetadata,
+  findInscriptionMetadataPda,
+  initialize,
+  pruneWriteDelegates,
+  removeAuthority,
+  writeData,
+  writeSegments,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('a write delegate can write to an inscription until it expires', async (t) => {
+  // Given a Umi instance and an inscription with a write delegate.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const delegate = generateSigner(umi);
+  const expiresAt = BigInt(Math.floor(Date.now() / 1000) + 3600);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      addWriteDelegate(umi, {
+        inscriptionMetadataAccount,
+        delegate: delegate.publicKey,
+        expiresAt,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.InscriptionMetadataAccount,
+    writeDelegates: [{ address: delegate.publicKey, expiresAt }],
+  });
+
+  // When the delegate writes to the inscription.
+  await writeData(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    authority: delegate,
+    value: Buffer.from('Hello, world!'),
+    associatedTag: null,
+    offset: 0,
+    precondition: null,
+  }).sendAndConfirm(umi);
+
+  // Then the data was written.
+  const jsonData = await umi.rpc.getAccount(inscriptionAccount.publicKey);
+  if (jsonData.exists) {
+    t.is(Buffer.from(jsonData.data).toString('utf8'), 'Hello, world!');
+  } else {
+    t.fail('Inscription account does not exist');
+  }
+
+  // And the delegate can't manage the authorities.
+  const promise = addAuthority(umi, {
+    inscriptionMetadataAccount,
+    authority: delegate,
+    newAuthority: delegate.publicKey,
+    role: AuthorityRole.Admin,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { name: 'InvalidAuthority' });
+});
+
+test('a write delegate can write segments to an inscription', async (t) => {
+  // Given a Umi instance and an inscription with a write delegate.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const delegate = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      addWriteDelegate(umi, {
+        inscriptionMetadataAccount,
+        delegate: delegate.publicKey,
+        expiresAt: BigInt(Math.floor(Date.now() / 1000) + 3600),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the delegate writes segments to the inscription.
+  await writeSegments(umi, {
+    inscriptionMetadataAccount,
+    authority: delegate,
+    segments: [
+      {
+        associatedTag: null,
+        offset: 0,
+        value: Buffer.from('Hello, world!'),
+      },
+    ],
+  })
+    .addRemainingAccounts([
+      {
+        pubkey: inscriptionAccount.publicKey,
+        isSigner: false,
+        isWritable: true,
+      },
+    ])
+    .sendAndConfirm(umi);
+
+  // Then the data was written.
+  const inscriptionData = await umi.rpc.getAccount(
+    inscriptionAccount.publicKey
+  );
+  if (inscriptionData.exists) {
+    t.is(Buffer.from(inscriptionData.data).toString('utf8'), 'Hello, world!');
+  } else {
+    t.fail('Inscription account does not exist');
+  }
+});
+
+test('a write delegate can copy data within an inscription', async (t) => {
+  // Given a Umi instance and an inscription with data and a write delegate.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const delegate = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      writeData(umi, {
+        inscriptionAccount: inscriptionAccount.publicKey,
+        inscriptionMetadataAccount,
+        value: Buffer.from('Hello, world!'),
+        associatedTag: null,
+        offset: 0,
+        precondition: null,
+      })
+    )
+    .add(
+      addWriteDelegate(umi, {
+        inscriptionMetadataAccount,
+        delegate: delegate.publicKey,
+        expiresAt: BigInt(Math.floor(Date.now() / 1000) + 3600),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the delegate copies part of the data to the end of the inscription.
+  await copyData(umi, {
+    sourceAccount: inscriptionAccount.publicKey,
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    authority: delegate,
+    associatedTag: null,
+    sourceOffset: 0,
+    length: 5,
+    offset: 13,
+  }).sendAndConfirm(umi);
+
+  // Then the data was copied.
+  const inscriptionData = await umi.rpc.getAccount(
+    inscriptionAccount.publicKey
+  );
+  if (inscriptionData.exists) {
+    t.is(
+      Buffer.from(inscriptionData.data).toString('utf8'),
+      'Hello, world!Hello'
+    );
+  } else {
+    t.fail('Inscription account does not exist');
+  }
+});
+
+test('anyone can prune the expired write delegates', async (t) => {
+  // Given a Umi instance and an inscription with an expired write delegate.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const delegate = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      addWriteDelegate(umi, {
+        inscriptionMetadataAccount,
+        delegate: delegate.publicKey,
+        expiresAt: 1,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the expired delegate tries to write to the inscription.
+  const promise = writeData(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    authority: delegate,
+    value: Buffer.from('Hello, world!'),
+    associatedTag: null,
+    offset: 0,
+    precondition: null,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise, { name: 'InvalidAuthority' });
+
+  // And anyone can prune the delegate.
+  const pruner = generateSigner(umi);
+  await umi.rpc.airdrop(pruner.publicKey, sol(1));
+
+  await pruneWriteDelegates(umi, {
+    inscriptionMetadataAccount,
+    payer: pruner,
+  }).sendAndConfirm(umi);
+
+  const inscriptionMetadata = await fetchInscriptionMetadata(
+    umi,
+    inscriptionMetadataAccount
+  );
+
+  t.like(inscriptionMetadata, <InscriptionMetadata>{
+    key: Key.InscriptionMetadataAccount,
+    writeDelegates: [] as WriteDelegate[],
+  });
+
+  // And the pruner received the freed rent.
+  const balance = await umi.rpc.getBalance(pruner.publicKey);
+  t.true(balance.basisPoints > sol(1).basisPoints);
+});
+
+test('pruning does not pay out rent that the delegates did not free', async (t) => {
+  // Given a Umi instance and an inscription with slack in its metadata account.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+  const authority = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      addAuthority(umi, {
+        inscriptionMetadataAccount,
+        newAuthority: authority.publicKey,
+        role: AuthorityRole.Writer,
+      })
+    )
+    .add(
+      removeAuthority(umi, {
+        inscriptionMetadataAccount,
+        target: authority.publicKey,
+        allowLastAuthority: false,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  const metadataBalance = await umi.rpc.getBalance(
+    inscriptionMetadataAccount[0]
+  );
+
+  // When someone prunes while no delegate has expired.
+  const pruner = generateSigner(umi);
+  await umi.rpc.airdrop(pruner.publicKey, sol(1));
+
+  await pruneWriteDelegates(umi, {
+    inscriptionMetadataAccount,
+    payer: pruner,
+  }).sendAndConfirm(umi);
+
+  // Then the slack stays in the metadata account.
+  t.deepEqual(
+    await umi.rpc.getBalance(inscriptionMetadataAccount[0]),
+    metadataBalance
+  );
+  const balance = await umi.rpc.getBalance(pruner.publicKey);
+  t.true(balance.basisPoints < sol(1).basisPoints);
+});
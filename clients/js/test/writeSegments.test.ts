@@ -0,0 +1,136 @@
+import test from 'ava';
+import {
+  TransactionBuilder,
+  generateSigner,
+  publicKey,
+} from '@metaplex-foundation/umi';
+import {
+  DataType,
+  findAssociatedInscriptionPda,
+  findInscriptionMetadataPda,
+  initialize,
+  initializeAssociatedInscription,
+  writeSegments,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can write several segments to an inscription and its associated inscription', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  const associatedInscriptionAccount = findAssociatedInscriptionPda(umi, {
+    associated_tag: 'image',
+    inscriptionMetadataAccount,
+  });
+
+  let builder = new TransactionBuilder();
+
+  // When we create a new account and an associated inscription.
+  builder = builder.add(
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  );
+
+  builder = builder.add(
+    initializeAssociatedInscription(umi, {
+      inscriptionAccount: inscriptionAccount.publicKey,
+      associationTag: 'image',
+      initialSize: null,
+      dataType: DataType.Uninitialized,
+      contentType: null,
+    })
+  );
+
+  // And write segments to both in a single instruction.
+  builder = builder.add(
+    writeSegments(umi, {
+      inscriptionMetadataAccount,
+      segments: [
+        {
+          associatedTag: null,
+          offset: 6,
+          value: Buffer.from('world!'),
+        },
+        {
+          associatedTag: null,
+          offset: 0,
+          value: Buffer.from('Hello,'),
+        },
+        {
+          associatedTag: 'image',
+          offset: 0,
+          value: Buffer.from('Image data'),
+        },
+      ],
+    }).addRemainingAccounts([
+      {
+        pubkey: inscriptionAccount.publicKey,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: publicKey(associatedInscriptionAccount),
+        isSigner: false,
+        isWritable: true,
+      },
+    ])
+  );
+
+  await builder.sendAndConfirm(umi);
+
+  // Then every segment was written.
+  const inscriptionData = await umi.rpc.getAccount(
+    inscriptionAccount.publicKey
+  );
+  if (inscriptionData.exists) {
+    t.is(Buffer.from(inscriptionData.data).toString('utf8'), 'Hello,world!');
+  } else {
+    t.fail('Inscription account does not exist');
+  }
+
+  const imageData = await umi.rpc.getAccount(
+    publicKey(associatedInscriptionAccount)
+  );
+  if (imageData.exists) {
+    t.is(Buffer.from(imageData.data).toString('utf8'), 'Image data');
+  } else {
+    t.fail('Associated inscription account does not exist');
+  }
+});
+
+test('it cannot write segments without the inscription account', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  ).sendAndConfirm(umi);
+
+  // When we write a segment without passing the inscription account.
+  const promise = writeSegments(umi, {
+    inscriptionMetadataAccount,
+    segments: [
+      {
+        associatedTag: null,
+        offset: 0,
+        value: Buffer.from('Hello, world!'),
+      },
+    ],
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise);
+});
@@ -0,0 +1,188 @@
+import test from 'ava';
+import {
+  TransactionBuilder,
+  generateSigner,
+  publicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Key,
+  closeStagingBuffer,
+  findInscriptionMetadataPda,
+  findStagingBufferPda,
+  initialize,
+  writeData,
+  writeStagingBuffer,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it can commit data from a staging buffer', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  const stagingAccount = findStagingBufferPda(umi, {
+    inscriptionMetadataAccount,
+    authority: umi.identity.publicKey,
+  });
+
+  let builder = new TransactionBuilder();
+
+  // When we create a new account and stage the data in two chunks.
+  builder = builder.add(
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  );
+
+  builder = builder.add(
+    writeStagingBuffer(umi, {
+      stagingAccount,
+      inscriptionMetadataAccount,
+      offset: 0,
+      value: Buffer.from('Hello, '),
+    })
+  );
+
+  builder = builder.add(
+    writeStagingBuffer(umi, {
+      stagingAccount,
+      inscriptionMetadataAccount,
+      offset: 7,
+      value: Buffer.from('world!'),
+    })
+  );
+
+  await builder.sendAndConfirm(umi);
+
+  // And commit the staged data to the inscription.
+  await writeData(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    stagingAccount,
+    value: new Uint8Array(),
+    associatedTag: null,
+    offset: 0,
+    precondition: null,
+  }).sendAndConfirm(umi);
+
+  // Then the inscription holds the staged data.
+  const inscriptionData = await umi.rpc.getAccount(
+    inscriptionAccount.publicKey
+  );
+  if (inscriptionData.exists) {
+    t.is(Buffer.from(inscriptionData.data).toString('utf8'), 'Hello, world!');
+  } else {
+    t.fail('Inscription account does not exist');
+  }
+
+  // And the staging buffer was closed.
+  t.false(await umi.rpc.accountExists(publicKey(stagingAccount)));
+});
+
+test('it cannot commit a staging buffer along with a value', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  const stagingAccount = findStagingBufferPda(umi, {
+    inscriptionMetadataAccount,
+    authority: umi.identity.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      writeStagingBuffer(umi, {
+        stagingAccount,
+        inscriptionMetadataAccount,
+        offset: 0,
+        value: Buffer.from('Hello, world!'),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we commit the staged data along with a value.
+  const promise = writeData(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+    inscriptionMetadataAccount,
+    stagingAccount,
+    value: Buffer.from('Goodbye!'),
+    associatedTag: null,
+    offset: 0,
+    precondition: null,
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(promise);
+});
+
+test('it can discard a staging buffer without committing it', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const inscriptionAccount = generateSigner(umi);
+
+  const inscriptionMetadataAccount = await findInscriptionMetadataPda(umi, {
+    inscriptionAccount: inscriptionAccount.publicKey,
+  });
+
+  const stagingAccount = findStagingBufferPda(umi, {
+    inscriptionMetadataAccount,
+    authority: umi.identity.publicKey,
+  });
+
+  await (
+    await initialize(umi, {
+      inscriptionAccount,
+    })
+  )
+    .add(
+      writeStagingBuffer(umi, {
+        stagingAccount,
+        inscriptionMetadataAccount,
+        offset: 0,
+        value: Buffer.from('Hello, world!'),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And the staging buffer starts with its key.
+  const stagingData = await umi.rpc.getAccount(publicKey(stagingAccount));
+  if (stagingData.exists) {
+    t.is(stagingData.data[0], Key.StagingBufferAccount);
+    t.is(
+      Buffer.from(stagingData.data.slice(1)).toString('utf8'),
+      'Hello, world!'
+    );
+  } else {
+    t.fail('Staging account does not exist');
+  }
+
+  // When we discard the staging buffer.
+  await closeStagingBuffer(umi, {
+    stagingAccount,
+    inscriptionMetadataAccount,
+  }).sendAndConfirm(umi);
+
+  // Then the staging buffer is gone and the inscription is untouched.
+  t.false(await umi.rpc.accountExists(publicKey(stagingAccount)));
+
+  const inscriptionData = await umi.rpc.getAccount(
+    inscriptionAccount.publicKey
+  );
+  if (inscriptionData.exists) {
+    t.is(inscriptionData.data.length, 0);
+  } else {
+    t.fail('Inscription account does not exist');
+  }
+});
@@ -5,8 +5,6 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
-use crate::generated::types::AssociatedInscription;
-use crate::generated::types::DataType;
 use crate::generated::types::Key;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -14,45 +12,42 @@ use solana_program::pubkey::Pubkey;
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct InscriptionMetadata {
+pub struct InscriptionRank {
     pub key: Key,
+    pub bump: u8,
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
-    pub inscription_account: Pubkey,
-    pub bump: u8,
-    pub data_type: DataType,
-    pub inscription_rank: u64,
-    pub inscription_bump: Option<u8>,
-    pub update_authorities: Vec<Pubkey>,
-    pub associated_inscriptions: Vec<AssociatedInscription>,
-    pub mint: Option<Pubkey>,
-    pub padding: [u8; 7],
+    pub inscription_metadata_account: Pubkey,
 }
 
-impl InscriptionMetadata {
+impl InscriptionRank {
+    pub const LEN: usize = 34;
+
     pub fn create_pda(
-        inscription_account: Pubkey,
+        rank: u64,
         bump: u8,
     ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
         solana_program::pubkey::Pubkey::create_program_address(
             &[
                 "Inscription".as_bytes(),
+                "Rank".as_bytes(),
                 crate::MPL_INSCRIPTION_ID.as_ref(),
-                inscription_account.as_ref(),
+                rank.to_string().as_ref(),
                 &[bump],
             ],
             &crate::MPL_INSCRIPTION_ID,
         )
     }
 
-    pub fn find_pda(inscription_account: &Pubkey) -> (solana_program::pubkey::Pubkey, u8) {
+    pub fn find_pda(rank: u64) -> (solana_program::pubkey::Pubkey, u8) {
         solana_program::pubkey::Pubkey::find_program_address(
             &[
                 "Inscription".as_bytes(),
+                "Rank".as_bytes(),
                 crate::MPL_INSCRIPTION_ID.as_ref(),
-                inscription_account.as_ref(),
+                rank.to_string().as_ref(),
             ],
             &crate::MPL_INSCRIPTION_ID,
         )
@@ -65,7 +60,7 @@ impl InscriptionMetadata {
     }
 }
 
-impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for InscriptionMetadata {
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for InscriptionRank {
     type Error = std::io::Error;
 
     fn try_from(
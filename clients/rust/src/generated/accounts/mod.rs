@@ -6,11 +6,13 @@
 //!
 
 pub(crate) mod associated_inscription_account;
-pub(crate) mod inscription_metadata;
+pub(crate) mod inscription_rank;
 pub(crate) mod inscription_shard;
+pub(crate) mod inscription_tombstone;
 pub(crate) mod mint_inscription;
 
 pub use self::associated_inscription_account::*;
-pub use self::inscription_metadata::*;
+pub use self::inscription_rank::*;
 pub use self::inscription_shard::*;
+pub use self::inscription_tombstone::*;
 pub use self::mint_inscription::*;
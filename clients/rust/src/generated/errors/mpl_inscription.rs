@@ -67,6 +67,48 @@ pub enum MplInscriptionError {
     /// 18 (0x12) - The inscription metadata account is invalid.
     #[error("The inscription metadata account is invalid.")]
     InvalidInscriptionMetadataAccount,
+    /// 19 (0x13) - The inscription is frozen and can no longer be modified.
+    #[error("The inscription is frozen and can no longer be modified.")]
+    InscriptionFrozen,
+    /// 20 (0x14) - Invalid Compression Program
+    #[error("Invalid Compression Program")]
+    InvalidCompressionProgram,
+    /// 21 (0x15) - Invalid Core Asset
+    #[error("Invalid Core Asset")]
+    InvalidCoreAsset,
+    /// 22 (0x16) - The inscription data doesn't match the write precondition.
+    #[error("The inscription data doesn't match the write precondition.")]
+    WritePreconditionFailed,
+    /// 23 (0x17) - Can't copy more than 10 KiB of data in a single call.
+    #[error("Can't copy more than 10 KiB of data in a single call.")]
+    CopyTooLarge,
+    /// 24 (0x18) - The range to copy is outside of the source account's data.
+    #[error("The range to copy is outside of the source account's data.")]
+    InvalidCopyRange,
+    /// 25 (0x19) - Can't initialize an account with more than 10 KiB of data.
+    #[error("Can't initialize an account with more than 10 KiB of data.")]
+    InitialSizeTooLarge,
+    /// 26 (0x1A) - Removing the last authority requires explicitly allowing it.
+    #[error("Removing the last authority requires explicitly allowing it.")]
+    CannotRemoveLastAuthority,
+    /// 27 (0x1B) - The threshold can't be greater than the number of authorities.
+    #[error("The threshold can't be greater than the number of authorities.")]
+    InvalidAuthorityThreshold,
+    /// 28 (0x1C) - Not enough authorities signed to meet the threshold.
+    #[error("Not enough authorities signed to meet the threshold.")]
+    AuthorityThresholdNotMet,
+    /// 29 (0x1D) - The inscription was closed and its address is reserved by a tombstone.
+    #[error("The inscription was closed and its address is reserved by a tombstone.")]
+    InscriptionTombstoned,
+    /// 30 (0x1E) - Rent must be refunded to the account that paid for it.
+    #[error("Rent must be refunded to the account that paid for it.")]
+    InvalidRentPayer,
+    /// 31 (0x1F) - The content type can't be longer than 64 characters.
+    #[error("The content type can't be longer than 64 characters.")]
+    ContentTypeTooLong,
+    /// 32 (0x20) - The asset metadata does not match the compressed asset.
+    #[error("The asset metadata does not match the compressed asset.")]
+    InvalidAssetMetadata,
 }
 
 impl solana_program::program_error::PrintProgramError for MplInscriptionError {
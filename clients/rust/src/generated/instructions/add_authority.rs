@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::AuthorityRole;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -84,6 +85,7 @@ impl AddAuthorityInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddAuthorityInstructionArgs {
     pub new_authority: Pubkey,
+    pub role: AuthorityRole,
 }
 
 /// Instruction builder.
@@ -94,6 +96,7 @@ pub struct AddAuthorityBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     new_authority: Option<Pubkey>,
+    role: Option<AuthorityRole>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -135,6 +138,11 @@ impl AddAuthorityBuilder {
         self.new_authority = Some(new_authority);
         self
     }
+    #[inline(always)]
+    pub fn role(&mut self, role: AuthorityRole) -> &mut Self {
+        self.role = Some(role);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -170,6 +178,7 @@ impl AddAuthorityBuilder {
                 .new_authority
                 .clone()
                 .expect("new_authority is not set"),
+            role: self.role.clone().expect("role is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -326,6 +335,7 @@ impl<'a, 'b> AddAuthorityCpiBuilder<'a, 'b> {
             authority: None,
             system_program: None,
             new_authority: None,
+            role: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -369,6 +379,11 @@ impl<'a, 'b> AddAuthorityCpiBuilder<'a, 'b> {
         self.instruction.new_authority = Some(new_authority);
         self
     }
+    #[inline(always)]
+    pub fn role(&mut self, role: AuthorityRole) -> &mut Self {
+        self.instruction.role = Some(role);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -416,6 +431,7 @@ impl<'a, 'b> AddAuthorityCpiBuilder<'a, 'b> {
                 .new_authority
                 .clone()
                 .expect("new_authority is not set"),
+            role: self.instruction.role.clone().expect("role is not set"),
         };
         let instruction = AddAuthorityCpi {
             __program: self.instruction.__program,
@@ -449,6 +465,7 @@ struct AddAuthorityCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     new_authority: Option<Pubkey>,
+    role: Option<AuthorityRole>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
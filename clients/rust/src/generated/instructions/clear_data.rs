@@ -20,6 +20,8 @@ pub struct ClearData {
     pub authority: Option<solana_program::pubkey::Pubkey>,
     /// System program
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The account that paid the rent, if it isn't the payer.
+    pub rent_payer: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl ClearData {
@@ -35,7 +37,7 @@ impl ClearData {
         args: ClearDataInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.inscription_account,
             false,
@@ -61,6 +63,16 @@ impl ClearData {
             self.system_program,
             false,
         ));
+        if let Some(rent_payer) = self.rent_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                rent_payer, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = ClearDataInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -99,6 +111,7 @@ pub struct ClearDataBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    rent_payer: Option<solana_program::pubkey::Pubkey>,
     associated_tag: Option<String>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -145,6 +158,13 @@ impl ClearDataBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The account that paid the rent, if it isn't the payer.
+    #[inline(always)]
+    pub fn rent_payer(&mut self, rent_payer: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.rent_payer = rent_payer;
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn associated_tag(&mut self, associated_tag: String) -> &mut Self {
@@ -183,6 +203,7 @@ impl ClearDataBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent_payer: self.rent_payer,
         };
         let args = ClearDataInstructionArgs {
             associated_tag: self.associated_tag.clone(),
@@ -204,6 +225,8 @@ pub struct ClearDataCpiAccounts<'a, 'b> {
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account that paid the rent, if it isn't the payer.
+    pub rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `clear_data` CPI instruction.
@@ -220,6 +243,8 @@ pub struct ClearDataCpi<'a, 'b> {
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account that paid the rent, if it isn't the payer.
+    pub rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: ClearDataInstructionArgs,
 }
@@ -237,6 +262,7 @@ impl<'a, 'b> ClearDataCpi<'a, 'b> {
             payer: accounts.payer,
             authority: accounts.authority,
             system_program: accounts.system_program,
+            rent_payer: accounts.rent_payer,
             __args: args,
         }
     }
@@ -273,7 +299,7 @@ impl<'a, 'b> ClearDataCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.inscription_account.key,
             false,
@@ -301,6 +327,17 @@ impl<'a, 'b> ClearDataCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(rent_payer) = self.rent_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *rent_payer.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -317,7 +354,7 @@ impl<'a, 'b> ClearDataCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.inscription_account.clone());
         account_infos.push(self.inscription_metadata_account.clone());
@@ -326,6 +363,9 @@ impl<'a, 'b> ClearDataCpi<'a, 'b> {
             account_infos.push(authority.clone());
         }
         account_infos.push(self.system_program.clone());
+        if let Some(rent_payer) = self.rent_payer {
+            account_infos.push(rent_payer.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -352,6 +392,7 @@ impl<'a, 'b> ClearDataCpiBuilder<'a, 'b> {
             payer: None,
             authority: None,
             system_program: None,
+            rent_payer: None,
             associated_tag: None,
             __remaining_accounts: Vec::new(),
         });
@@ -400,6 +441,16 @@ impl<'a, 'b> ClearDataCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The account that paid the rent, if it isn't the payer.
+    #[inline(always)]
+    pub fn rent_payer(
+        &mut self,
+        rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rent_payer = rent_payer;
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn associated_tag(&mut self, associated_tag: String) -> &mut Self {
@@ -471,6 +522,8 @@ impl<'a, 'b> ClearDataCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            rent_payer: self.instruction.rent_payer,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -487,6 +540,7 @@ struct ClearDataCpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     associated_tag: Option<String>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -20,6 +20,10 @@ pub struct Close {
     pub authority: Option<solana_program::pubkey::Pubkey>,
     /// System program
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The account that paid the rent, if it isn't the payer.
+    pub rent_payer: Option<solana_program::pubkey::Pubkey>,
+    /// The account to send the lamports to, instead of the payer.
+    pub destination: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl Close {
@@ -35,7 +39,7 @@ impl Close {
         args: CloseInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.inscription_account,
             false,
@@ -61,6 +65,27 @@ impl Close {
             self.system_program,
             false,
         ));
+        if let Some(rent_payer) = self.rent_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                rent_payer, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(destination) = self.destination {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                destination,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = CloseInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -89,6 +114,7 @@ impl CloseInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CloseInstructionArgs {
     pub associated_tag: Option<String>,
+    pub tombstone: bool,
 }
 
 /// Instruction builder.
@@ -99,7 +125,10 @@ pub struct CloseBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    rent_payer: Option<solana_program::pubkey::Pubkey>,
+    destination: Option<solana_program::pubkey::Pubkey>,
     associated_tag: Option<String>,
+    tombstone: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -145,12 +174,34 @@ impl CloseBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The account that paid the rent, if it isn't the payer.
+    #[inline(always)]
+    pub fn rent_payer(&mut self, rent_payer: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.rent_payer = rent_payer;
+        self
+    }
+    /// `[optional account]`
+    /// The account to send the lamports to, instead of the payer.
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.destination = destination;
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn associated_tag(&mut self, associated_tag: String) -> &mut Self {
         self.associated_tag = Some(associated_tag);
         self
     }
+    #[inline(always)]
+    pub fn tombstone(&mut self, tombstone: bool) -> &mut Self {
+        self.tombstone = Some(tombstone);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -183,9 +234,12 @@ impl CloseBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent_payer: self.rent_payer,
+            destination: self.destination,
         };
         let args = CloseInstructionArgs {
             associated_tag: self.associated_tag.clone(),
+            tombstone: self.tombstone.clone().expect("tombstone is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -204,6 +258,10 @@ pub struct CloseCpiAccounts<'a, 'b> {
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account that paid the rent, if it isn't the payer.
+    pub rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The account to send the lamports to, instead of the payer.
+    pub destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `close` CPI instruction.
@@ -220,6 +278,10 @@ pub struct CloseCpi<'a, 'b> {
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account that paid the rent, if it isn't the payer.
+    pub rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The account to send the lamports to, instead of the payer.
+    pub destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: CloseInstructionArgs,
 }
@@ -237,6 +299,8 @@ impl<'a, 'b> CloseCpi<'a, 'b> {
             payer: accounts.payer,
             authority: accounts.authority,
             system_program: accounts.system_program,
+            rent_payer: accounts.rent_payer,
+            destination: accounts.destination,
             __args: args,
         }
     }
@@ -273,7 +337,7 @@ impl<'a, 'b> CloseCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.inscription_account.key,
             false,
@@ -301,6 +365,28 @@ impl<'a, 'b> CloseCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(rent_payer) = self.rent_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *rent_payer.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(destination) = self.destination {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *destination.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -317,7 +403,7 @@ impl<'a, 'b> CloseCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.inscription_account.clone());
         account_infos.push(self.inscription_metadata_account.clone());
@@ -326,6 +412,12 @@ impl<'a, 'b> CloseCpi<'a, 'b> {
             account_infos.push(authority.clone());
         }
         account_infos.push(self.system_program.clone());
+        if let Some(rent_payer) = self.rent_payer {
+            account_infos.push(rent_payer.clone());
+        }
+        if let Some(destination) = self.destination {
+            account_infos.push(destination.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -352,7 +444,10 @@ impl<'a, 'b> CloseCpiBuilder<'a, 'b> {
             payer: None,
             authority: None,
             system_program: None,
+            rent_payer: None,
+            destination: None,
             associated_tag: None,
+            tombstone: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -400,12 +495,37 @@ impl<'a, 'b> CloseCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The account that paid the rent, if it isn't the payer.
+    #[inline(always)]
+    pub fn rent_payer(
+        &mut self,
+        rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rent_payer = rent_payer;
+        self
+    }
+    /// `[optional account]`
+    /// The account to send the lamports to, instead of the payer.
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.destination = destination;
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn associated_tag(&mut self, associated_tag: String) -> &mut Self {
         self.instruction.associated_tag = Some(associated_tag);
         self
     }
+    #[inline(always)]
+    pub fn tombstone(&mut self, tombstone: bool) -> &mut Self {
+        self.instruction.tombstone = Some(tombstone);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -449,6 +569,11 @@ impl<'a, 'b> CloseCpiBuilder<'a, 'b> {
     ) -> solana_program::entrypoint::ProgramResult {
         let args = CloseInstructionArgs {
             associated_tag: self.instruction.associated_tag.clone(),
+            tombstone: self
+                .instruction
+                .tombstone
+                .clone()
+                .expect("tombstone is not set"),
         };
         let instruction = CloseCpi {
             __program: self.instruction.__program,
@@ -471,6 +596,10 @@ impl<'a, 'b> CloseCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            rent_payer: self.instruction.rent_payer,
+
+            destination: self.instruction.destination,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -487,7 +616,10 @@ struct CloseCpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     associated_tag: Option<String>,
+    tombstone: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
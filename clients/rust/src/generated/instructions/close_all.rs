@@ -0,0 +1,611 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct CloseAll {
+    /// The account where data is stored.
+    pub inscription_account: solana_program::pubkey::Pubkey,
+    /// The account to store the inscription account's metadata in.
+    pub inscription_metadata_account: solana_program::pubkey::Pubkey,
+    /// The account that will pay for the rent.
+    pub payer: solana_program::pubkey::Pubkey,
+    /// The authority of the inscription account.
+    pub authority: Option<solana_program::pubkey::Pubkey>,
+    /// System program
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// The account that paid the rent, if it isn't the payer.
+    pub rent_payer: Option<solana_program::pubkey::Pubkey>,
+    /// The account to send the lamports to, instead of the payer.
+    pub destination: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl CloseAll {
+    pub fn instruction(
+        &self,
+        args: CloseAllInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: CloseAllInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.inscription_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.inscription_metadata_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authority, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        if let Some(rent_payer) = self.rent_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                rent_payer, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(destination) = self.destination {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                destination,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = CloseAllInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_INSCRIPTION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct CloseAllInstructionData {
+    discriminator: u8,
+}
+
+impl CloseAllInstructionData {
+    fn new() -> Self {
+        Self { discriminator: 24 }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CloseAllInstructionArgs {
+    pub tombstone: bool,
+}
+
+/// Instruction builder.
+#[derive(Default)]
+pub struct CloseAllBuilder {
+    inscription_account: Option<solana_program::pubkey::Pubkey>,
+    inscription_metadata_account: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    rent_payer: Option<solana_program::pubkey::Pubkey>,
+    destination: Option<solana_program::pubkey::Pubkey>,
+    tombstone: Option<bool>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl CloseAllBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The account where data is stored.
+    #[inline(always)]
+    pub fn inscription_account(
+        &mut self,
+        inscription_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.inscription_account = Some(inscription_account);
+        self
+    }
+    /// The account to store the inscription account's metadata in.
+    #[inline(always)]
+    pub fn inscription_metadata_account(
+        &mut self,
+        inscription_metadata_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.inscription_metadata_account = Some(inscription_metadata_account);
+        self
+    }
+    /// The account that will pay for the rent.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account]`
+    /// The authority of the inscription account.
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.authority = authority;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// The account that paid the rent, if it isn't the payer.
+    #[inline(always)]
+    pub fn rent_payer(&mut self, rent_payer: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.rent_payer = rent_payer;
+        self
+    }
+    /// `[optional account]`
+    /// The account to send the lamports to, instead of the payer.
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.destination = destination;
+        self
+    }
+    #[inline(always)]
+    pub fn tombstone(&mut self, tombstone: bool) -> &mut Self {
+        self.tombstone = Some(tombstone);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = CloseAll {
+            inscription_account: self
+                .inscription_account
+                .expect("inscription_account is not set"),
+            inscription_metadata_account: self
+                .inscription_metadata_account
+                .expect("inscription_metadata_account is not set"),
+            payer: self.payer.expect("payer is not set"),
+            authority: self.authority,
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent_payer: self.rent_payer,
+            destination: self.destination,
+        };
+        let args = CloseAllInstructionArgs {
+            tombstone: self.tombstone.clone().expect("tombstone is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `close_all` CPI accounts.
+pub struct CloseAllCpiAccounts<'a, 'b> {
+    /// The account where data is stored.
+    pub inscription_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account to store the inscription account's metadata in.
+    pub inscription_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account that will pay for the rent.
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The authority of the inscription account.
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account that paid the rent, if it isn't the payer.
+    pub rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The account to send the lamports to, instead of the payer.
+    pub destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `close_all` CPI instruction.
+pub struct CloseAllCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account where data is stored.
+    pub inscription_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account to store the inscription account's metadata in.
+    pub inscription_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account that will pay for the rent.
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The authority of the inscription account.
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account that paid the rent, if it isn't the payer.
+    pub rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The account to send the lamports to, instead of the payer.
+    pub destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: CloseAllInstructionArgs,
+}
+
+impl<'a, 'b> CloseAllCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: CloseAllCpiAccounts<'a, 'b>,
+        args: CloseAllInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            inscription_account: accounts.inscription_account,
+            inscription_metadata_account: accounts.inscription_metadata_account,
+            payer: accounts.payer,
+            authority: accounts.authority,
+            system_program: accounts.system_program,
+            rent_payer: accounts.rent_payer,
+            destination: accounts.destination,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.inscription_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.inscription_metadata_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authority.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        if let Some(rent_payer) = self.rent_payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *rent_payer.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(destination) = self.destination {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *destination.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = CloseAllInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_INSCRIPTION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.inscription_account.clone());
+        account_infos.push(self.inscription_metadata_account.clone());
+        account_infos.push(self.payer.clone());
+        if let Some(authority) = self.authority {
+            account_infos.push(authority.clone());
+        }
+        account_infos.push(self.system_program.clone());
+        if let Some(rent_payer) = self.rent_payer {
+            account_infos.push(rent_payer.clone());
+        }
+        if let Some(destination) = self.destination {
+            account_infos.push(destination.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// `close_all` CPI instruction builder.
+pub struct CloseAllCpiBuilder<'a, 'b> {
+    instruction: Box<CloseAllCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CloseAllCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CloseAllCpiBuilderInstruction {
+            __program: program,
+            inscription_account: None,
+            inscription_metadata_account: None,
+            payer: None,
+            authority: None,
+            system_program: None,
+            rent_payer: None,
+            destination: None,
+            tombstone: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The account where data is stored.
+    #[inline(always)]
+    pub fn inscription_account(
+        &mut self,
+        inscription_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.inscription_account = Some(inscription_account);
+        self
+    }
+    /// The account to store the inscription account's metadata in.
+    #[inline(always)]
+    pub fn inscription_metadata_account(
+        &mut self,
+        inscription_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.inscription_metadata_account = Some(inscription_metadata_account);
+        self
+    }
+    /// The account that will pay for the rent.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// `[optional account]`
+    /// The authority of the inscription account.
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authority = authority;
+        self
+    }
+    /// System program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// The account that paid the rent, if it isn't the payer.
+    #[inline(always)]
+    pub fn rent_payer(
+        &mut self,
+        rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rent_payer = rent_payer;
+        self
+    }
+    /// `[optional account]`
+    /// The account to send the lamports to, instead of the payer.
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.destination = destination;
+        self
+    }
+    #[inline(always)]
+    pub fn tombstone(&mut self, tombstone: bool) -> &mut Self {
+        self.instruction.tombstone = Some(tombstone);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = CloseAllInstructionArgs {
+            tombstone: self
+                .instruction
+                .tombstone
+                .clone()
+                .expect("tombstone is not set"),
+        };
+        let instruction = CloseAllCpi {
+            __program: self.instruction.__program,
+
+            inscription_account: self
+                .instruction
+                .inscription_account
+                .expect("inscription_account is not set"),
+
+            inscription_metadata_account: self
+                .instruction
+                .inscription_metadata_account
+                .expect("inscription_metadata_account is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            authority: self.instruction.authority,
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            rent_payer: self.instruction.rent_payer,
+
+            destination: self.instruction.destination,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct CloseAllCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    inscription_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    inscription_metadata_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rent_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tombstone: Option<bool>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
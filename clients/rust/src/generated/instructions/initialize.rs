@@ -22,18 +22,24 @@ pub struct Initialize {
     pub authority: Option<solana_program::pubkey::Pubkey>,
     /// System program
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The account to record the inscription's rank in, for lookups by rank.
+    pub inscription_rank_account: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl Initialize {
-    pub fn instruction(&self) -> solana_program::instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(
+        &self,
+        args: InitializeInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: InitializeInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.inscription_account,
             true,
@@ -63,8 +69,21 @@ impl Initialize {
             self.system_program,
             false,
         ));
+        if let Some(inscription_rank_account) = self.inscription_rank_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                inscription_rank_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
-        let data = InitializeInstructionData::new().try_to_vec().unwrap();
+        let mut data = InitializeInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
 
         solana_program::instruction::Instruction {
             program_id: crate::MPL_INSCRIPTION_ID,
@@ -85,6 +104,12 @@ impl InitializeInstructionData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitializeInstructionArgs {
+    pub initial_size: Option<u64>,
+}
+
 /// Instruction builder.
 #[derive(Default)]
 pub struct InitializeBuilder {
@@ -94,6 +119,8 @@ pub struct InitializeBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    inscription_rank_account: Option<solana_program::pubkey::Pubkey>,
+    initial_size: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -148,6 +175,22 @@ impl InitializeBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The account to record the inscription's rank in, for lookups by rank.
+    #[inline(always)]
+    pub fn inscription_rank_account(
+        &mut self,
+        inscription_rank_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.inscription_rank_account = inscription_rank_account;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn initial_size(&mut self, initial_size: u64) -> &mut Self {
+        self.initial_size = Some(initial_size);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -183,9 +226,13 @@ impl InitializeBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            inscription_rank_account: self.inscription_rank_account,
+        };
+        let args = InitializeInstructionArgs {
+            initial_size: self.initial_size.clone(),
         };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -203,6 +250,8 @@ pub struct InitializeCpiAccounts<'a, 'b> {
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account to record the inscription's rank in, for lookups by rank.
+    pub inscription_rank_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `initialize` CPI instruction.
@@ -221,12 +270,17 @@ pub struct InitializeCpi<'a, 'b> {
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account to record the inscription's rank in, for lookups by rank.
+    pub inscription_rank_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: InitializeInstructionArgs,
 }
 
 impl<'a, 'b> InitializeCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_program::account_info::AccountInfo<'a>,
         accounts: InitializeCpiAccounts<'a, 'b>,
+        args: InitializeInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -236,6 +290,8 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
             payer: accounts.payer,
             authority: accounts.authority,
             system_program: accounts.system_program,
+            inscription_rank_account: accounts.inscription_rank_account,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -271,7 +327,7 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.inscription_account.key,
             true,
@@ -303,6 +359,17 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(inscription_rank_account) = self.inscription_rank_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *inscription_rank_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -310,14 +377,16 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = InitializeInstructionData::new().try_to_vec().unwrap();
+        let mut data = InitializeInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
 
         let instruction = solana_program::instruction::Instruction {
             program_id: crate::MPL_INSCRIPTION_ID,
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.inscription_account.clone());
         account_infos.push(self.inscription_metadata_account.clone());
@@ -327,6 +396,9 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
             account_infos.push(authority.clone());
         }
         account_infos.push(self.system_program.clone());
+        if let Some(inscription_rank_account) = self.inscription_rank_account {
+            account_infos.push(inscription_rank_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -354,6 +426,8 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
             payer: None,
             authority: None,
             system_program: None,
+            inscription_rank_account: None,
+            initial_size: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -410,6 +484,22 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The account to record the inscription's rank in, for lookups by rank.
+    #[inline(always)]
+    pub fn inscription_rank_account(
+        &mut self,
+        inscription_rank_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.inscription_rank_account = inscription_rank_account;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn initial_size(&mut self, initial_size: u64) -> &mut Self {
+        self.instruction.initial_size = Some(initial_size);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -451,6 +541,9 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
+        let args = InitializeInstructionArgs {
+            initial_size: self.instruction.initial_size.clone(),
+        };
         let instruction = InitializeCpi {
             __program: self.instruction.__program,
 
@@ -477,6 +570,9 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            inscription_rank_account: self.instruction.inscription_rank_account,
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -493,6 +589,8 @@ struct InitializeCpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    inscription_rank_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    initial_size: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::DataType;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -97,6 +98,9 @@ impl InitializeAssociatedInscriptionInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeAssociatedInscriptionInstructionArgs {
     pub association_tag: String,
+    pub initial_size: Option<u64>,
+    pub data_type: DataType,
+    pub content_type: Option<String>,
 }
 
 /// Instruction builder.
@@ -109,6 +113,9 @@ pub struct InitializeAssociatedInscriptionBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     association_tag: Option<String>,
+    initial_size: Option<u64>,
+    data_type: Option<DataType>,
+    content_type: Option<String>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -168,6 +175,23 @@ impl InitializeAssociatedInscriptionBuilder {
         self.association_tag = Some(association_tag);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn initial_size(&mut self, initial_size: u64) -> &mut Self {
+        self.initial_size = Some(initial_size);
+        self
+    }
+    #[inline(always)]
+    pub fn data_type(&mut self, data_type: DataType) -> &mut Self {
+        self.data_type = Some(data_type);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn content_type(&mut self, content_type: String) -> &mut Self {
+        self.content_type = Some(content_type);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -209,6 +233,9 @@ impl InitializeAssociatedInscriptionBuilder {
                 .association_tag
                 .clone()
                 .expect("association_tag is not set"),
+            initial_size: self.initial_size.clone(),
+            data_type: self.data_type.clone().expect("data_type is not set"),
+            content_type: self.content_type.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -389,6 +416,9 @@ impl<'a, 'b> InitializeAssociatedInscriptionCpiBuilder<'a, 'b> {
             authority: None,
             system_program: None,
             association_tag: None,
+            initial_size: None,
+            data_type: None,
+            content_type: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -450,6 +480,23 @@ impl<'a, 'b> InitializeAssociatedInscriptionCpiBuilder<'a, 'b> {
         self.instruction.association_tag = Some(association_tag);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn initial_size(&mut self, initial_size: u64) -> &mut Self {
+        self.instruction.initial_size = Some(initial_size);
+        self
+    }
+    #[inline(always)]
+    pub fn data_type(&mut self, data_type: DataType) -> &mut Self {
+        self.instruction.data_type = Some(data_type);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn content_type(&mut self, content_type: String) -> &mut Self {
+        self.instruction.content_type = Some(content_type);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -497,6 +544,13 @@ impl<'a, 'b> InitializeAssociatedInscriptionCpiBuilder<'a, 'b> {
                 .association_tag
                 .clone()
                 .expect("association_tag is not set"),
+            initial_size: self.instruction.initial_size.clone(),
+            data_type: self
+                .instruction
+                .data_type
+                .clone()
+                .expect("data_type is not set"),
+            content_type: self.instruction.content_type.clone(),
         };
         let instruction = InitializeAssociatedInscriptionCpi {
             __program: self.instruction.__program,
@@ -542,6 +596,9 @@ struct InitializeAssociatedInscriptionCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     association_tag: Option<String>,
+    initial_size: Option<u64>,
+    data_type: Option<DataType>,
+    content_type: Option<String>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
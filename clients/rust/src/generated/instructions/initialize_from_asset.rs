@@ -0,0 +1,992 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct InitializeFromAsset {
+    /// The account where data is stored.
+    pub asset_inscription_account: solana_program::pubkey::Pubkey,
+    /// The account to store the inscription account's metadata in.
+    pub inscription_metadata_account: solana_program::pubkey::Pubkey,
+    /// The compressed asset id that will be used to derive the PDA.
+    pub asset_id: solana_program::pubkey::Pubkey,
+    /// The Bubblegum config account of the Merkle tree.
+    pub tree_config: solana_program::pubkey::Pubkey,
+    /// The Merkle tree holding the compressed asset.
+    pub merkle_tree: solana_program::pubkey::Pubkey,
+    /// The owner of the compressed asset.
+    pub leaf_owner: solana_program::pubkey::Pubkey,
+    /// The delegate of the compressed asset.
+    pub leaf_delegate: solana_program::pubkey::Pubkey,
+    /// The shard account for the inscription counter.
+    pub inscription_shard_account: solana_program::pubkey::Pubkey,
+    /// The account that will pay for the rent.
+    pub payer: solana_program::pubkey::Pubkey,
+    /// The update authority of the asset, either that of its verified collection or the creator or delegate of its private tree.
+    pub authority: Option<solana_program::pubkey::Pubkey>,
+    /// System program
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// SPL Account Compression program
+    pub compression_program: solana_program::pubkey::Pubkey,
+    /// The Token Metadata account of the asset's verified collection, if the authority is its update authority.
+    pub collection_metadata: Option<solana_program::pubkey::Pubkey>,
+    /// The account to record the inscription's rank in, for lookups by rank.
+    pub inscription_rank_account: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl InitializeFromAsset {
+    pub fn instruction(
+        &self,
+        args: InitializeFromAssetInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: InitializeFromAssetInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.asset_inscription_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.inscription_metadata_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.asset_id,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.tree_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.merkle_tree,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.leaf_owner,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.leaf_delegate,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.inscription_shard_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authority, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.compression_program,
+            false,
+        ));
+        if let Some(collection_metadata) = self.collection_metadata {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                collection_metadata,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(inscription_rank_account) = self.inscription_rank_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                inscription_rank_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = InitializeFromAssetInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_INSCRIPTION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct InitializeFromAssetInstructionData {
+    discriminator: u8,
+}
+
+impl InitializeFromAssetInstructionData {
+    fn new() -> Self {
+        Self { discriminator: 13 }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitializeFromAssetInstructionArgs {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+    pub metadata: Vec<u8>,
+}
+
+/// Instruction builder.
+#[derive(Default)]
+pub struct InitializeFromAssetBuilder {
+    asset_inscription_account: Option<solana_program::pubkey::Pubkey>,
+    inscription_metadata_account: Option<solana_program::pubkey::Pubkey>,
+    asset_id: Option<solana_program::pubkey::Pubkey>,
+    tree_config: Option<solana_program::pubkey::Pubkey>,
+    merkle_tree: Option<solana_program::pubkey::Pubkey>,
+    leaf_owner: Option<solana_program::pubkey::Pubkey>,
+    leaf_delegate: Option<solana_program::pubkey::Pubkey>,
+    inscription_shard_account: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    compression_program: Option<solana_program::pubkey::Pubkey>,
+    collection_metadata: Option<solana_program::pubkey::Pubkey>,
+    inscription_rank_account: Option<solana_program::pubkey::Pubkey>,
+    root: Option<[u8; 32]>,
+    data_hash: Option<[u8; 32]>,
+    creator_hash: Option<[u8; 32]>,
+    nonce: Option<u64>,
+    index: Option<u32>,
+    metadata: Option<Vec<u8>>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl InitializeFromAssetBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The account where data is stored.
+    #[inline(always)]
+    pub fn asset_inscription_account(
+        &mut self,
+        asset_inscription_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.asset_inscription_account = Some(asset_inscription_account);
+        self
+    }
+    /// The account to store the inscription account's metadata in.
+    #[inline(always)]
+    pub fn inscription_metadata_account(
+        &mut self,
+        inscription_metadata_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.inscription_metadata_account = Some(inscription_metadata_account);
+        self
+    }
+    /// The compressed asset id that will be used to derive the PDA.
+    #[inline(always)]
+    pub fn asset_id(&mut self, asset_id: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.asset_id = Some(asset_id);
+        self
+    }
+    /// The Bubblegum config account of the Merkle tree.
+    #[inline(always)]
+    pub fn tree_config(&mut self, tree_config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.tree_config = Some(tree_config);
+        self
+    }
+    /// The Merkle tree holding the compressed asset.
+    #[inline(always)]
+    pub fn merkle_tree(&mut self, merkle_tree: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.merkle_tree = Some(merkle_tree);
+        self
+    }
+    /// The owner of the compressed asset.
+    #[inline(always)]
+    pub fn leaf_owner(&mut self, leaf_owner: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.leaf_owner = Some(leaf_owner);
+        self
+    }
+    /// The delegate of the compressed asset.
+    #[inline(always)]
+    pub fn leaf_delegate(&mut self, leaf_delegate: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.leaf_delegate = Some(leaf_delegate);
+        self
+    }
+    /// The shard account for the inscription counter.
+    #[inline(always)]
+    pub fn inscription_shard_account(
+        &mut self,
+        inscription_shard_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.inscription_shard_account = Some(inscription_shard_account);
+        self
+    }
+    /// The account that will pay for the rent.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account]`
+    /// The update authority of the asset, either that of its verified collection or the creator or delegate of its private tree.
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.authority = authority;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK']`
+    /// SPL Account Compression program
+    #[inline(always)]
+    pub fn compression_program(
+        &mut self,
+        compression_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.compression_program = Some(compression_program);
+        self
+    }
+    /// `[optional account]`
+    /// The Token Metadata account of the asset's verified collection, if the authority is its update authority.
+    #[inline(always)]
+    pub fn collection_metadata(
+        &mut self,
+        collection_metadata: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.collection_metadata = collection_metadata;
+        self
+    }
+    /// `[optional account]`
+    /// The account to record the inscription's rank in, for lookups by rank.
+    #[inline(always)]
+    pub fn inscription_rank_account(
+        &mut self,
+        inscription_rank_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.inscription_rank_account = inscription_rank_account;
+        self
+    }
+    #[inline(always)]
+    pub fn root(&mut self, root: [u8; 32]) -> &mut Self {
+        self.root = Some(root);
+        self
+    }
+    #[inline(always)]
+    pub fn data_hash(&mut self, data_hash: [u8; 32]) -> &mut Self {
+        self.data_hash = Some(data_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn creator_hash(&mut self, creator_hash: [u8; 32]) -> &mut Self {
+        self.creator_hash = Some(creator_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn nonce(&mut self, nonce: u64) -> &mut Self {
+        self.nonce = Some(nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn index(&mut self, index: u32) -> &mut Self {
+        self.index = Some(index);
+        self
+    }
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: Vec<u8>) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = InitializeFromAsset {
+            asset_inscription_account: self
+                .asset_inscription_account
+                .expect("asset_inscription_account is not set"),
+            inscription_metadata_account: self
+                .inscription_metadata_account
+                .expect("inscription_metadata_account is not set"),
+            asset_id: self.asset_id.expect("asset_id is not set"),
+            tree_config: self.tree_config.expect("tree_config is not set"),
+            merkle_tree: self.merkle_tree.expect("merkle_tree is not set"),
+            leaf_owner: self.leaf_owner.expect("leaf_owner is not set"),
+            leaf_delegate: self.leaf_delegate.expect("leaf_delegate is not set"),
+            inscription_shard_account: self
+                .inscription_shard_account
+                .expect("inscription_shard_account is not set"),
+            payer: self.payer.expect("payer is not set"),
+            authority: self.authority,
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            compression_program: self.compression_program.unwrap_or(solana_program::pubkey!(
+                "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"
+            )),
+            collection_metadata: self.collection_metadata,
+            inscription_rank_account: self.inscription_rank_account,
+        };
+        let args = InitializeFromAssetInstructionArgs {
+            root: self.root.clone().expect("root is not set"),
+            data_hash: self.data_hash.clone().expect("data_hash is not set"),
+            creator_hash: self.creator_hash.clone().expect("creator_hash is not set"),
+            nonce: self.nonce.clone().expect("nonce is not set"),
+            index: self.index.clone().expect("index is not set"),
+            metadata: self.metadata.clone().expect("metadata is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `initialize_from_asset` CPI accounts.
+pub struct InitializeFromAssetCpiAccounts<'a, 'b> {
+    /// The account where data is stored.
+    pub asset_inscription_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account to store the inscription account's metadata in.
+    pub inscription_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The compressed asset id that will be used to derive the PDA.
+    pub asset_id: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The Bubblegum config account of the Merkle tree.
+    pub tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The Merkle tree holding the compressed asset.
+    pub merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The owner of the compressed asset.
+    pub leaf_owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The delegate of the compressed asset.
+    pub leaf_delegate: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The shard account for the inscription counter.
+    pub inscription_shard_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account that will pay for the rent.
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The update authority of the asset, either that of its verified collection or the creator or delegate of its private tree.
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL Account Compression program
+    pub compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The Token Metadata account of the asset's verified collection, if the authority is its update authority.
+    pub collection_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The account to record the inscription's rank in, for lookups by rank.
+    pub inscription_rank_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `initialize_from_asset` CPI instruction.
+pub struct InitializeFromAssetCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account where data is stored.
+    pub asset_inscription_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account to store the inscription account's metadata in.
+    pub inscription_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The compressed asset id that will be used to derive the PDA.
+    pub asset_id: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The Bubblegum config account of the Merkle tree.
+    pub tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The Merkle tree holding the compressed asset.
+    pub merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The owner of the compressed asset.
+    pub leaf_owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The delegate of the compressed asset.
+    pub leaf_delegate: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The shard account for the inscription counter.
+    pub inscription_shard_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account that will pay for the rent.
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The update authority of the asset, either that of its verified collection or the creator or delegate of its private tree.
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL Account Compression program
+    pub compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The Token Metadata account of the asset's verified collection, if the authority is its update authority.
+    pub collection_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The account to record the inscription's rank in, for lookups by rank.
+    pub inscription_rank_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: InitializeFromAssetInstructionArgs,
+}
+
+impl<'a, 'b> InitializeFromAssetCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: InitializeFromAssetCpiAccounts<'a, 'b>,
+        args: InitializeFromAssetInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            asset_inscription_account: accounts.asset_inscription_account,
+            inscription_metadata_account: accounts.inscription_metadata_account,
+            asset_id: accounts.asset_id,
+            tree_config: accounts.tree_config,
+            merkle_tree: accounts.merkle_tree,
+            leaf_owner: accounts.leaf_owner,
+            leaf_delegate: accounts.leaf_delegate,
+            inscription_shard_account: accounts.inscription_shard_account,
+            payer: accounts.payer,
+            authority: accounts.authority,
+            system_program: accounts.system_program,
+            compression_program: accounts.compression_program,
+            collection_metadata: accounts.collection_metadata,
+            inscription_rank_account: accounts.inscription_rank_account,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.asset_inscription_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.inscription_metadata_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.asset_id.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.tree_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.merkle_tree.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.leaf_owner.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.leaf_delegate.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.inscription_shard_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authority.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.compression_program.key,
+            false,
+        ));
+        if let Some(collection_metadata) = self.collection_metadata {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *collection_metadata.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(inscription_rank_account) = self.inscription_rank_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *inscription_rank_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = InitializeFromAssetInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_INSCRIPTION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(14 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.asset_inscription_account.clone());
+        account_infos.push(self.inscription_metadata_account.clone());
+        account_infos.push(self.asset_id.clone());
+        account_infos.push(self.tree_config.clone());
+        account_infos.push(self.merkle_tree.clone());
+        account_infos.push(self.leaf_owner.clone());
+        account_infos.push(self.leaf_delegate.clone());
+        account_infos.push(self.inscription_shard_account.clone());
+        account_infos.push(self.payer.clone());
+        if let Some(authority) = self.authority {
+            account_infos.push(authority.clone());
+        }
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.compression_program.clone());
+        if let Some(collection_metadata) = self.collection_metadata {
+            account_infos.push(collection_metadata.clone());
+        }
+        if let Some(inscription_rank_account) = self.inscription_rank_account {
+            account_infos.push(inscription_rank_account.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// `initialize_from_asset` CPI instruction builder.
+pub struct InitializeFromAssetCpiBuilder<'a, 'b> {
+    instruction: Box<InitializeFromAssetCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> InitializeFromAssetCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(InitializeFromAssetCpiBuilderInstruction {
+            __program: program,
+            asset_inscription_account: None,
+            inscription_metadata_account: None,
+            asset_id: None,
+            tree_config: None,
+            merkle_tree: None,
+            leaf_owner: None,
+            leaf_delegate: None,
+            inscription_shard_account: None,
+            payer: None,
+            authority: None,
+            system_program: None,
+            compression_program: None,
+            collection_metadata: None,
+            inscription_rank_account: None,
+            root: None,
+            data_hash: None,
+            creator_hash: None,
+            nonce: None,
+            index: None,
+            metadata: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The account where data is stored.
+    #[inline(always)]
+    pub fn asset_inscription_account(
+        &mut self,
+        asset_inscription_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.asset_inscription_account = Some(asset_inscription_account);
+        self
+    }
+    /// The account to store the inscription account's metadata in.
+    #[inline(always)]
+    pub fn inscription_metadata_account(
+        &mut self,
+        inscription_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.inscription_metadata_account = Some(inscription_metadata_account);
+        self
+    }
+    /// The compressed asset id that will be used to derive the PDA.
+    #[inline(always)]
+    pub fn asset_id(
+        &mut self,
+        asset_id: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.asset_id = Some(asset_id);
+        self
+    }
+    /// The Bubblegum config account of the Merkle tree.
+    #[inline(always)]
+    pub fn tree_config(
+        &mut self,
+        tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.tree_config = Some(tree_config);
+        self
+    }
+    /// The Merkle tree holding the compressed asset.
+    #[inline(always)]
+    pub fn merkle_tree(
+        &mut self,
+        merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.merkle_tree = Some(merkle_tree);
+        self
+    }
+    /// The owner of the compressed asset.
+    #[inline(always)]
+    pub fn leaf_owner(
+        &mut self,
+        leaf_owner: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.leaf_owner = Some(leaf_owner);
+        self
+    }
+    /// The delegate of the compressed asset.
+    #[inline(always)]
+    pub fn leaf_delegate(
+        &mut self,
+        leaf_delegate: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.leaf_delegate = Some(leaf_delegate);
+        self
+    }
+    /// The shard account for the inscription counter.
+    #[inline(always)]
+    pub fn inscription_shard_account(
+        &mut self,
+        inscription_shard_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.inscription_shard_account = Some(inscription_shard_account);
+        self
+    }
+    /// The account that will pay for the rent.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// `[optional account]`
+    /// The update authority of the asset, either that of its verified collection or the creator or delegate of its private tree.
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authority = authority;
+        self
+    }
+    /// System program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// SPL Account Compression program
+    #[inline(always)]
+    pub fn compression_program(
+        &mut self,
+        compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.compression_program = Some(compression_program);
+        self
+    }
+    /// `[optional account]`
+    /// The Token Metadata account of the asset's verified collection, if the authority is its update authority.
+    #[inline(always)]
+    pub fn collection_metadata(
+        &mut self,
+        collection_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.collection_metadata = collection_metadata;
+        self
+    }
+    /// `[optional account]`
+    /// The account to record the inscription's rank in, for lookups by rank.
+    #[inline(always)]
+    pub fn inscription_rank_account(
+        &mut self,
+        inscription_rank_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.inscription_rank_account = inscription_rank_account;
+        self
+    }
+    #[inline(always)]
+    pub fn root(&mut self, root: [u8; 32]) -> &mut Self {
+        self.instruction.root = Some(root);
+        self
+    }
+    #[inline(always)]
+    pub fn data_hash(&mut self, data_hash: [u8; 32]) -> &mut Self {
+        self.instruction.data_hash = Some(data_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn creator_hash(&mut self, creator_hash: [u8; 32]) -> &mut Self {
+        self.instruction.creator_hash = Some(creator_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn nonce(&mut self, nonce: u64) -> &mut Self {
+        self.instruction.nonce = Some(nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn index(&mut self, index: u32) -> &mut Self {
+        self.instruction.index = Some(index);
+        self
+    }
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: Vec<u8>) -> &mut Self {
+        self.instruction.metadata = Some(metadata);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = InitializeFromAssetInstructionArgs {
+            root: self.instruction.root.clone().expect("root is not set"),
+            data_hash: self
+                .instruction
+                .data_hash
+                .clone()
+                .expect("data_hash is not set"),
+            creator_hash: self
+                .instruction
+                .creator_hash
+                .clone()
+                .expect("creator_hash is not set"),
+            nonce: self.instruction.nonce.clone().expect("nonce is not set"),
+            index: self.instruction.index.clone().expect("index is not set"),
+            metadata: self
+                .instruction
+                .metadata
+                .clone()
+                .expect("metadata is not set"),
+        };
+        let instruction = InitializeFromAssetCpi {
+            __program: self.instruction.__program,
+
+            asset_inscription_account: self
+                .instruction
+                .asset_inscription_account
+                .expect("asset_inscription_account is not set"),
+
+            inscription_metadata_account: self
+                .instruction
+                .inscription_metadata_account
+                .expect("inscription_metadata_account is not set"),
+
+            asset_id: self.instruction.asset_id.expect("asset_id is not set"),
+
+            tree_config: self
+                .instruction
+                .tree_config
+                .expect("tree_config is not set"),
+
+            merkle_tree: self
+                .instruction
+                .merkle_tree
+                .expect("merkle_tree is not set"),
+
+            leaf_owner: self.instruction.leaf_owner.expect("leaf_owner is not set"),
+
+            leaf_delegate: self
+                .instruction
+                .leaf_delegate
+                .expect("leaf_delegate is not set"),
+
+            inscription_shard_account: self
+                .instruction
+                .inscription_shard_account
+                .expect("inscription_shard_account is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            authority: self.instruction.authority,
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            compression_program: self
+                .instruction
+                .compression_program
+                .expect("compression_program is not set"),
+
+            collection_metadata: self.instruction.collection_metadata,
+
+            inscription_rank_account: self.instruction.inscription_rank_account,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct InitializeFromAssetCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    asset_inscription_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    inscription_metadata_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    asset_id: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tree_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    merkle_tree: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    leaf_owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    leaf_delegate: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    inscription_shard_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    compression_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    inscription_rank_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    root: Option<[u8; 32]>,
+    data_hash: Option<[u8; 32]>,
+    creator_hash: Option<[u8; 32]>,
+    nonce: Option<u64>,
+    index: Option<u32>,
+    metadata: Option<Vec<u8>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -16,7 +16,7 @@ pub struct InitializeFromMint {
     pub inscription_metadata_account: solana_program::pubkey::Pubkey,
     /// The mint that will be used to derive the PDA.
     pub mint_account: solana_program::pubkey::Pubkey,
-    /// The metadata for the mint.
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata.
     pub token_metadata_account: solana_program::pubkey::Pubkey,
     /// The shard account for the inscription counter.
     pub inscription_shard_account: solana_program::pubkey::Pubkey,
@@ -26,6 +26,10 @@ pub struct InitializeFromMint {
     pub authority: Option<solana_program::pubkey::Pubkey>,
     /// System program
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The token account holding the NFT, if the authority is the holder.
+    pub token_account: Option<solana_program::pubkey::Pubkey>,
+    /// The account to record the inscription's rank in, for lookups by rank.
+    pub inscription_rank_account: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl InitializeFromMint {
@@ -37,7 +41,7 @@ impl InitializeFromMint {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.mint_inscription_account,
             false,
@@ -75,6 +79,28 @@ impl InitializeFromMint {
             self.system_program,
             false,
         ));
+        if let Some(token_account) = self.token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(inscription_rank_account) = self.inscription_rank_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                inscription_rank_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = InitializeFromMintInstructionData::new()
             .try_to_vec()
@@ -110,6 +136,8 @@ pub struct InitializeFromMintBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    token_account: Option<solana_program::pubkey::Pubkey>,
+    inscription_rank_account: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -141,7 +169,7 @@ impl InitializeFromMintBuilder {
         self.mint_account = Some(mint_account);
         self
     }
-    /// The metadata for the mint.
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata.
     #[inline(always)]
     pub fn token_metadata_account(
         &mut self,
@@ -179,6 +207,26 @@ impl InitializeFromMintBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The token account holding the NFT, if the authority is the holder.
+    #[inline(always)]
+    pub fn token_account(
+        &mut self,
+        token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.token_account = token_account;
+        self
+    }
+    /// `[optional account]`
+    /// The account to record the inscription's rank in, for lookups by rank.
+    #[inline(always)]
+    pub fn inscription_rank_account(
+        &mut self,
+        inscription_rank_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.inscription_rank_account = inscription_rank_account;
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -218,6 +266,8 @@ impl InitializeFromMintBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_account: self.token_account,
+            inscription_rank_account: self.inscription_rank_account,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -232,7 +282,7 @@ pub struct InitializeFromMintCpiAccounts<'a, 'b> {
     pub inscription_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
     /// The mint that will be used to derive the PDA.
     pub mint_account: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The metadata for the mint.
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata.
     pub token_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
     /// The shard account for the inscription counter.
     pub inscription_shard_account: &'b solana_program::account_info::AccountInfo<'a>,
@@ -242,6 +292,10 @@ pub struct InitializeFromMintCpiAccounts<'a, 'b> {
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account holding the NFT, if the authority is the holder.
+    pub token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The account to record the inscription's rank in, for lookups by rank.
+    pub inscription_rank_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `initialize_from_mint` CPI instruction.
@@ -254,7 +308,7 @@ pub struct InitializeFromMintCpi<'a, 'b> {
     pub inscription_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
     /// The mint that will be used to derive the PDA.
     pub mint_account: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The metadata for the mint.
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata.
     pub token_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
     /// The shard account for the inscription counter.
     pub inscription_shard_account: &'b solana_program::account_info::AccountInfo<'a>,
@@ -264,6 +318,10 @@ pub struct InitializeFromMintCpi<'a, 'b> {
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account holding the NFT, if the authority is the holder.
+    pub token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The account to record the inscription's rank in, for lookups by rank.
+    pub inscription_rank_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> InitializeFromMintCpi<'a, 'b> {
@@ -281,6 +339,8 @@ impl<'a, 'b> InitializeFromMintCpi<'a, 'b> {
             payer: accounts.payer,
             authority: accounts.authority,
             system_program: accounts.system_program,
+            token_account: accounts.token_account,
+            inscription_rank_account: accounts.inscription_rank_account,
         }
     }
     #[inline(always)]
@@ -316,7 +376,7 @@ impl<'a, 'b> InitializeFromMintCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.mint_inscription_account.key,
             false,
@@ -356,6 +416,28 @@ impl<'a, 'b> InitializeFromMintCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(token_account) = self.token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(inscription_rank_account) = self.inscription_rank_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *inscription_rank_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -372,7 +454,7 @@ impl<'a, 'b> InitializeFromMintCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.mint_inscription_account.clone());
         account_infos.push(self.inscription_metadata_account.clone());
@@ -384,6 +466,12 @@ impl<'a, 'b> InitializeFromMintCpi<'a, 'b> {
             account_infos.push(authority.clone());
         }
         account_infos.push(self.system_program.clone());
+        if let Some(token_account) = self.token_account {
+            account_infos.push(token_account.clone());
+        }
+        if let Some(inscription_rank_account) = self.inscription_rank_account {
+            account_infos.push(inscription_rank_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -413,6 +501,8 @@ impl<'a, 'b> InitializeFromMintCpiBuilder<'a, 'b> {
             payer: None,
             authority: None,
             system_program: None,
+            token_account: None,
+            inscription_rank_account: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -444,7 +534,7 @@ impl<'a, 'b> InitializeFromMintCpiBuilder<'a, 'b> {
         self.instruction.mint_account = Some(mint_account);
         self
     }
-    /// The metadata for the mint.
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata.
     #[inline(always)]
     pub fn token_metadata_account(
         &mut self,
@@ -487,6 +577,26 @@ impl<'a, 'b> InitializeFromMintCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The token account holding the NFT, if the authority is the holder.
+    #[inline(always)]
+    pub fn token_account(
+        &mut self,
+        token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_account = token_account;
+        self
+    }
+    /// `[optional account]`
+    /// The account to record the inscription's rank in, for lookups by rank.
+    #[inline(always)]
+    pub fn inscription_rank_account(
+        &mut self,
+        inscription_rank_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.inscription_rank_account = inscription_rank_account;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -564,6 +674,10 @@ impl<'a, 'b> InitializeFromMintCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            token_account: self.instruction.token_account,
+
+            inscription_rank_account: self.instruction.inscription_rank_account,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -582,6 +696,8 @@ struct InitializeFromMintCpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    inscription_rank_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -6,25 +6,61 @@
 //!
 
 pub(crate) mod add_authority;
+pub(crate) mod add_write_delegate;
 pub(crate) mod allocate;
 pub(crate) mod clear_data;
 pub(crate) mod close;
+pub(crate) mod close_all;
+pub(crate) mod close_staging_buffer;
+pub(crate) mod commit_hash;
+pub(crate) mod copy_data;
 pub(crate) mod create_shard;
+pub(crate) mod freeze;
 pub(crate) mod initialize;
 pub(crate) mod initialize_associated_inscription;
+pub(crate) mod initialize_from_asset;
+pub(crate) mod initialize_from_core_asset;
 pub(crate) mod initialize_from_mint;
+pub(crate) mod prune_write_delegates;
+pub(crate) mod reclaim_rent;
+pub(crate) mod record_inscription_rank;
 pub(crate) mod remove_authority;
+pub(crate) mod replace_authority;
+pub(crate) mod set_authority_threshold;
+pub(crate) mod set_content_type;
+pub(crate) mod set_data_type;
 pub(crate) mod set_mint;
+pub(crate) mod sync_authority;
 pub(crate) mod write_data;
+pub(crate) mod write_segments;
+pub(crate) mod write_staging_buffer;
 
 pub use self::add_authority::*;
+pub use self::add_write_delegate::*;
 pub use self::allocate::*;
 pub use self::clear_data::*;
 pub use self::close::*;
+pub use self::close_all::*;
+pub use self::close_staging_buffer::*;
+pub use self::commit_hash::*;
+pub use self::copy_data::*;
 pub use self::create_shard::*;
+pub use self::freeze::*;
 pub use self::initialize::*;
 pub use self::initialize_associated_inscription::*;
+pub use self::initialize_from_asset::*;
+pub use self::initialize_from_core_asset::*;
 pub use self::initialize_from_mint::*;
+pub use self::prune_write_delegates::*;
+pub use self::reclaim_rent::*;
+pub use self::record_inscription_rank::*;
 pub use self::remove_authority::*;
+pub use self::replace_authority::*;
+pub use self::set_authority_threshold::*;
+pub use self::set_content_type::*;
+pub use self::set_data_type::*;
 pub use self::set_mint::*;
+pub use self::sync_authority::*;
 pub use self::write_data::*;
+pub use self::write_segments::*;
+pub use self::write_staging_buffer::*;
@@ -7,6 +7,7 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
 
 /// Accounts.
 pub struct RemoveAuthority {
@@ -14,19 +15,23 @@ pub struct RemoveAuthority {
     pub inscription_metadata_account: solana_program::pubkey::Pubkey,
     /// The account paying for the transaction and rent.
     pub payer: solana_program::pubkey::Pubkey,
-    /// The authority of the inscription account to be removed.
+    /// The authority of the inscription account.
     pub authority: Option<solana_program::pubkey::Pubkey>,
     /// System program
     pub system_program: solana_program::pubkey::Pubkey,
 }
 
 impl RemoveAuthority {
-    pub fn instruction(&self) -> solana_program::instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(
+        &self,
+        args: RemoveAuthorityInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: RemoveAuthorityInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
         let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
@@ -52,7 +57,9 @@ impl RemoveAuthority {
             false,
         ));
         accounts.extend_from_slice(remaining_accounts);
-        let data = RemoveAuthorityInstructionData::new().try_to_vec().unwrap();
+        let mut data = RemoveAuthorityInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
 
         solana_program::instruction::Instruction {
             program_id: crate::MPL_INSCRIPTION_ID,
@@ -73,6 +80,13 @@ impl RemoveAuthorityInstructionData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveAuthorityInstructionArgs {
+    pub target: Option<Pubkey>,
+    pub allow_last_authority: bool,
+}
+
 /// Instruction builder.
 #[derive(Default)]
 pub struct RemoveAuthorityBuilder {
@@ -80,6 +94,8 @@ pub struct RemoveAuthorityBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    target: Option<Pubkey>,
+    allow_last_authority: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -103,7 +119,7 @@ impl RemoveAuthorityBuilder {
         self
     }
     /// `[optional account]`
-    /// The authority of the inscription account to be removed.
+    /// The authority of the inscription account.
     #[inline(always)]
     pub fn authority(&mut self, authority: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
         self.authority = authority;
@@ -116,6 +132,17 @@ impl RemoveAuthorityBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn target(&mut self, target: Pubkey) -> &mut Self {
+        self.target = Some(target);
+        self
+    }
+    #[inline(always)]
+    pub fn allow_last_authority(&mut self, allow_last_authority: bool) -> &mut Self {
+        self.allow_last_authority = Some(allow_last_authority);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -146,8 +173,15 @@ impl RemoveAuthorityBuilder {
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
         };
+        let args = RemoveAuthorityInstructionArgs {
+            target: self.target.clone(),
+            allow_last_authority: self
+                .allow_last_authority
+                .clone()
+                .expect("allow_last_authority is not set"),
+        };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -157,7 +191,7 @@ pub struct RemoveAuthorityCpiAccounts<'a, 'b> {
     pub inscription_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
     /// The account paying for the transaction and rent.
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The authority of the inscription account to be removed.
+    /// The authority of the inscription account.
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -171,16 +205,19 @@ pub struct RemoveAuthorityCpi<'a, 'b> {
     pub inscription_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
     /// The account paying for the transaction and rent.
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The authority of the inscription account to be removed.
+    /// The authority of the inscription account.
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: RemoveAuthorityInstructionArgs,
 }
 
 impl<'a, 'b> RemoveAuthorityCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_program::account_info::AccountInfo<'a>,
         accounts: RemoveAuthorityCpiAccounts<'a, 'b>,
+        args: RemoveAuthorityInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -188,6 +225,7 @@ impl<'a, 'b> RemoveAuthorityCpi<'a, 'b> {
             payer: accounts.payer,
             authority: accounts.authority,
             system_program: accounts.system_program,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -254,7 +292,9 @@ impl<'a, 'b> RemoveAuthorityCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = RemoveAuthorityInstructionData::new().try_to_vec().unwrap();
+        let mut data = RemoveAuthorityInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
 
         let instruction = solana_program::instruction::Instruction {
             program_id: crate::MPL_INSCRIPTION_ID,
@@ -294,6 +334,8 @@ impl<'a, 'b> RemoveAuthorityCpiBuilder<'a, 'b> {
             payer: None,
             authority: None,
             system_program: None,
+            target: None,
+            allow_last_authority: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -314,7 +356,7 @@ impl<'a, 'b> RemoveAuthorityCpiBuilder<'a, 'b> {
         self
     }
     /// `[optional account]`
-    /// The authority of the inscription account to be removed.
+    /// The authority of the inscription account.
     #[inline(always)]
     pub fn authority(
         &mut self,
@@ -332,6 +374,17 @@ impl<'a, 'b> RemoveAuthorityCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn target(&mut self, target: Pubkey) -> &mut Self {
+        self.instruction.target = Some(target);
+        self
+    }
+    #[inline(always)]
+    pub fn allow_last_authority(&mut self, allow_last_authority: bool) -> &mut Self {
+        self.instruction.allow_last_authority = Some(allow_last_authority);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -373,6 +426,14 @@ impl<'a, 'b> RemoveAuthorityCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
+        let args = RemoveAuthorityInstructionArgs {
+            target: self.instruction.target.clone(),
+            allow_last_authority: self
+                .instruction
+                .allow_last_authority
+                .clone()
+                .expect("allow_last_authority is not set"),
+        };
         let instruction = RemoveAuthorityCpi {
             __program: self.instruction.__program,
 
@@ -389,6 +450,7 @@ impl<'a, 'b> RemoveAuthorityCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -403,6 +465,8 @@ struct RemoveAuthorityCpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    target: Option<Pubkey>,
+    allow_last_authority: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
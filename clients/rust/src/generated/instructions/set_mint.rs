@@ -20,18 +20,26 @@ pub struct SetMint {
     pub payer: solana_program::pubkey::Pubkey,
     /// System program
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The authority of the inscription account or the update authority of the mint.
+    pub authority: Option<solana_program::pubkey::Pubkey>,
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata, if the authority is its update authority.
+    pub token_metadata_account: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl SetMint {
-    pub fn instruction(&self) -> solana_program::instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(
+        &self,
+        args: SetMintInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: SetMintInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.mint_inscription_account,
             false,
@@ -51,8 +59,31 @@ impl SetMint {
             self.system_program,
             false,
         ));
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authority, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(token_metadata_account) = self.token_metadata_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token_metadata_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
-        let data = SetMintInstructionData::new().try_to_vec().unwrap();
+        let mut data = SetMintInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
 
         solana_program::instruction::Instruction {
             program_id: crate::MPL_INSCRIPTION_ID,
@@ -73,6 +104,12 @@ impl SetMintInstructionData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetMintInstructionArgs {
+    pub backfill_inscription_bump: bool,
+}
+
 /// Instruction builder.
 #[derive(Default)]
 pub struct SetMintBuilder {
@@ -81,6 +118,9 @@ pub struct SetMintBuilder {
     mint_account: Option<solana_program::pubkey::Pubkey>,
     payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    token_metadata_account: Option<solana_program::pubkey::Pubkey>,
+    backfill_inscription_bump: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -125,6 +165,28 @@ impl SetMintBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The authority of the inscription account or the update authority of the mint.
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.authority = authority;
+        self
+    }
+    /// `[optional account]`
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata, if the authority is its update authority.
+    #[inline(always)]
+    pub fn token_metadata_account(
+        &mut self,
+        token_metadata_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.token_metadata_account = token_metadata_account;
+        self
+    }
+    #[inline(always)]
+    pub fn backfill_inscription_bump(&mut self, backfill_inscription_bump: bool) -> &mut Self {
+        self.backfill_inscription_bump = Some(backfill_inscription_bump);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -157,9 +219,17 @@ impl SetMintBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            authority: self.authority,
+            token_metadata_account: self.token_metadata_account,
+        };
+        let args = SetMintInstructionArgs {
+            backfill_inscription_bump: self
+                .backfill_inscription_bump
+                .clone()
+                .expect("backfill_inscription_bump is not set"),
         };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -175,6 +245,10 @@ pub struct SetMintCpiAccounts<'a, 'b> {
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The authority of the inscription account or the update authority of the mint.
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata, if the authority is its update authority.
+    pub token_metadata_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `set_mint` CPI instruction.
@@ -191,12 +265,19 @@ pub struct SetMintCpi<'a, 'b> {
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The authority of the inscription account or the update authority of the mint.
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata, if the authority is its update authority.
+    pub token_metadata_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: SetMintInstructionArgs,
 }
 
 impl<'a, 'b> SetMintCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_program::account_info::AccountInfo<'a>,
         accounts: SetMintCpiAccounts<'a, 'b>,
+        args: SetMintInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -205,6 +286,9 @@ impl<'a, 'b> SetMintCpi<'a, 'b> {
             mint_account: accounts.mint_account,
             payer: accounts.payer,
             system_program: accounts.system_program,
+            authority: accounts.authority,
+            token_metadata_account: accounts.token_metadata_account,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -240,7 +324,7 @@ impl<'a, 'b> SetMintCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.mint_inscription_account.key,
             false,
@@ -261,6 +345,28 @@ impl<'a, 'b> SetMintCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authority.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(token_metadata_account) = self.token_metadata_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token_metadata_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -268,20 +374,28 @@ impl<'a, 'b> SetMintCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = SetMintInstructionData::new().try_to_vec().unwrap();
+        let mut data = SetMintInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
 
         let instruction = solana_program::instruction::Instruction {
             program_id: crate::MPL_INSCRIPTION_ID,
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.mint_inscription_account.clone());
         account_infos.push(self.inscription_metadata_account.clone());
         account_infos.push(self.mint_account.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(authority) = self.authority {
+            account_infos.push(authority.clone());
+        }
+        if let Some(token_metadata_account) = self.token_metadata_account {
+            account_infos.push(token_metadata_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -308,6 +422,9 @@ impl<'a, 'b> SetMintCpiBuilder<'a, 'b> {
             mint_account: None,
             payer: None,
             system_program: None,
+            authority: None,
+            token_metadata_account: None,
+            backfill_inscription_bump: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -354,6 +471,31 @@ impl<'a, 'b> SetMintCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The authority of the inscription account or the update authority of the mint.
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authority = authority;
+        self
+    }
+    /// `[optional account]`
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata, if the authority is its update authority.
+    #[inline(always)]
+    pub fn token_metadata_account(
+        &mut self,
+        token_metadata_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_metadata_account = token_metadata_account;
+        self
+    }
+    #[inline(always)]
+    pub fn backfill_inscription_bump(&mut self, backfill_inscription_bump: bool) -> &mut Self {
+        self.instruction.backfill_inscription_bump = Some(backfill_inscription_bump);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -395,6 +537,13 @@ impl<'a, 'b> SetMintCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
+        let args = SetMintInstructionArgs {
+            backfill_inscription_bump: self
+                .instruction
+                .backfill_inscription_bump
+                .clone()
+                .expect("backfill_inscription_bump is not set"),
+        };
         let instruction = SetMintCpi {
             __program: self.instruction.__program,
 
@@ -419,6 +568,11 @@ impl<'a, 'b> SetMintCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            authority: self.instruction.authority,
+
+            token_metadata_account: self.instruction.token_metadata_account,
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -434,6 +588,9 @@ struct SetMintCpiBuilderInstruction<'a, 'b> {
     mint_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_metadata_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    backfill_inscription_bump: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -0,0 +1,588 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct SyncAuthority {
+    /// The account to store the inscription account's metadata in.
+    pub inscription_metadata_account: solana_program::pubkey::Pubkey,
+    /// The mint the inscription was created from.
+    pub mint_account: solana_program::pubkey::Pubkey,
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata.
+    pub token_metadata_account: solana_program::pubkey::Pubkey,
+    /// The token account holding the NFT, to sync the holder of a holder inscription.
+    pub token_account: Option<solana_program::pubkey::Pubkey>,
+    /// The account that will pay for the rent, if older metadata accounts need to grow.
+    pub payer: Option<solana_program::pubkey::Pubkey>,
+    /// System program
+    pub system_program: Option<solana_program::pubkey::Pubkey>,
+    /// An Admin of an older inscription handing its place over to the current update authority of the mint.
+    pub authority: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl SyncAuthority {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.inscription_metadata_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_metadata_account,
+            false,
+        ));
+        if let Some(token_account) = self.token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(payer) = self.payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(payer, true));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                system_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authority, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let data = SyncAuthorityInstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_INSCRIPTION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct SyncAuthorityInstructionData {
+    discriminator: u8,
+}
+
+impl SyncAuthorityInstructionData {
+    fn new() -> Self {
+        Self { discriminator: 23 }
+    }
+}
+
+/// Instruction builder.
+#[derive(Default)]
+pub struct SyncAuthorityBuilder {
+    inscription_metadata_account: Option<solana_program::pubkey::Pubkey>,
+    mint_account: Option<solana_program::pubkey::Pubkey>,
+    token_metadata_account: Option<solana_program::pubkey::Pubkey>,
+    token_account: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl SyncAuthorityBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The account to store the inscription account's metadata in.
+    #[inline(always)]
+    pub fn inscription_metadata_account(
+        &mut self,
+        inscription_metadata_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.inscription_metadata_account = Some(inscription_metadata_account);
+        self
+    }
+    /// The mint the inscription was created from.
+    #[inline(always)]
+    pub fn mint_account(&mut self, mint_account: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint_account = Some(mint_account);
+        self
+    }
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata.
+    #[inline(always)]
+    pub fn token_metadata_account(
+        &mut self,
+        token_metadata_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.token_metadata_account = Some(token_metadata_account);
+        self
+    }
+    /// `[optional account]`
+    /// The token account holding the NFT, to sync the holder of a holder inscription.
+    #[inline(always)]
+    pub fn token_account(
+        &mut self,
+        token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.token_account = token_account;
+        self
+    }
+    /// `[optional account]`
+    /// The account that will pay for the rent, if older metadata accounts need to grow.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.payer = payer;
+        self
+    }
+    /// `[optional account]`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.system_program = system_program;
+        self
+    }
+    /// `[optional account]`
+    /// An Admin of an older inscription handing its place over to the current update authority of the mint.
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.authority = authority;
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = SyncAuthority {
+            inscription_metadata_account: self
+                .inscription_metadata_account
+                .expect("inscription_metadata_account is not set"),
+            mint_account: self.mint_account.expect("mint_account is not set"),
+            token_metadata_account: self
+                .token_metadata_account
+                .expect("token_metadata_account is not set"),
+            token_account: self.token_account,
+            payer: self.payer,
+            system_program: self.system_program,
+            authority: self.authority,
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `sync_authority` CPI accounts.
+pub struct SyncAuthorityCpiAccounts<'a, 'b> {
+    /// The account to store the inscription account's metadata in.
+    pub inscription_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mint the inscription was created from.
+    pub mint_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata.
+    pub token_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account holding the NFT, to sync the holder of a holder inscription.
+    pub token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The account that will pay for the rent, if older metadata accounts need to grow.
+    pub payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// System program
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// An Admin of an older inscription handing its place over to the current update authority of the mint.
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `sync_authority` CPI instruction.
+pub struct SyncAuthorityCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account to store the inscription account's metadata in.
+    pub inscription_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mint the inscription was created from.
+    pub mint_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata.
+    pub token_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account holding the NFT, to sync the holder of a holder inscription.
+    pub token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The account that will pay for the rent, if older metadata accounts need to grow.
+    pub payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// System program
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// An Admin of an older inscription handing its place over to the current update authority of the mint.
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+impl<'a, 'b> SyncAuthorityCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: SyncAuthorityCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            inscription_metadata_account: accounts.inscription_metadata_account,
+            mint_account: accounts.mint_account,
+            token_metadata_account: accounts.token_metadata_account,
+            token_account: accounts.token_account,
+            payer: accounts.payer,
+            system_program: accounts.system_program,
+            authority: accounts.authority,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.inscription_metadata_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_metadata_account.key,
+            false,
+        ));
+        if let Some(token_account) = self.token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(payer) = self.payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *payer.key, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *system_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authority.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = SyncAuthorityInstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_INSCRIPTION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.inscription_metadata_account.clone());
+        account_infos.push(self.mint_account.clone());
+        account_infos.push(self.token_metadata_account.clone());
+        if let Some(token_account) = self.token_account {
+            account_infos.push(token_account.clone());
+        }
+        if let Some(payer) = self.payer {
+            account_infos.push(payer.clone());
+        }
+        if let Some(system_program) = self.system_program {
+            account_infos.push(system_program.clone());
+        }
+        if let Some(authority) = self.authority {
+            account_infos.push(authority.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// `sync_authority` CPI instruction builder.
+pub struct SyncAuthorityCpiBuilder<'a, 'b> {
+    instruction: Box<SyncAuthorityCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> SyncAuthorityCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(SyncAuthorityCpiBuilderInstruction {
+            __program: program,
+            inscription_metadata_account: None,
+            mint_account: None,
+            token_metadata_account: None,
+            token_account: None,
+            payer: None,
+            system_program: None,
+            authority: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The account to store the inscription account's metadata in.
+    #[inline(always)]
+    pub fn inscription_metadata_account(
+        &mut self,
+        inscription_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.inscription_metadata_account = Some(inscription_metadata_account);
+        self
+    }
+    /// The mint the inscription was created from.
+    #[inline(always)]
+    pub fn mint_account(
+        &mut self,
+        mint_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mint_account = Some(mint_account);
+        self
+    }
+    /// The metadata for the mint, or the mint itself if it uses Token-2022 metadata.
+    #[inline(always)]
+    pub fn token_metadata_account(
+        &mut self,
+        token_metadata_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_metadata_account = Some(token_metadata_account);
+        self
+    }
+    /// `[optional account]`
+    /// The token account holding the NFT, to sync the holder of a holder inscription.
+    #[inline(always)]
+    pub fn token_account(
+        &mut self,
+        token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_account = token_account;
+        self
+    }
+    /// `[optional account]`
+    /// The account that will pay for the rent, if older metadata accounts need to grow.
+    #[inline(always)]
+    pub fn payer(
+        &mut self,
+        payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.payer = payer;
+        self
+    }
+    /// `[optional account]`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.system_program = system_program;
+        self
+    }
+    /// `[optional account]`
+    /// An Admin of an older inscription handing its place over to the current update authority of the mint.
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authority = authority;
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = SyncAuthorityCpi {
+            __program: self.instruction.__program,
+
+            inscription_metadata_account: self
+                .instruction
+                .inscription_metadata_account
+                .expect("inscription_metadata_account is not set"),
+
+            mint_account: self
+                .instruction
+                .mint_account
+                .expect("mint_account is not set"),
+
+            token_metadata_account: self
+                .instruction
+                .token_metadata_account
+                .expect("token_metadata_account is not set"),
+
+            token_account: self.instruction.token_account,
+
+            payer: self.instruction.payer,
+
+            system_program: self.instruction.system_program,
+
+            authority: self.instruction.authority,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct SyncAuthorityCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    inscription_metadata_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_metadata_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
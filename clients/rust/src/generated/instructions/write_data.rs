@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::WritePrecondition;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -20,6 +21,8 @@ pub struct WriteData {
     pub authority: Option<solana_program::pubkey::Pubkey>,
     /// System program
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The staging buffer to take the data from, in place of the value.
+    pub staging_account: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl WriteData {
@@ -35,7 +38,7 @@ impl WriteData {
         args: WriteDataInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.inscription_account,
             false,
@@ -61,6 +64,17 @@ impl WriteData {
             self.system_program,
             false,
         ));
+        if let Some(staging_account) = self.staging_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                staging_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = WriteDataInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -91,6 +105,7 @@ pub struct WriteDataInstructionArgs {
     pub associated_tag: Option<String>,
     pub offset: u64,
     pub value: Vec<u8>,
+    pub precondition: Option<WritePrecondition>,
 }
 
 /// Instruction builder.
@@ -101,9 +116,11 @@ pub struct WriteDataBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    staging_account: Option<solana_program::pubkey::Pubkey>,
     associated_tag: Option<String>,
     offset: Option<u64>,
     value: Option<Vec<u8>>,
+    precondition: Option<WritePrecondition>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -149,6 +166,16 @@ impl WriteDataBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The staging buffer to take the data from, in place of the value.
+    #[inline(always)]
+    pub fn staging_account(
+        &mut self,
+        staging_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.staging_account = staging_account;
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn associated_tag(&mut self, associated_tag: String) -> &mut Self {
@@ -165,6 +192,12 @@ impl WriteDataBuilder {
         self.value = Some(value);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn precondition(&mut self, precondition: WritePrecondition) -> &mut Self {
+        self.precondition = Some(precondition);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -197,11 +230,13 @@ impl WriteDataBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            staging_account: self.staging_account,
         };
         let args = WriteDataInstructionArgs {
             associated_tag: self.associated_tag.clone(),
             offset: self.offset.clone().expect("offset is not set"),
             value: self.value.clone().expect("value is not set"),
+            precondition: self.precondition.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -220,6 +255,8 @@ pub struct WriteDataCpiAccounts<'a, 'b> {
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The staging buffer to take the data from, in place of the value.
+    pub staging_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `write_data` CPI instruction.
@@ -236,6 +273,8 @@ pub struct WriteDataCpi<'a, 'b> {
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// System program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The staging buffer to take the data from, in place of the value.
+    pub staging_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: WriteDataInstructionArgs,
 }
@@ -253,6 +292,7 @@ impl<'a, 'b> WriteDataCpi<'a, 'b> {
             payer: accounts.payer,
             authority: accounts.authority,
             system_program: accounts.system_program,
+            staging_account: accounts.staging_account,
             __args: args,
         }
     }
@@ -289,7 +329,7 @@ impl<'a, 'b> WriteDataCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.inscription_account.key,
             false,
@@ -317,6 +357,17 @@ impl<'a, 'b> WriteDataCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(staging_account) = self.staging_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *staging_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_INSCRIPTION_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -333,7 +384,7 @@ impl<'a, 'b> WriteDataCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.inscription_account.clone());
         account_infos.push(self.inscription_metadata_account.clone());
@@ -342,6 +393,9 @@ impl<'a, 'b> WriteDataCpi<'a, 'b> {
             account_infos.push(authority.clone());
         }
         account_infos.push(self.system_program.clone());
+        if let Some(staging_account) = self.staging_account {
+            account_infos.push(staging_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -368,9 +422,11 @@ impl<'a, 'b> WriteDataCpiBuilder<'a, 'b> {
             payer: None,
             authority: None,
             system_program: None,
+            staging_account: None,
             associated_tag: None,
             offset: None,
             value: None,
+            precondition: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -418,6 +474,16 @@ impl<'a, 'b> WriteDataCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The staging buffer to take the data from, in place of the value.
+    #[inline(always)]
+    pub fn staging_account(
+        &mut self,
+        staging_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.staging_account = staging_account;
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn associated_tag(&mut self, associated_tag: String) -> &mut Self {
@@ -434,6 +500,12 @@ impl<'a, 'b> WriteDataCpiBuilder<'a, 'b> {
         self.instruction.value = Some(value);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn precondition(&mut self, precondition: WritePrecondition) -> &mut Self {
+        self.instruction.precondition = Some(precondition);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -479,6 +551,7 @@ impl<'a, 'b> WriteDataCpiBuilder<'a, 'b> {
             associated_tag: self.instruction.associated_tag.clone(),
             offset: self.instruction.offset.clone().expect("offset is not set"),
             value: self.instruction.value.clone().expect("value is not set"),
+            precondition: self.instruction.precondition.clone(),
         };
         let instruction = WriteDataCpi {
             __program: self.instruction.__program,
@@ -501,6 +574,8 @@ impl<'a, 'b> WriteDataCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            staging_account: self.instruction.staging_account,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -517,9 +592,11 @@ struct WriteDataCpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staging_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     associated_tag: Option<String>,
     offset: Option<u64>,
     value: Option<Vec<u8>>,
+    precondition: Option<WritePrecondition>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -15,4 +15,7 @@ pub enum Key {
     InscriptionMetadataAccount,
     MintInscriptionMetadataAccount,
     InscriptionShardAccount,
+    InscriptionRankAccount,
+    InscriptionTombstoneAccount,
+    StagingBufferAccount,
 }
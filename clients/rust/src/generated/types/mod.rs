@@ -5,10 +5,20 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod associated_content_type;
 pub(crate) mod associated_inscription;
+pub(crate) mod authority_role;
 pub(crate) mod data_type;
 pub(crate) mod key;
+pub(crate) mod write_delegate;
+pub(crate) mod write_precondition;
+pub(crate) mod write_segment;
 
+pub use self::associated_content_type::*;
 pub use self::associated_inscription::*;
+pub use self::authority_role::*;
 pub use self::data_type::*;
 pub use self::key::*;
+pub use self::write_delegate::*;
+pub use self::write_precondition::*;
+pub use self::write_segment::*;
@@ -0,0 +1,132 @@
+use borsh::{maybestd::io, BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::generated::types::{
+    AssociatedContentType, AssociatedInscription, AuthorityRole, DataType, Key, WriteDelegate,
+};
+
+#[derive(BorshSerialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InscriptionMetadata {
+    pub key: Key,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub inscription_account: Pubkey,
+    pub bump: u8,
+    pub data_type: DataType,
+    pub inscription_rank: u64,
+    pub inscription_bump: Option<u8>,
+    pub update_authorities: Vec<Pubkey>,
+    pub associated_inscriptions: Vec<AssociatedInscription>,
+    pub mint: Option<Pubkey>,
+    pub frozen: bool,
+    pub padding: [u8; 6],
+    // Fields below were added after the original layout and are missing from older accounts.
+    pub content_hash: Option<[u8; 32]>,
+    pub content_length: u64,
+    pub authority_roles: Vec<AuthorityRole>,
+    pub write_delegates: Vec<WriteDelegate>,
+    pub authority_threshold: u8,
+    pub mint_update_authority: Option<Pubkey>,
+    pub rent_payer: Option<Pubkey>,
+    pub associated_content_types: Vec<AssociatedContentType>,
+    pub mint_holder: Option<Pubkey>,
+}
+
+impl InscriptionMetadata {
+    pub fn create_pda(
+        inscription_account: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "Inscription".as_bytes(),
+                crate::MPL_INSCRIPTION_ID.as_ref(),
+                inscription_account.as_ref(),
+                &[bump],
+            ],
+            &crate::MPL_INSCRIPTION_ID,
+        )
+    }
+
+    pub fn find_pda(inscription_account: &Pubkey) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "Inscription".as_bytes(),
+                crate::MPL_INSCRIPTION_ID.as_ref(),
+                inscription_account.as_ref(),
+            ],
+            &crate::MPL_INSCRIPTION_ID,
+        )
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl BorshDeserialize for InscriptionMetadata {
+    fn deserialize(buf: &mut &[u8]) -> io::Result<Self> {
+        let mut metadata = Self {
+            key: Key::deserialize(buf)?,
+            inscription_account: Pubkey::deserialize(buf)?,
+            bump: u8::deserialize(buf)?,
+            data_type: DataType::deserialize(buf)?,
+            inscription_rank: u64::deserialize(buf)?,
+            inscription_bump: Option::<u8>::deserialize(buf)?,
+            update_authorities: Vec::<Pubkey>::deserialize(buf)?,
+            associated_inscriptions: Vec::<AssociatedInscription>::deserialize(buf)?,
+            mint: Option::<Pubkey>::deserialize(buf)?,
+            frozen: bool::deserialize(buf)?,
+            padding: <[u8; 6]>::deserialize(buf)?,
+            content_hash: deserialize_or_default(buf)?,
+            content_length: deserialize_or_default(buf)?,
+            authority_roles: deserialize_or_default(buf)?,
+            write_delegates: deserialize_or_default(buf)?,
+            authority_threshold: deserialize_or_default(buf)?,
+            mint_update_authority: deserialize_or_default(buf)?,
+            rent_payer: deserialize_or_default(buf)?,
+            associated_content_types: deserialize_or_default(buf)?,
+            mint_holder: deserialize_or_default(buf)?,
+        };
+
+        // Authorities from before roles existed are Admins.
+        if metadata.authority_roles.len() < metadata.update_authorities.len() {
+            metadata
+                .authority_roles
+                .resize(metadata.update_authorities.len(), AuthorityRole::Admin);
+        }
+
+        Ok(metadata)
+    }
+
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        Self::deserialize(&mut data.as_slice())
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for InscriptionMetadata {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
+
+/// Read a field that was appended to the account layout, older accounts end before it.
+fn deserialize_or_default<T: BorshDeserialize + Default>(buf: &mut &[u8]) -> io::Result<T> {
+    if buf.is_empty() {
+        Ok(T::default())
+    } else {
+        T::deserialize(buf)
+    }
+}
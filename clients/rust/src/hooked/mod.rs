@@ -0,0 +1,3 @@
+mod inscription_metadata;
+
+pub use inscription_metadata::*;
@@ -1,4 +1,10 @@
 mod generated;
+mod hooked;
 
 pub use generated::programs::MPL_INSCRIPTION_ID as ID;
 pub use generated::*;
+
+pub mod accounts {
+    pub use crate::generated::accounts::*;
+    pub use crate::hooked::InscriptionMetadata;
+}
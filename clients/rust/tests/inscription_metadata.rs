@@ -0,0 +1,105 @@
+use borsh::BorshSerialize;
+use mpl_inscription::{
+    accounts::InscriptionMetadata,
+    types::{AssociatedInscription, AuthorityRole, DataType, Key},
+};
+use solana_program::pubkey::Pubkey;
+
+/// The inscription metadata layout from before any fields were appended to it.
+#[derive(BorshSerialize)]
+struct LegacyInscriptionMetadata {
+    key: Key,
+    inscription_account: Pubkey,
+    bump: u8,
+    data_type: DataType,
+    inscription_rank: u64,
+    inscription_bump: Option<u8>,
+    update_authorities: Vec<Pubkey>,
+    associated_inscriptions: Vec<AssociatedInscription>,
+    mint: Option<Pubkey>,
+    padding: [u8; 7],
+}
+
+#[test]
+fn deserialize_legacy_layout() {
+    // Given inscription metadata in the original layout.
+    let inscription_account = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let data = LegacyInscriptionMetadata {
+        key: Key::InscriptionMetadataAccount,
+        inscription_account,
+        bump: 254,
+        data_type: DataType::Binary,
+        inscription_rank: 7,
+        inscription_bump: None,
+        update_authorities: vec![authority],
+        associated_inscriptions: vec![AssociatedInscription {
+            tag: "image".to_string(),
+            bump: 253,
+            data_type: DataType::Uninitialized,
+        }],
+        mint: None,
+        padding: [0; 7],
+    }
+    .try_to_vec()
+    .unwrap();
+
+    // When it is deserialized.
+    let inscription_metadata = InscriptionMetadata::from_bytes(&data).unwrap();
+
+    // Then the original fields are read and the appended ones are defaulted.
+    assert_eq!(
+        inscription_metadata.inscription_account,
+        inscription_account
+    );
+    assert_eq!(inscription_metadata.inscription_rank, 7);
+    assert_eq!(inscription_metadata.update_authorities, vec![authority]);
+    assert_eq!(inscription_metadata.associated_inscriptions.len(), 1);
+    assert!(!inscription_metadata.frozen);
+    assert_eq!(inscription_metadata.content_hash, None);
+    assert_eq!(inscription_metadata.content_length, 0);
+    assert_eq!(
+        inscription_metadata.authority_roles,
+        vec![AuthorityRole::Admin]
+    );
+    assert!(inscription_metadata.write_delegates.is_empty());
+    assert_eq!(inscription_metadata.authority_threshold, 0);
+    assert_eq!(inscription_metadata.mint_update_authority, None);
+    assert_eq!(inscription_metadata.rent_payer, None);
+    assert!(inscription_metadata.associated_content_types.is_empty());
+    assert_eq!(inscription_metadata.mint_holder, None);
+}
+
+#[test]
+fn deserialize_current_layout() {
+    // Given inscription metadata with every appended field set.
+    let inscription_metadata = InscriptionMetadata {
+        key: Key::MintInscriptionMetadataAccount,
+        inscription_account: Pubkey::new_unique(),
+        bump: 254,
+        data_type: DataType::Json,
+        inscription_rank: 7,
+        inscription_bump: Some(253),
+        update_authorities: vec![Pubkey::new_unique()],
+        associated_inscriptions: vec![],
+        mint: Some(Pubkey::new_unique()),
+        frozen: true,
+        padding: [0; 6],
+        content_hash: Some([1; 32]),
+        content_length: 32,
+        authority_roles: vec![AuthorityRole::Writer],
+        write_delegates: vec![],
+        authority_threshold: 1,
+        mint_update_authority: Some(Pubkey::new_unique()),
+        rent_payer: Some(Pubkey::new_unique()),
+        associated_content_types: vec![],
+        mint_holder: Some(Pubkey::new_unique()),
+    };
+    let data = inscription_metadata.try_to_vec().unwrap();
+
+    // When it is deserialized, then every field round-trips.
+    assert_eq!(
+        InscriptionMetadata::from_bytes(&data).unwrap(),
+        inscription_metadata
+    );
+}
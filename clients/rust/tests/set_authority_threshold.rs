@@ -0,0 +1,96 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshSerialize;
+use mpl_inscription::{
+    accounts::InscriptionMetadata,
+    instructions::SetAuthorityThresholdBuilder,
+    types::{AssociatedInscription, DataType, Key},
+};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+/// The inscription metadata layout from before any fields were appended to it.
+#[derive(BorshSerialize)]
+struct LegacyInscriptionMetadata {
+    key: Key,
+    inscription_account: Pubkey,
+    bump: u8,
+    data_type: DataType,
+    inscription_rank: u64,
+    inscription_bump: Option<u8>,
+    update_authorities: Vec<Pubkey>,
+    associated_inscriptions: Vec<AssociatedInscription>,
+    mint: Option<Pubkey>,
+    padding: [u8; 7],
+}
+
+#[tokio::test]
+async fn set_threshold_on_legacy_account() {
+    // Given an inscription metadata account in the original layout.
+    let authority = Keypair::new();
+    let inscription_metadata_account = Pubkey::new_unique();
+    let data = LegacyInscriptionMetadata {
+        key: Key::InscriptionMetadataAccount,
+        inscription_account: Pubkey::new_unique(),
+        bump: 255,
+        data_type: DataType::Binary,
+        inscription_rank: 0,
+        inscription_bump: None,
+        update_authorities: vec![authority.pubkey()],
+        associated_inscriptions: vec![],
+        mint: None,
+        padding: [0; 7],
+    }
+    .try_to_vec()
+    .unwrap();
+
+    let mut program_test = ProgramTest::new("mpl_inscription", mpl_inscription::ID, None);
+    program_test.add_account(
+        inscription_metadata_account,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: mpl_inscription::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+
+    // When the authority sets a threshold on it.
+    let ix = SetAuthorityThresholdBuilder::new()
+        .inscription_metadata_account(inscription_metadata_account)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .threshold(1)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the account is resized and the threshold is stored.
+    let account = context
+        .banks_client
+        .get_account(inscription_metadata_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let inscription_metadata = InscriptionMetadata::from_bytes(&account.data).unwrap();
+
+    assert_eq!(inscription_metadata.authority_threshold, 1);
+    assert_eq!(
+        inscription_metadata.update_authorities,
+        vec![authority.pubkey()]
+    );
+}
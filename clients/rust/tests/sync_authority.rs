@@ -0,0 +1,158 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use borsh::BorshSerialize;
+use mpl_inscription::{
+    accounts::InscriptionMetadata,
+    errors::MplInscriptionError,
+    instructions::SyncAuthorityBuilder,
+    types::{AssociatedInscription, DataType, Key},
+};
+use solana_program_test::{tokio, BanksClientError, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    pubkey,
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+const SPL_TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const TOKEN_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// The inscription metadata layout from before any fields were appended to it.
+#[derive(BorshSerialize)]
+struct LegacyInscriptionMetadata {
+    key: Key,
+    inscription_account: Pubkey,
+    bump: u8,
+    data_type: DataType,
+    inscription_rank: u64,
+    inscription_bump: Option<u8>,
+    update_authorities: Vec<Pubkey>,
+    associated_inscriptions: Vec<AssociatedInscription>,
+    mint: Option<Pubkey>,
+    padding: [u8; 7],
+}
+
+fn program_account(owner: Pubkey, data: Vec<u8>) -> Account {
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[tokio::test]
+async fn admin_hands_over_to_new_update_authority_on_legacy_account() {
+    // Given a legacy mint inscription of the previous update authority of the mint.
+    let old_authority = Keypair::new();
+    let new_authority = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let token_metadata_account = Pubkey::new_unique();
+    let inscription_metadata_account = Pubkey::new_unique();
+
+    let inscription_metadata = LegacyInscriptionMetadata {
+        key: Key::MintInscriptionMetadataAccount,
+        inscription_account: Pubkey::new_unique(),
+        bump: 255,
+        data_type: DataType::Binary,
+        inscription_rank: 0,
+        inscription_bump: Some(255),
+        update_authorities: vec![old_authority.pubkey()],
+        associated_inscriptions: vec![],
+        mint: Some(mint),
+        padding: [0; 7],
+    }
+    .try_to_vec()
+    .unwrap();
+
+    // And Token Metadata that moved to a new update authority, up to the edition nonce.
+    let mut token_metadata = vec![4];
+    token_metadata.extend_from_slice(new_authority.as_ref());
+    token_metadata.extend_from_slice(mint.as_ref());
+    (
+        String::new(),
+        String::new(),
+        String::new(),
+        0u16,
+        None::<u8>,
+    )
+        .serialize(&mut token_metadata)
+        .unwrap();
+    (false, true, None::<u8>)
+        .serialize(&mut token_metadata)
+        .unwrap();
+
+    let mut program_test = ProgramTest::new("mpl_inscription", mpl_inscription::ID, None);
+    program_test.add_account(
+        inscription_metadata_account,
+        program_account(mpl_inscription::ID, inscription_metadata),
+    );
+    program_test.add_account(mint, program_account(SPL_TOKEN_PROGRAM_ID, vec![]));
+    program_test.add_account(
+        token_metadata_account,
+        program_account(TOKEN_METADATA_PROGRAM_ID, token_metadata),
+    );
+    let mut context = program_test.start_with_context().await;
+
+    let mut builder = SyncAuthorityBuilder::new();
+    builder
+        .inscription_metadata_account(inscription_metadata_account)
+        .mint_account(mint)
+        .token_metadata_account(token_metadata_account)
+        .payer(Some(context.payer.pubkey()))
+        .system_program(Some(system_program::ID));
+
+    // When anyone syncs it, then it fails since the new update authority isn't one of its own.
+    let tx = Transaction::new_signed_with_payer(
+        &[builder.instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_matches!(
+        error,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(code)
+        )) if code == MplInscriptionError::InvalidAuthority as u32
+    );
+
+    // When the old update authority signs the sync.
+    let tx = Transaction::new_signed_with_payer(
+        &[builder
+            .authority(Some(old_authority.pubkey()))
+            .instruction()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &old_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the new update authority takes its place and is followed from now on.
+    let account = context
+        .banks_client
+        .get_account(inscription_metadata_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let inscription_metadata = InscriptionMetadata::from_bytes(&account.data).unwrap();
+
+    assert_eq!(inscription_metadata.update_authorities, vec![new_authority]);
+    assert_eq!(
+        inscription_metadata.mint_update_authority,
+        Some(new_authority)
+    );
+}
@@ -75,6 +75,14 @@ kinobi.update(
         k.variableSeed("Shard Number", k.numberTypeNode('u8', 'le')),
       ],
     },
+    inscriptionRank: {
+      seeds: [
+        k.stringConstantSeed("Inscription"),
+        k.stringConstantSeed("Rank"),
+        k.programSeed(),
+        k.variableSeed("Rank", k.numberTypeNode('u64', 'le')),
+      ],
+    },
     tokenMetadataAccount: {
       seeds: [
         k.stringConstantSeed("metadata"),
@@ -135,6 +143,11 @@ kinobi.update(
       ignoreIfOptional: true,
       ...k.pdaDefault("associatedInscriptionAccount"),
     },
+    {
+      account: "compressionProgram",
+      ignoreIfOptional: true,
+      ...k.programDefault("splAccountCompression", "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"),
+    },
     // {
     //   account: "inscriptionMetadataAccount",
     //   ignoreIfOptional: true,
@@ -187,6 +200,8 @@ kinobi.update(
     inscriptionMetadataAccount: key("InscriptionMetadataAccount"),
     mintInscriptionMetadataAccount: key("MintInscriptionMetadataAccount"),
     inscriptionShardAccount: key("InscriptionShardAccount"),
+    inscriptionRankAccount: key("InscriptionRankAccount"),
+    inscriptionTombstoneAccount: key("InscriptionTombstoneAccount"),
   })
 );
 
@@ -195,6 +210,8 @@ const jsDir = path.join(clientDir, "js", "src", "generated");
 const prettier = require(path.join(clientDir, "js", ".prettierrc.json"));
 kinobi.accept(new k.RenderJavaScriptVisitor(jsDir, {
   prettier,
+  // Older inscription metadata accounts end before the appended fields.
+  customAccountData: ["inscriptionMetadata"],
   dependencyMap: {
     mplTokenMetadata: "@metaplex-foundation/mpl-token-metadata",
   },
@@ -212,6 +229,11 @@ kinobi.update(
   })
 );
 
+// The Rust client deserializes inscription metadata by hand to support older accounts.
+kinobi.update(
+  new k.DeleteNodesVisitor([{ kind: "accountNode", name: "inscriptionMetadata" }])
+);
+
 // Render Rust.
 const crateDir = path.join(clientDir, "rust");
 const rustDir = path.join(clientDir, "rust", "src", "generated");
@@ -1,7 +1,7 @@
 #!/bin/bash
 
-EXTERNAL_ID=("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s" "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV")
-EXTERNAL_SO=("mpl_token_metadata.so" "spl_noop.so")
+EXTERNAL_ID=("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s" "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV" "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY" "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK" "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d")
+EXTERNAL_SO=("mpl_token_metadata.so" "spl_noop.so" "bubblegum.so" "spl_account_compression.so" "mpl_core.so")
 
 # output colours
 RED() { echo $'\e[1;31m'$1$'\e[0m'; }
@@ -83,6 +83,62 @@ pub enum MplInscriptionError {
     /// 18 - Invalid Inscription Metadata Account
     #[error("The inscription metadata account is invalid.")]
     InvalidInscriptionMetadataAccount,
+
+    /// 19 - Inscription Frozen
+    #[error("The inscription is frozen and can no longer be modified.")]
+    InscriptionFrozen,
+
+    /// 20 - Invalid Compression Program
+    #[error("Invalid Compression Program")]
+    InvalidCompressionProgram,
+
+    /// 21 - Invalid Core Asset
+    #[error("Invalid Core Asset")]
+    InvalidCoreAsset,
+
+    /// 22 - Write Precondition Failed
+    #[error("The inscription data doesn't match the write precondition.")]
+    WritePreconditionFailed,
+
+    /// 23 - Copy Too Large
+    #[error("Can't copy more than 10 KiB of data in a single call.")]
+    CopyTooLarge,
+
+    /// 24 - Invalid Copy Range
+    #[error("The range to copy is outside of the source account's data.")]
+    InvalidCopyRange,
+
+    /// 25 - Initial Size Too Large
+    #[error("Can't initialize an account with more than 10 KiB of data.")]
+    InitialSizeTooLarge,
+
+    /// 26 - Cannot Remove Last Authority
+    #[error("Removing the last authority requires explicitly allowing it.")]
+    CannotRemoveLastAuthority,
+
+    /// 27 - Invalid Authority Threshold
+    #[error("The threshold can't be greater than the number of authorities.")]
+    InvalidAuthorityThreshold,
+
+    /// 28 - Authority Threshold Not Met
+    #[error("Not enough authorities signed to meet the threshold.")]
+    AuthorityThresholdNotMet,
+
+    /// 29 - Inscription Tombstoned
+    #[error("The inscription was closed and its address is reserved by a tombstone.")]
+    InscriptionTombstoned,
+
+    /// 30 - Invalid Rent Payer
+    #[error("Rent must be refunded to the account that paid for it.")]
+    InvalidRentPayer,
+
+    /// 31 - Content Type Too Long
+    #[error("The content type can't be longer than 64 characters.")]
+    ContentTypeTooLong,
+
+    /// 32 - Invalid Asset Metadata
+    #[error("The asset metadata does not match the compressed asset.")]
+    InvalidAssetMetadata,
 }
 
 impl PrintProgramError for MplInscriptionError {
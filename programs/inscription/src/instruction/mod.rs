@@ -2,6 +2,8 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use shank::{ShankContext, ShankInstruction};
 use solana_program::pubkey::Pubkey;
 
+use crate::state::{AuthorityRole, DataType};
+
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, ShankContext, ShankInstruction)]
 #[rustfmt::skip]
 pub enum MplInscriptionInstruction {
@@ -12,25 +14,30 @@ pub enum MplInscriptionInstruction {
     #[account(3, writable, signer, name="payer", desc="The account that will pay for the rent.")]
     #[account(4, optional, signer, name="authority", desc="The authority of the inscription account.")]
     #[account(5, name="system_program", desc = "System program")]
-    Initialize,
+    #[account(6, optional, writable, name="inscription_rank_account", desc="The account to record the inscription's rank in, for lookups by rank.")]
+    Initialize(InitializeArgs),
 
     /// Initialize the Inscription and Metadata accounts as a Mint PDA.
     #[account(0, writable, name="mint_inscription_account", desc = "The account where data is stored.")]
     #[account(1, writable, name="inscription_metadata_account", desc = "The account to store the inscription account's metadata in.")]
     #[account(2, name="mint_account", desc="The mint that will be used to derive the PDA.")]
-    #[account(3, name="token_metadata_account", desc="The metadata for the mint.")]
+    #[account(3, name="token_metadata_account", desc="The metadata for the mint, or the mint itself if it uses Token-2022 metadata.")]
     #[account(4, writable, name="inscription_shard_account", desc="The shard account for the inscription counter.")]
     #[account(5, writable, signer, name="payer", desc="The account that will pay for the rent.")]
     #[account(6, optional, signer, name="authority", desc="The authority of the inscription account.")]
     #[account(7, name="system_program", desc = "System program")]
+    #[account(8, optional, name="token_account", desc="The token account holding the NFT, if the authority is the holder.")]
+    #[account(9, optional, writable, name="inscription_rank_account", desc="The account to record the inscription's rank in, for lookups by rank.")]
     InitializeFromMint,
 
-    /// Close the Inscription and Metadata accounts.
+    /// Close the Inscription and Metadata accounts, optionally leaving a tombstone behind.
     #[account(0, writable, name="inscription_account", desc = "The account where data is stored.")]
     #[account(1, writable, name="inscription_metadata_account", desc = "The account to store the inscription account's metadata in.")]
     #[account(2, writable, signer, name="payer", desc="The account that will pay for the rent.")]
     #[account(3, optional, signer, name="authority", desc="The authority of the inscription account.")]
     #[account(4, name="system_program", desc = "System program")]
+    #[account(5, optional, writable, name="rent_payer", desc="The account that paid the rent, if it isn't the payer.")]
+    #[account(6, optional, writable, name="destination", desc="The account to send the lamports to, instead of the payer.")]
     Close(CloseArgs),
 
     /// Write data to the inscription account.
@@ -39,6 +46,7 @@ pub enum MplInscriptionInstruction {
     #[account(2, writable, signer, name="payer", desc="The account that will pay for the rent.")]
     #[account(3, optional, signer, name="authority", desc="The authority of the inscription account.")]
     #[account(4, name="system_program", desc = "System program")]
+    #[account(5, optional, writable, name="staging_account", desc="The staging buffer to take the data from, in place of the value.")]
     WriteData(WriteDataArgs),
 
     /// Clear the inscription account.
@@ -47,6 +55,7 @@ pub enum MplInscriptionInstruction {
     #[account(2, writable, signer, name="payer", desc="The account that will pay for the rent.")]
     #[account(3, optional, signer, name="authority", desc="The authority of the inscription account.")]
     #[account(4, name="system_program", desc = "System program")]
+    #[account(5, optional, writable, name="rent_payer", desc="The account that paid the rent, if it isn't the payer.")]
     ClearData(ClearDataArgs),
 
     /// Add an update authority to the Inscription.
@@ -59,9 +68,9 @@ pub enum MplInscriptionInstruction {
     /// Remove an update authority from the Inscription account.
     #[account(0, writable, name="inscription_metadata_account", desc = "The account to store the metadata's metadata in.")]
     #[account(1, writable, signer, name="payer", desc="The account paying for the transaction and rent.")]
-    #[account(2, optional, signer, name="authority", desc="The authority of the inscription account to be removed.")]
+    #[account(2, optional, signer, name="authority", desc="The authority of the inscription account.")]
     #[account(3, name="system_program", desc = "System program")]
-    RemoveAuthority,
+    RemoveAuthority(RemoveAuthorityArgs),
 
     /// Create an Inscription Shard account for counting inscriptions.
     #[account(0, writable, name="shard_account", desc = "The account to store the shard data in.")]
@@ -92,13 +101,181 @@ pub enum MplInscriptionInstruction {
     #[account(2, name="mint_account", desc="The mint that will be used to derive the PDA.")]
     #[account(3, writable, signer, name="payer", desc="The account that will pay for the rent.")]
     #[account(4, name="system_program", desc = "System program")]
-    SetMint,
+    #[account(5, optional, signer, name="authority", desc="The authority of the inscription account or the update authority of the mint.")]
+    #[account(6, optional, name="token_metadata_account", desc="The metadata for the mint, or the mint itself if it uses Token-2022 metadata, if the authority is its update authority.")]
+    SetMint(SetMintArgs),
+
+    /// Set the data type of the inscription or an associated inscription.
+    #[account(0, name="inscription_account", desc = "The account where data is stored.")]
+    #[account(1, writable, name="inscription_metadata_account", desc = "The account to store the inscription account's metadata in.")]
+    #[account(2, writable, signer, name="payer", desc="The account that will pay for the rent.")]
+    #[account(3, optional, signer, name="authority", desc="The authority of the inscription account.")]
+    #[account(4, name="system_program", desc = "System program")]
+    SetDataType(SetDataTypeArgs),
+
+    /// Permanently freeze the inscription so it can no longer be modified.
+    #[account(0, writable, name="inscription_metadata_account", desc = "The account to store the metadata's metadata in.")]
+    #[account(1, writable, signer, name="payer", desc="The account paying for the transaction and rent.")]
+    #[account(2, optional, signer, name="authority", desc="The authority of the inscription account.")]
+    #[account(3, name="system_program", desc = "System program")]
+    Freeze,
+
+    /// Initialize the Inscription and Metadata accounts as a compressed asset PDA.
+    #[account(0, writable, name="asset_inscription_account", desc = "The account where data is stored.")]
+    #[account(1, writable, name="inscription_metadata_account", desc = "The account to store the inscription account's metadata in.")]
+    #[account(2, name="asset_id", desc="The compressed asset id that will be used to derive the PDA.")]
+    #[account(3, name="tree_config", desc="The Bubblegum config account of the Merkle tree.")]
+    #[account(4, name="merkle_tree", desc="The Merkle tree holding the compressed asset.")]
+    #[account(5, name="leaf_owner", desc="The owner of the compressed asset.")]
+    #[account(6, name="leaf_delegate", desc="The delegate of the compressed asset.")]
+    #[account(7, writable, name="inscription_shard_account", desc="The shard account for the inscription counter.")]
+    #[account(8, writable, signer, name="payer", desc="The account that will pay for the rent.")]
+    #[account(9, optional, signer, name="authority", desc="The update authority of the asset, either that of its verified collection or the creator or delegate of its private tree.")]
+    #[account(10, name="system_program", desc = "System program")]
+    #[account(11, name="compression_program", desc = "SPL Account Compression program")]
+    #[account(12, optional, name="collection_metadata", desc="The Token Metadata account of the asset's verified collection, if the authority is its update authority.")]
+    #[account(13, optional, writable, name="inscription_rank_account", desc="The account to record the inscription's rank in, for lookups by rank.")]
+    InitializeFromAsset(InitializeFromAssetArgs),
+
+    /// Initialize the Inscription and Metadata accounts as an mpl-core Asset PDA.
+    #[account(0, writable, name="mint_inscription_account", desc = "The account where data is stored.")]
+    #[account(1, writable, name="inscription_metadata_account", desc = "The account to store the inscription account's metadata in.")]
+    #[account(2, name="asset", desc="The mpl-core asset that will be used to derive the PDA.")]
+    #[account(3, optional, name="collection", desc="The collection of the asset, if it is the update authority.")]
+    #[account(4, writable, name="inscription_shard_account", desc="The shard account for the inscription counter.")]
+    #[account(5, writable, signer, name="payer", desc="The account that will pay for the rent.")]
+    #[account(6, optional, signer, name="authority", desc="The authority of the inscription account.")]
+    #[account(7, name="system_program", desc = "System program")]
+    #[account(8, optional, writable, name="inscription_rank_account", desc="The account to record the inscription's rank in, for lookups by rank.")]
+    InitializeFromCoreAsset,
+
+    /// Commit to the hash of the inscription account's current data.
+    #[account(0, name="inscription_account", desc = "The account where data is stored.")]
+    #[account(1, writable, name="inscription_metadata_account", desc = "The account to store the inscription account's metadata in.")]
+    #[account(2, writable, signer, name="payer", desc="The account that will pay for the rent.")]
+    #[account(3, optional, signer, name="authority", desc="The authority of the inscription account.")]
+    #[account(4, name="system_program", desc = "System program")]
+    CommitHash,
+
+    /// Write several segments of data to the inscription and its associated inscriptions.
+    #[account(0, writable, name="inscription_metadata_account", desc = "The account to store the inscription account's metadata in.")]
+    #[account(1, writable, signer, name="payer", desc="The account that will pay for the rent.")]
+    #[account(2, optional, signer, name="authority", desc="The authority of the inscription account.")]
+    #[account(3, name="system_program", desc = "System program")]
+    WriteSegments(WriteSegmentsArgs),
+
+    /// Copy a range of data from one inscription account to another.
+    #[account(0, name="source_account", desc = "The inscription account to copy data from.")]
+    #[account(1, writable, name="inscription_account", desc = "The account where data is stored.")]
+    #[account(2, writable, name="inscription_metadata_account", desc = "The account to store the inscription account's metadata in.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the rent.")]
+    #[account(4, optional, signer, name="authority", desc="The authority of the inscription account.")]
+    #[account(5, name="system_program", desc = "System program")]
+    CopyData(CopyDataArgs),
+
+    /// Write data to a staging buffer to be committed to the inscription later.
+    #[account(0, writable, name="staging_account", desc = "The staging buffer where data is stored.")]
+    #[account(1, name="inscription_metadata_account", desc = "The metadata account of the inscription the data is staged for.")]
+    #[account(2, writable, signer, name="payer", desc="The account that will pay for the rent.")]
+    #[account(3, optional, signer, name="authority", desc="The authority of the inscription account.")]
+    #[account(4, name="system_program", desc = "System program")]
+    WriteStagingBuffer(WriteStagingBufferArgs),
+
+    /// Replace an update authority of the Inscription with a new one.
+    #[account(0, writable, name="inscription_metadata_account", desc = "The account to store the metadata's metadata in.")]
+    #[account(1, writable, signer, name="payer", desc="The account paying for the transaction and rent.")]
+    #[account(2, optional, signer, name="authority", desc="The authority of the inscription account.")]
+    #[account(3, name="system_program", desc = "System program")]
+    ReplaceAuthority(ReplaceAuthorityArgs),
+
+    /// Add a write delegate to the Inscription, or update its expiry.
+    #[account(0, writable, name="inscription_metadata_account", desc = "The account to store the metadata's metadata in.")]
+    #[account(1, writable, signer, name="payer", desc="The account that will pay for the rent.")]
+    #[account(2, optional, signer, name="authority", desc="The authority of the inscription account.")]
+    #[account(3, name="system_program", desc = "System program")]
+    AddWriteDelegate(AddWriteDelegateArgs),
+
+    /// Remove the expired write delegates from the Inscription.
+    #[account(0, writable, name="inscription_metadata_account", desc = "The account to store the metadata's metadata in.")]
+    #[account(1, writable, signer, name="payer", desc="The account that will receive the refunded rent.")]
+    PruneWriteDelegates,
+
+    /// Set the number of authorities that must sign destructive instructions, 0 to disable.
+    /// The additional authorities sign as remaining accounts.
+    #[account(0, writable, name="inscription_metadata_account", desc = "The account to store the metadata's metadata in.")]
+    #[account(1, writable, signer, name="payer", desc="The account that will pay for the rent.")]
+    #[account(2, optional, signer, name="authority", desc="The authority of the inscription account.")]
+    #[account(3, name="system_program", desc = "System program")]
+    SetAuthorityThreshold(SetAuthorityThresholdArgs),
+
+    /// Sync the mint inscription's authorities with the current update authority and holder of the mint.
+    /// The holder of a holder inscription stays a Writer after a transfer, until anyone syncs it
+    /// with the token account of the new holder.
+    /// Older inscriptions only start following the update authority once it is one of theirs, or
+    /// once one of their Admins hands its place over to it.
+    #[account(0, writable, name="inscription_metadata_account", desc = "The account to store the inscription account's metadata in.")]
+    #[account(1, name="mint_account", desc="The mint the inscription was created from.")]
+    #[account(2, name="token_metadata_account", desc="The metadata for the mint, or the mint itself if it uses Token-2022 metadata.")]
+    #[account(3, optional, name="token_account", desc="The token account holding the NFT, to sync the holder of a holder inscription.")]
+    #[account(4, optional, writable, signer, name="payer", desc="The account that will pay for the rent, if older metadata accounts need to grow.")]
+    #[account(5, optional, name="system_program", desc = "System program")]
+    #[account(6, optional, signer, name="authority", desc="An Admin of an older inscription handing its place over to the current update authority of the mint.")]
+    SyncAuthority,
+
+    /// Close every Associated Inscription account, then the Inscription and Metadata accounts.
+    /// The associated inscription accounts are passed as remaining accounts in order.
+    #[account(0, writable, name="inscription_account", desc = "The account where data is stored.")]
+    #[account(1, writable, name="inscription_metadata_account", desc = "The account to store the inscription account's metadata in.")]
+    #[account(2, writable, signer, name="payer", desc="The account that will pay for the rent.")]
+    #[account(3, optional, signer, name="authority", desc="The authority of the inscription account.")]
+    #[account(4, name="system_program", desc = "System program")]
+    #[account(5, optional, writable, name="rent_payer", desc="The account that paid the rent, if it isn't the payer.")]
+    #[account(6, optional, writable, name="destination", desc="The account to send the lamports to, instead of the payer.")]
+    CloseAll(CloseAllArgs),
+
+    /// Move the lamports above the rent-exempt minimum out of an inscription, associated inscription or metadata account.
+    #[account(0, writable, name="inscription_metadata_account", desc = "The account to store the inscription account's metadata in.")]
+    #[account(1, writable, name="target_account", desc="The inscription, associated inscription or metadata account to reclaim rent from.")]
+    #[account(2, writable, signer, name="payer", desc="The account that will pay for the transaction.")]
+    #[account(3, optional, signer, name="authority", desc="The authority of the inscription account.")]
+    #[account(4, optional, writable, name="rent_payer", desc="The account that paid the rent, if it isn't the payer.")]
+    #[account(5, optional, writable, name="destination", desc="The account to send the lamports to, instead of the payer.")]
+    ReclaimRent(ReclaimRentArgs),
+
+    /// Set or clear the content type of an associated inscription.
+    #[account(0, name="associated_inscription_account", desc = "The associated inscription account to update.")]
+    #[account(1, writable, name="inscription_metadata_account", desc = "The account to store the inscription account's metadata in.")]
+    #[account(2, writable, signer, name="payer", desc="The account that will pay for the rent.")]
+    #[account(3, optional, signer, name="authority", desc="The authority of the inscription account.")]
+    #[account(4, name="system_program", desc = "System program")]
+    SetContentType(SetContentTypeArgs),
+
+    /// Discard a staging buffer without committing it to the inscription.
+    #[account(0, writable, name="staging_account", desc = "The staging buffer to discard.")]
+    #[account(1, name="inscription_metadata_account", desc = "The metadata account of the inscription the data was staged for.")]
+    #[account(2, writable, signer, name="payer", desc="The account receiving the rent.")]
+    #[account(3, optional, signer, name="authority", desc="The authority that staged the data.")]
+    CloseStagingBuffer,
+
+    /// Record the rank of an existing inscription so it can be looked up by it.
+    #[account(0, name="inscription_metadata_account", desc = "The metadata account of the inscription.")]
+    #[account(1, writable, name="inscription_rank_account", desc="The account to record the inscription's rank in, for lookups by rank.")]
+    #[account(2, writable, signer, name="payer", desc="The account that will pay for the rent.")]
+    #[account(3, name="system_program", desc = "System program")]
+    RecordInscriptionRank,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct InitializeArgs {
+    pub initial_size: Option<usize>,
 }
 
 #[repr(C)]
 #[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct CloseArgs {
     pub associated_tag: Option<String>,
+    pub tombstone: bool,
 }
 
 #[repr(C)]
@@ -107,6 +284,14 @@ pub struct WriteDataArgs {
     pub associated_tag: Option<String>,
     pub offset: usize,
     pub value: Vec<u8>,
+    pub precondition: Option<WritePrecondition>,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct WritePrecondition {
+    pub expected_length: Option<u64>,
+    pub expected_hash: Option<[u8; 32]>,
 }
 
 #[repr(C)]
@@ -126,6 +311,14 @@ pub struct AllocateArgs {
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct AddAuthorityArgs {
     pub new_authority: Pubkey,
+    pub role: AuthorityRole,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct RemoveAuthorityArgs {
+    pub target: Option<Pubkey>,
+    pub allow_last_authority: bool,
 }
 
 #[repr(C)]
@@ -138,4 +331,100 @@ pub struct CreateShardArgs {
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct AssociateInscriptionAccountArgs {
     pub association_tag: String,
+    pub initial_size: Option<usize>,
+    pub data_type: DataType,
+    pub content_type: Option<String>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct SetDataTypeArgs {
+    pub associated_tag: Option<String>,
+    pub data_type: DataType,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct InitializeFromAssetArgs {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+    pub metadata: Vec<u8>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct WriteSegment {
+    pub associated_tag: Option<String>,
+    pub offset: usize,
+    pub value: Vec<u8>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct WriteSegmentsArgs {
+    pub segments: Vec<WriteSegment>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct CopyDataArgs {
+    pub associated_tag: Option<String>,
+    pub source_offset: usize,
+    pub length: usize,
+    pub offset: usize,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct WriteStagingBufferArgs {
+    pub offset: usize,
+    pub value: Vec<u8>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ReplaceAuthorityArgs {
+    pub target: Option<Pubkey>,
+    pub new_authority: Pubkey,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct AddWriteDelegateArgs {
+    pub delegate: Pubkey,
+    pub expires_at: i64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct SetAuthorityThresholdArgs {
+    pub threshold: u8,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct SetMintArgs {
+    pub backfill_inscription_bump: bool,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct CloseAllArgs {
+    pub tombstone: bool,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ReclaimRentArgs {
+    pub associated_tag: Option<String>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct SetContentTypeArgs {
+    pub association_tag: String,
+    pub content_type: Option<String>,
 }
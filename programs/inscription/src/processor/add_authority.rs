@@ -8,7 +8,7 @@ use solana_program::{
 use crate::{
     error::MplInscriptionError,
     instruction::{accounts::AddAuthorityAccounts, AddAuthorityArgs},
-    state::InscriptionMetadata,
+    state::{AuthorityRole, InscriptionMetadata},
 };
 
 pub(crate) fn process_add_authority<'a>(
@@ -27,6 +27,11 @@ pub(crate) fn process_add_authority<'a>(
         &ctx.accounts.inscription_metadata_account.data.borrow(),
     )?;
 
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
     // The payer must sign as well as the authority, if present.
     let authority = match ctx.accounts.authority {
         Some(authority) => {
@@ -38,13 +43,15 @@ pub(crate) fn process_add_authority<'a>(
 
     assert_signer(ctx.accounts.payer)?;
 
-    if !inscription_metadata
-        .update_authorities
-        .contains(authority.key)
-    {
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Admin) {
         return Err(MplInscriptionError::InvalidAuthority.into());
     }
 
+    // Destructive actions need enough authorities to sign when a threshold is set.
+    if !inscription_metadata.meets_threshold(authority, ctx.remaining_accounts) {
+        return Err(MplInscriptionError::AuthorityThresholdNotMet.into());
+    }
+
     if ctx.accounts.system_program.key != &system_program::ID {
         return Err(MplInscriptionError::InvalidSystemProgram.into());
     }
@@ -60,6 +67,7 @@ pub(crate) fn process_add_authority<'a>(
     inscription_metadata
         .update_authorities
         .push(args.new_authority);
+    inscription_metadata.authority_roles.push(args.role);
 
     // Write the updated inscription metadata account back to the account.
     let serialized_data = inscription_metadata.try_to_vec()?;
@@ -0,0 +1,89 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::{assert_signer, resize_or_reallocate_account_raw};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+    system_program,
+};
+
+use crate::{
+    error::MplInscriptionError,
+    instruction::{accounts::AddWriteDelegateAccounts, AddWriteDelegateArgs},
+    state::{AuthorityRole, InscriptionMetadata, WriteDelegate},
+};
+
+pub(crate) fn process_add_write_delegate<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: AddWriteDelegateArgs,
+) -> ProgramResult {
+    let ctx = &AddWriteDelegateAccounts::context(accounts)?;
+
+    // Check that the account is already initialized.
+    if (ctx.accounts.inscription_metadata_account.owner != &crate::ID)
+        || ctx.accounts.inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+    let mut inscription_metadata = InscriptionMetadata::try_from_slice(
+        &ctx.accounts.inscription_metadata_account.data.borrow(),
+    )?;
+
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
+
+    assert_signer(ctx.accounts.payer)?;
+
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Admin) {
+        return Err(MplInscriptionError::InvalidAuthority.into());
+    }
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplInscriptionError::InvalidSystemProgram.into());
+    }
+
+    // Update the expiry of an existing delegate or add a new one.
+    match inscription_metadata
+        .write_delegates
+        .iter_mut()
+        .find(|delegate| delegate.address == args.delegate)
+    {
+        Some(delegate) => delegate.expires_at = args.expires_at,
+        None => inscription_metadata.write_delegates.push(WriteDelegate {
+            address: args.delegate,
+            expires_at: args.expires_at,
+        }),
+    }
+
+    // Write the updated inscription metadata account back to the account.
+    let serialized_data = inscription_metadata.try_to_vec()?;
+
+    // Resize the account to fit the new delegate.
+    resize_or_reallocate_account_raw(
+        ctx.accounts.inscription_metadata_account,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        serialized_data.len(),
+    )?;
+
+    // Write the inscription metadata to the metadata account.
+    sol_memcpy(
+        &mut ctx
+            .accounts
+            .inscription_metadata_account
+            .try_borrow_mut_data()?,
+        &serialized_data,
+        serialized_data.len(),
+    );
+
+    Ok(())
+}
@@ -2,14 +2,17 @@ use borsh::BorshDeserialize;
 use mpl_utils::{assert_derivation, assert_signer, resize_or_reallocate_account_raw};
 use solana_program::{
     account_info::AccountInfo,
+    clock::Clock,
     entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
     system_program,
+    sysvar::Sysvar,
 };
 
+use super::commit_hash::write_inscription_metadata;
 use crate::{
     error::MplInscriptionError,
     instruction::{accounts::AllocateAccounts, AllocateArgs},
-    state::{InscriptionMetadata, ASSOCIATION, PREFIX},
+    state::{AuthorityRole, InscriptionMetadata, ASSOCIATION, PREFIX},
 };
 
 pub(crate) fn process_allocate<'a>(
@@ -27,10 +30,15 @@ pub(crate) fn process_allocate<'a>(
     if ctx.accounts.inscription_metadata_account.owner != &crate::ID {
         return Err(MplInscriptionError::NotInitialized.into());
     }
-    let inscription_metadata = InscriptionMetadata::try_from_slice(
+    let mut inscription_metadata = InscriptionMetadata::try_from_slice(
         &ctx.accounts.inscription_metadata_account.data.borrow(),
     )?;
 
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
     // Verify that the derived address is correct for the metadata account.
     match args.associated_tag {
         Some(tag) => {
@@ -95,9 +103,9 @@ pub(crate) fn process_allocate<'a>(
 
     assert_signer(ctx.accounts.payer)?;
 
-    if !inscription_metadata
-        .update_authorities
-        .contains(authority.key)
+    // Write delegates can write until they expire.
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Writer)
+        && !inscription_metadata.is_write_delegate(authority.key, Clock::get()?.unix_timestamp)
     {
         return Err(MplInscriptionError::InvalidAuthority.into());
     }
@@ -115,6 +123,17 @@ pub(crate) fn process_allocate<'a>(
 
     let new_size = std::cmp::min(args.target_size, max_realloc_size);
 
+    // Shrinking the account drops data, so only authorities meeting the threshold can do it.
+    if new_size < ctx.accounts.inscription_account.data_len() {
+        if !inscription_metadata.has_role(authority.key, AuthorityRole::Writer) {
+            return Err(MplInscriptionError::InvalidAuthority.into());
+        }
+
+        if !inscription_metadata.meets_threshold(authority, ctx.remaining_accounts) {
+            return Err(MplInscriptionError::AuthorityThresholdNotMet.into());
+        }
+    }
+
     // Resize the account to fit the new authority.
     resize_or_reallocate_account_raw(
         ctx.accounts.inscription_account,
@@ -123,5 +142,17 @@ pub(crate) fn process_allocate<'a>(
         new_size,
     )?;
 
+    // Any change to the inscription's data invalidates its content hash commitment.
+    if ctx.accounts.inscription_account.key == &inscription_metadata.inscription_account
+        && inscription_metadata.invalidate_content_hash()
+    {
+        write_inscription_metadata(
+            ctx.accounts.inscription_metadata_account,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            &inscription_metadata,
+        )?;
+    }
+
     Ok(())
 }
@@ -1,11 +1,15 @@
 use borsh::BorshDeserialize;
 use mpl_utils::{assert_derivation, assert_signer, resize_or_reallocate_account_raw};
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, system_program};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, rent::Rent, system_program,
+    sysvar::Sysvar,
+};
 
+use super::commit_hash::write_inscription_metadata;
 use crate::{
     error::MplInscriptionError,
     instruction::{accounts::ClearDataAccounts, ClearDataArgs},
-    state::{InscriptionMetadata, ASSOCIATION, PREFIX},
+    state::{AuthorityRole, InscriptionMetadata, ASSOCIATION, PREFIX},
 };
 
 pub(crate) fn process_clear_data<'a>(
@@ -28,10 +32,15 @@ pub(crate) fn process_clear_data<'a>(
     if ctx.accounts.inscription_metadata_account.owner != &crate::ID {
         return Err(MplInscriptionError::NotInitialized.into());
     }
-    let inscription_metadata = InscriptionMetadata::try_from_slice(
+    let mut inscription_metadata = InscriptionMetadata::try_from_slice(
         &ctx.accounts.inscription_metadata_account.data.borrow(),
     )?;
 
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
     // Verify that the derived address is correct for the metadata account.
     match args.associated_tag {
         Some(tag) => {
@@ -96,17 +105,24 @@ pub(crate) fn process_clear_data<'a>(
 
     assert_signer(ctx.accounts.payer)?;
 
-    if !inscription_metadata
-        .update_authorities
-        .contains(authority.key)
-    {
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Writer) {
         return Err(MplInscriptionError::InvalidAuthority.into());
     }
 
+    // Destructive actions need enough authorities to sign when a threshold is set.
+    if !inscription_metadata.meets_threshold(authority, ctx.remaining_accounts) {
+        return Err(MplInscriptionError::AuthorityThresholdNotMet.into());
+    }
+
     if ctx.accounts.system_program.key != &system_program::ID {
         return Err(MplInscriptionError::InvalidSystemProgram.into());
     }
 
+    // Rent goes back to whoever paid for it.
+    let rent_destination = inscription_metadata
+        .rent_destination(ctx.accounts.payer, ctx.accounts.rent_payer, None)
+        .ok_or(MplInscriptionError::InvalidRentPayer)?;
+
     // Resize the account to fit the new authority.
     resize_or_reallocate_account_raw(
         ctx.accounts.inscription_account,
@@ -115,5 +131,33 @@ pub(crate) fn process_clear_data<'a>(
         0,
     )?;
 
+    // Refund the rent that is no longer needed to the rent payer.
+    let rent_amount = Rent::get()?.minimum_balance(0);
+    let excess_lamports = ctx
+        .accounts
+        .inscription_account
+        .lamports()
+        .saturating_sub(rent_amount);
+
+    let destination_lamports = rent_destination
+        .lamports()
+        .checked_add(excess_lamports)
+        .ok_or(MplInscriptionError::NumericalOverflow)?;
+
+    **ctx.accounts.inscription_account.try_borrow_mut_lamports()? -= excess_lamports;
+    **rent_destination.try_borrow_mut_lamports()? = destination_lamports;
+
+    // Any change to the inscription's data invalidates its content hash commitment.
+    if ctx.accounts.inscription_account.key == &inscription_metadata.inscription_account
+        && inscription_metadata.invalidate_content_hash()
+    {
+        write_inscription_metadata(
+            ctx.accounts.inscription_metadata_account,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            &inscription_metadata,
+        )?;
+    }
+
     Ok(())
 }
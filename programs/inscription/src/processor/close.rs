@@ -3,14 +3,14 @@ use mpl_utils::{
     assert_derivation, assert_signer, close_account_raw, resize_or_reallocate_account_raw,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
-    system_program,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, program_memory::sol_memcpy,
+    rent::Rent, system_program, sysvar::Sysvar,
 };
 
 use crate::{
     error::MplInscriptionError,
     instruction::{accounts::CloseAccounts, CloseArgs},
-    state::{InscriptionMetadata, ASSOCIATION, PREFIX},
+    state::{AuthorityRole, InscriptionMetadata, InscriptionTombstone, ASSOCIATION, PREFIX},
 };
 
 pub(crate) fn process_close<'a>(accounts: &'a [AccountInfo<'a>], args: CloseArgs) -> ProgramResult {
@@ -32,6 +32,11 @@ pub(crate) fn process_close<'a>(accounts: &'a [AccountInfo<'a>], args: CloseArgs
         &ctx.accounts.inscription_metadata_account.data.borrow(),
     )?;
 
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
     // The payer must sign as well as the authority, if present.
     let authority = match ctx.accounts.authority {
         Some(authority) => {
@@ -42,17 +47,28 @@ pub(crate) fn process_close<'a>(accounts: &'a [AccountInfo<'a>], args: CloseArgs
     };
 
     assert_signer(ctx.accounts.payer)?;
-    if !inscription_metadata
-        .update_authorities
-        .contains(authority.key)
-    {
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Admin) {
         return Err(MplInscriptionError::InvalidAuthority.into());
     }
 
+    // Destructive actions need enough authorities to sign when a threshold is set.
+    if !inscription_metadata.meets_threshold(authority, ctx.remaining_accounts) {
+        return Err(MplInscriptionError::AuthorityThresholdNotMet.into());
+    }
+
     if ctx.accounts.system_program.key != &system_program::ID {
         return Err(MplInscriptionError::InvalidSystemProgram.into());
     }
 
+    // Rent goes back to whoever paid for it, or the destination if they are closing.
+    let rent_destination = inscription_metadata
+        .rent_destination(
+            ctx.accounts.payer,
+            ctx.accounts.rent_payer,
+            ctx.accounts.destination,
+        )
+        .ok_or(MplInscriptionError::InvalidRentPayer)?;
+
     // Verify that the derived address is correct for the metadata account.
     match args.associated_tag {
         Some(tag) => {
@@ -95,6 +111,7 @@ pub(crate) fn process_close<'a>(accounts: &'a [AccountInfo<'a>], args: CloseArgs
                 .retain(|associated_inscription| {
                     !(associated_inscription.tag == tag && associated_inscription.bump == bump)
                 });
+            inscription_metadata.set_content_type(&tag, None);
 
             // Write the updated inscription metadata account back to the account.
             let serialized_data = inscription_metadata.try_to_vec()?;
@@ -117,7 +134,7 @@ pub(crate) fn process_close<'a>(accounts: &'a [AccountInfo<'a>], args: CloseArgs
                 serialized_data.len(),
             );
 
-            close_account_raw(ctx.accounts.payer, ctx.accounts.inscription_account)?;
+            close_account_raw(rent_destination, ctx.accounts.inscription_account)?;
         }
         None => {
             if !inscription_metadata.associated_inscriptions.is_empty() {
@@ -138,14 +155,74 @@ pub(crate) fn process_close<'a>(accounts: &'a [AccountInfo<'a>], args: CloseArgs
                 return Err(MplInscriptionError::DerivedKeyInvalid.into());
             }
 
-            // Close both accounts
-            close_account_raw(ctx.accounts.payer, ctx.accounts.inscription_account)?;
-            close_account_raw(
-                ctx.accounts.payer,
-                ctx.accounts.inscription_metadata_account,
-            )?;
+            if args.tombstone {
+                write_tombstone(
+                    &inscription_metadata,
+                    ctx.accounts.inscription_account,
+                    ctx.accounts.inscription_metadata_account,
+                    ctx.accounts.payer,
+                    ctx.accounts.system_program,
+                    rent_destination,
+                )?;
+            }
+
+            // Close both accounts, unless the metadata account now holds a tombstone.
+            close_account_raw(rent_destination, ctx.accounts.inscription_account)?;
+            if !args.tombstone {
+                close_account_raw(rent_destination, ctx.accounts.inscription_metadata_account)?;
+            }
         }
     }
 
     Ok(())
 }
+
+/// Replace the inscription metadata with a tombstone that keeps its address and rank reserved.
+pub(crate) fn write_tombstone<'a>(
+    inscription_metadata: &InscriptionMetadata,
+    inscription_account: &AccountInfo<'a>,
+    inscription_metadata_account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    rent_destination: &AccountInfo<'a>,
+) -> ProgramResult {
+    let serialized_data = InscriptionTombstone {
+        bump: inscription_metadata.bump,
+        inscription_account: *inscription_account.key,
+        inscription_rank: inscription_metadata.inscription_rank,
+        closed_slot: Clock::get()?.slot,
+        content_hash: inscription_metadata.content_hash,
+        ..InscriptionTombstone::default()
+    }
+    .try_to_vec()?;
+
+    resize_or_reallocate_account_raw(
+        inscription_metadata_account,
+        payer,
+        system_program,
+        serialized_data.len(),
+    )?;
+
+    // Write the tombstone to the metadata account.
+    sol_memcpy(
+        &mut inscription_metadata_account.try_borrow_mut_data()?,
+        &serialized_data,
+        serialized_data.len(),
+    );
+
+    // Refund the rent that is no longer needed to the rent payer.
+    let rent_amount = Rent::get()?.minimum_balance(serialized_data.len());
+    let excess_lamports = inscription_metadata_account
+        .lamports()
+        .saturating_sub(rent_amount);
+
+    let destination_lamports = rent_destination
+        .lamports()
+        .checked_add(excess_lamports)
+        .ok_or(MplInscriptionError::NumericalOverflow)?;
+
+    **inscription_metadata_account.try_borrow_mut_lamports()? -= excess_lamports;
+    **rent_destination.try_borrow_mut_lamports()? = destination_lamports;
+
+    Ok(())
+}
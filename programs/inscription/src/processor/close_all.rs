@@ -0,0 +1,138 @@
+use borsh::BorshDeserialize;
+use mpl_utils::{assert_derivation, assert_signer, close_account_raw};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    system_program,
+};
+
+use super::close::write_tombstone;
+use crate::{
+    error::MplInscriptionError,
+    instruction::{accounts::CloseAllAccounts, CloseAllArgs},
+    state::{AuthorityRole, InscriptionMetadata, ASSOCIATION, PREFIX},
+};
+
+pub(crate) fn process_close_all<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: CloseAllArgs,
+) -> ProgramResult {
+    let ctx = &CloseAllAccounts::context(accounts)?;
+
+    // Check that the account is already initialized.
+    if ctx.accounts.inscription_account.owner != &crate::ID {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+
+    // Check that the account is already initialized.
+    if (ctx.accounts.inscription_metadata_account.owner != &crate::ID)
+        || ctx.accounts.inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+
+    let inscription_metadata = InscriptionMetadata::try_from_slice(
+        &ctx.accounts.inscription_metadata_account.data.borrow(),
+    )?;
+
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
+
+    assert_signer(ctx.accounts.payer)?;
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Admin) {
+        return Err(MplInscriptionError::InvalidAuthority.into());
+    }
+
+    // The associated inscriptions come first in the remaining accounts, then any cosigners.
+    let associated_count = inscription_metadata.associated_inscriptions.len();
+    if ctx.remaining_accounts.len() < associated_count {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (associated_accounts, cosigners) = ctx.remaining_accounts.split_at(associated_count);
+
+    // Destructive actions need enough authorities to sign when a threshold is set.
+    if !inscription_metadata.meets_threshold(authority, cosigners) {
+        return Err(MplInscriptionError::AuthorityThresholdNotMet.into());
+    }
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplInscriptionError::InvalidSystemProgram.into());
+    }
+
+    // Rent goes back to whoever paid for it, or the destination if they are closing.
+    let rent_destination = inscription_metadata
+        .rent_destination(
+            ctx.accounts.payer,
+            ctx.accounts.rent_payer,
+            ctx.accounts.destination,
+        )
+        .ok_or(MplInscriptionError::InvalidRentPayer)?;
+
+    // Verify that the derived address is correct for the metadata account.
+    let bump = assert_derivation(
+        &crate::ID,
+        ctx.accounts.inscription_metadata_account,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            ctx.accounts.inscription_account.key.as_ref(),
+        ],
+        MplInscriptionError::DerivedKeyInvalid,
+    )?;
+    if bump != inscription_metadata.bump {
+        return Err(MplInscriptionError::DerivedKeyInvalid.into());
+    }
+
+    // Close every Associated Inscription account.
+    for (associated_inscription, associated_account) in inscription_metadata
+        .associated_inscriptions
+        .iter()
+        .zip(associated_accounts.iter())
+    {
+        let bump = assert_derivation(
+            &crate::ID,
+            associated_account,
+            &[
+                PREFIX.as_bytes(),
+                ASSOCIATION.as_bytes(),
+                associated_inscription.tag.as_bytes(),
+                ctx.accounts.inscription_metadata_account.key.as_ref(),
+            ],
+            MplInscriptionError::DerivedKeyInvalid,
+        )?;
+        if bump != associated_inscription.bump {
+            return Err(MplInscriptionError::DerivedKeyInvalid.into());
+        }
+
+        close_account_raw(rent_destination, associated_account)?;
+    }
+
+    if args.tombstone {
+        write_tombstone(
+            &inscription_metadata,
+            ctx.accounts.inscription_account,
+            ctx.accounts.inscription_metadata_account,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            rent_destination,
+        )?;
+    }
+
+    // Close both accounts, unless the metadata account now holds a tombstone.
+    close_account_raw(rent_destination, ctx.accounts.inscription_account)?;
+    if !args.tombstone {
+        close_account_raw(rent_destination, ctx.accounts.inscription_metadata_account)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use mpl_utils::{assert_derivation, assert_signer, close_account_raw};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use crate::{
+    error::MplInscriptionError,
+    instruction::accounts::CloseStagingBufferAccounts,
+    state::{PREFIX, STAGING},
+};
+
+pub(crate) fn process_close_staging_buffer<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = &CloseStagingBufferAccounts::context(accounts)?;
+
+    // Check that the staging buffer is already initialized.
+    if ctx.accounts.staging_account.owner != &crate::ID {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
+
+    assert_signer(ctx.accounts.payer)?;
+
+    // Only the authority that staged the data can discard it. The metadata account isn't read so
+    // buffers can still be discarded after the inscription is closed.
+    assert_derivation(
+        &crate::ID,
+        ctx.accounts.staging_account,
+        &[
+            PREFIX.as_bytes(),
+            STAGING.as_bytes(),
+            ctx.accounts.inscription_metadata_account.key.as_ref(),
+            authority.key.as_ref(),
+        ],
+        MplInscriptionError::DerivedKeyInvalid,
+    )?;
+
+    close_account_raw(ctx.accounts.payer, ctx.accounts.staging_account)?;
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::{assert_derivation, assert_signer, resize_or_reallocate_account_raw};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, hash::hash, program_memory::sol_memcpy,
+    system_program,
+};
+
+use crate::{
+    error::MplInscriptionError,
+    instruction::accounts::CommitHashAccounts,
+    state::{AuthorityRole, InscriptionMetadata, PREFIX},
+};
+
+pub(crate) fn process_commit_hash<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = &CommitHashAccounts::context(accounts)?;
+
+    // Check that the inscription account is already initialized.
+    if ctx.accounts.inscription_account.owner != &crate::ID {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+
+    // Check that the metadata account is already initialized.
+    if (ctx.accounts.inscription_metadata_account.owner != &crate::ID)
+        || ctx.accounts.inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+    let mut inscription_metadata = InscriptionMetadata::try_from_slice(
+        &ctx.accounts.inscription_metadata_account.data.borrow(),
+    )?;
+
+    // Verify that the derived address is correct for the metadata account.
+    let bump = assert_derivation(
+        &crate::ID,
+        ctx.accounts.inscription_metadata_account,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            ctx.accounts.inscription_account.key.as_ref(),
+        ],
+        MplInscriptionError::DerivedKeyInvalid,
+    )?;
+    if bump != inscription_metadata.bump {
+        return Err(MplInscriptionError::DerivedKeyInvalid.into());
+    }
+
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
+
+    assert_signer(ctx.accounts.payer)?;
+
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Writer) {
+        return Err(MplInscriptionError::InvalidAuthority.into());
+    }
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplInscriptionError::InvalidSystemProgram.into());
+    }
+
+    // Hash the data currently stored in the inscription account.
+    let inscription_data = ctx.accounts.inscription_account.data.borrow();
+    inscription_metadata.content_hash = Some(hash(&inscription_data).to_bytes());
+    inscription_metadata.content_length = inscription_data.len() as u64;
+
+    write_inscription_metadata(
+        ctx.accounts.inscription_metadata_account,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        &inscription_metadata,
+    )
+}
+
+/// Resize the metadata account to fit the inscription metadata and write it back.
+pub(crate) fn write_inscription_metadata<'a>(
+    inscription_metadata_account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    inscription_metadata: &InscriptionMetadata,
+) -> ProgramResult {
+    let serialized_metadata = &inscription_metadata.try_to_vec()?;
+
+    resize_or_reallocate_account_raw(
+        inscription_metadata_account,
+        payer,
+        system_program,
+        serialized_metadata.len(),
+    )?;
+
+    // Write the inscription metadata to the metadata account.
+    sol_memcpy(
+        &mut inscription_metadata_account.try_borrow_mut_data()?,
+        serialized_metadata,
+        serialized_metadata.len(),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use borsh::BorshDeserialize;
+use mpl_utils::{assert_derivation, assert_signer, resize_or_reallocate_account_raw};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
+    program_memory::sol_memcpy,
+    system_program,
+    sysvar::Sysvar,
+};
+
+use super::commit_hash::write_inscription_metadata;
+use crate::{
+    error::MplInscriptionError,
+    instruction::{accounts::CopyDataAccounts, CopyDataArgs},
+    state::{AuthorityRole, InscriptionMetadata, ASSOCIATION, PREFIX},
+};
+
+pub(crate) fn process_copy_data<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: CopyDataArgs,
+) -> ProgramResult {
+    let ctx = &mut CopyDataAccounts::context(accounts)?;
+
+    // Check that the source account is an inscription account.
+    if ctx.accounts.source_account.owner != &crate::ID {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+
+    // Check that the inscription account is already initialized.
+    if ctx.accounts.inscription_account.owner != &crate::ID {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+
+    // Check that the metadata account is already initialized.
+    if (ctx.accounts.inscription_metadata_account.owner != &crate::ID)
+        || ctx.accounts.inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+    let mut inscription_metadata = InscriptionMetadata::try_from_slice(
+        &ctx.accounts.inscription_metadata_account.data.borrow(),
+    )?;
+
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
+    // Verify that the derived address is correct for the metadata account.
+    match args.associated_tag {
+        Some(tag) => {
+            // We don't allow empty tags.
+            if tag.is_empty() {
+                return Err(MplInscriptionError::AssociationTagCannotBeBlank.into());
+            }
+
+            // A tag can't be greater than the seed size.
+            if tag.len() > 32 {
+                return Err(MplInscriptionError::AssociationTagTooLong.into());
+            }
+
+            let bump = assert_derivation(
+                &crate::ID,
+                ctx.accounts.inscription_account,
+                &[
+                    PREFIX.as_bytes(),
+                    ASSOCIATION.as_bytes(),
+                    tag.as_bytes(),
+                    ctx.accounts.inscription_metadata_account.key.as_ref(),
+                ],
+                MplInscriptionError::DerivedKeyInvalid,
+            )?;
+
+            // Find the tag in the associated inscriptions and check the bump.
+            if !inscription_metadata
+                .associated_inscriptions
+                .iter()
+                .any(|associated_inscription| {
+                    associated_inscription.tag == tag && associated_inscription.bump == bump
+                })
+            {
+                return Err(MplInscriptionError::DerivedKeyInvalid.into());
+            }
+        }
+        None => {
+            let bump = assert_derivation(
+                &crate::ID,
+                ctx.accounts.inscription_metadata_account,
+                &[
+                    PREFIX.as_bytes(),
+                    crate::ID.as_ref(),
+                    ctx.accounts.inscription_account.key.as_ref(),
+                ],
+                MplInscriptionError::DerivedKeyInvalid,
+            )?;
+            if bump != inscription_metadata.bump {
+                return Err(MplInscriptionError::DerivedKeyInvalid.into());
+            }
+        }
+    }
+
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
+
+    assert_signer(ctx.accounts.payer)?;
+
+    // Write delegates can write until they expire.
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Writer)
+        && !inscription_metadata.is_write_delegate(authority.key, Clock::get()?.unix_timestamp)
+    {
+        return Err(MplInscriptionError::InvalidAuthority.into());
+    }
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplInscriptionError::InvalidSystemProgram.into());
+    }
+
+    // The copy is capped at the maximum realloc size for a single instruction.
+    if args.length > MAX_PERMITTED_DATA_INCREASE {
+        return Err(MplInscriptionError::CopyTooLarge.into());
+    }
+
+    let source_end = args
+        .source_offset
+        .checked_add(args.length)
+        .ok_or(MplInscriptionError::NumericalOverflow)?;
+
+    // Read the source range first, in case the source is also the destination.
+    let value = ctx
+        .accounts
+        .source_account
+        .data
+        .borrow()
+        .get(args.source_offset..source_end)
+        .ok_or(MplInscriptionError::InvalidCopyRange)?
+        .to_vec();
+
+    let old_size = ctx.accounts.inscription_account.data_len();
+    let write_end = args
+        .offset
+        .checked_add(value.len())
+        .ok_or(MplInscriptionError::NumericalOverflow)?;
+
+    // Resize the account to fit the new data if necessary.
+    if write_end > old_size {
+        resize_or_reallocate_account_raw(
+            ctx.accounts.inscription_account,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            write_end,
+        )?;
+    }
+
+    // Write the copied data to the inscription account.
+    sol_memcpy(
+        &mut ctx.accounts.inscription_account.try_borrow_mut_data()?[args.offset..],
+        &value,
+        value.len(),
+    );
+
+    // Any change to the inscription's data invalidates its content hash commitment.
+    if ctx.accounts.inscription_account.key == &inscription_metadata.inscription_account
+        && inscription_metadata.invalidate_content_hash()
+    {
+        write_inscription_metadata(
+            ctx.accounts.inscription_metadata_account,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            &inscription_metadata,
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,43 +1,42 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::{assert_derivation, assert_signer, create_or_allocate_account_raw};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke,
-    program_memory::sol_memcpy, rent::Rent, system_instruction, system_program, sysvar::Sysvar,
+    account_info::AccountInfo,
+    entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
+    program::invoke,
+    program_memory::sol_memcpy,
+    rent::Rent,
+    system_instruction, system_program,
+    sysvar::Sysvar,
 };
 
+use super::record_inscription_rank::create_inscription_rank;
 use crate::{
     error::MplInscriptionError,
-    instruction::accounts::InitializeAccounts,
-    state::{InscriptionMetadata, InscriptionShard, Key, PREFIX, SHARD_COUNT, SHARD_PREFIX},
+    instruction::{accounts::InitializeAccounts, InitializeArgs},
+    state::{
+        AuthorityRole, InscriptionMetadata, InscriptionShard, Key, PREFIX, SHARD_COUNT,
+        SHARD_PREFIX,
+    },
 };
 
-pub(crate) fn process_initialize<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+pub(crate) fn process_initialize<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: InitializeArgs,
+) -> ProgramResult {
     let ctx = &InitializeAccounts::context(accounts)?;
 
-    // Check that the account isn't already initialized.
-    if (ctx.accounts.inscription_account.owner != &system_program::ID)
-        || !ctx.accounts.inscription_account.data_is_empty()
-    {
-        return Err(MplInscriptionError::AlreadyInitialized.into());
-    }
+    // Large inscriptions can be created beforehand with a top-level create_account, which
+    // isn't limited to 10 KiB, as long as the program is set as the owner.
+    let pre_created = ctx.accounts.inscription_account.owner == &crate::ID;
 
     // Check that the account isn't already initialized.
-    if (ctx.accounts.inscription_metadata_account.owner != &system_program::ID)
-        || !ctx.accounts.inscription_metadata_account.data_is_empty()
+    if !pre_created
+        && ((ctx.accounts.inscription_account.owner != &system_program::ID)
+            || !ctx.accounts.inscription_account.data_is_empty())
     {
         return Err(MplInscriptionError::AlreadyInitialized.into());
     }
-    // Verify that the derived address is correct for the metadata account.
-    let bump = assert_derivation(
-        &crate::ID,
-        ctx.accounts.inscription_metadata_account,
-        &[
-            PREFIX.as_bytes(),
-            crate::ID.as_ref(),
-            ctx.accounts.inscription_account.key.as_ref(),
-        ],
-        MplInscriptionError::DerivedKeyInvalid,
-    )?;
 
     // The payer must sign as well as the authority, if present.
     let authority = match ctx.accounts.authority {
@@ -53,41 +52,102 @@ pub(crate) fn process_initialize<'a>(accounts: &'a [AccountInfo<'a>]) -> Program
         return Err(MplInscriptionError::InvalidSystemProgram.into());
     }
 
-    // Initialize the inscription account.
-    let rent = Rent::get()?;
-    let rent_amount = rent.minimum_balance(0);
-    invoke(
-        &system_instruction::create_account(
-            ctx.accounts.payer.key,
-            ctx.accounts.inscription_account.key,
-            rent_amount,
-            0,
-            &crate::ID,
-        ),
+    if pre_created {
+        // Only the holder of the keypair can hand a pre-created account over, and its
+        // size is kept as is, so the initial size is ignored.
+        assert_signer(ctx.accounts.inscription_account)?;
+    } else {
+        // Accounts created through a CPI can't start with more than 10 KiB of data.
+        let initial_size = args.initial_size.unwrap_or(0);
+        if initial_size > MAX_PERMITTED_DATA_INCREASE {
+            return Err(MplInscriptionError::InitialSizeTooLarge.into());
+        }
+
+        // Initialize the inscription account.
+        let rent = Rent::get()?;
+        let rent_amount = rent.minimum_balance(initial_size);
+        invoke(
+            &system_instruction::create_account(
+                ctx.accounts.payer.key,
+                ctx.accounts.inscription_account.key,
+                rent_amount,
+                initial_size as u64,
+                &crate::ID,
+            ),
+            &[
+                ctx.accounts.payer.clone(),
+                ctx.accounts.inscription_account.clone(),
+                ctx.accounts.system_program.clone(),
+            ],
+        )?;
+    }
+
+    // Initialize the inscription metadata.
+    create_inscription_metadata(
+        ctx.accounts.inscription_metadata_account,
+        ctx.accounts.inscription_shard_account,
+        ctx.accounts.inscription_rank_account,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        InscriptionMetadata {
+            inscription_account: *ctx.accounts.inscription_account.key,
+            update_authorities: vec![*authority.key],
+            authority_roles: vec![AuthorityRole::Admin],
+            rent_payer: Some(*ctx.accounts.payer.key),
+            ..InscriptionMetadata::default()
+        },
+    )
+}
+
+/// Create the metadata account of a new inscription, taking the next rank from the shard.
+pub(crate) fn create_inscription_metadata<'a>(
+    inscription_metadata_account: &AccountInfo<'a>,
+    inscription_shard_account: &AccountInfo<'a>,
+    inscription_rank_account: Option<&AccountInfo<'a>>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    mut inscription_metadata: InscriptionMetadata,
+) -> ProgramResult {
+    // Closed inscriptions leave a tombstone that permanently reserves the address.
+    if inscription_metadata_account.owner == &crate::ID
+        && inscription_metadata_account.data.borrow().first()
+            == Some(&(Key::InscriptionTombstoneAccount as u8))
+    {
+        return Err(MplInscriptionError::InscriptionTombstoned.into());
+    }
+
+    // Check that the account isn't already initialized.
+    if (inscription_metadata_account.owner != &system_program::ID)
+        || !inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::AlreadyInitialized.into());
+    }
+
+    // Verify that the derived address is correct for the metadata account.
+    inscription_metadata.bump = assert_derivation(
+        &crate::ID,
+        inscription_metadata_account,
         &[
-            ctx.accounts.payer.clone(),
-            ctx.accounts.inscription_account.clone(),
-            ctx.accounts.system_program.clone(),
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            inscription_metadata.inscription_account.as_ref(),
         ],
+        MplInscriptionError::DerivedKeyInvalid,
     )?;
 
-    // Initialize the inscription metadata.
-    let mut inscription_metadata = InscriptionMetadata {
-        inscription_account: *ctx.accounts.inscription_account.key,
-        bump,
-        update_authorities: vec![*authority.key],
-        ..InscriptionMetadata::default()
-    };
+    if (inscription_shard_account.owner != &crate::ID) || inscription_shard_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::InvalidShardAccount.into());
+    }
 
-    let mut shard =
-        InscriptionShard::try_from_slice(&ctx.accounts.inscription_shard_account.data.borrow())?;
+    let mut shard = InscriptionShard::try_from_slice(&inscription_shard_account.data.borrow())?;
     if shard.key != Key::InscriptionShardAccount {
         return Err(MplInscriptionError::InvalidShardAccount.into());
     }
 
     let shard_bump = assert_derivation(
         &crate::ID,
-        ctx.accounts.inscription_shard_account,
+        inscription_shard_account,
         &[
             PREFIX.as_bytes(),
             SHARD_PREFIX.as_bytes(),
@@ -119,24 +179,21 @@ pub(crate) fn process_initialize<'a>(accounts: &'a [AccountInfo<'a>]) -> Program
     // Initialize the inscription metadata account.
     create_or_allocate_account_raw(
         crate::ID,
-        ctx.accounts.inscription_metadata_account,
-        ctx.accounts.system_program,
-        ctx.accounts.payer,
+        inscription_metadata_account,
+        system_program,
+        payer,
         serialized_metadata.len(),
         &[
             PREFIX.as_bytes(),
             crate::ID.as_ref(),
-            ctx.accounts.inscription_account.key.as_ref(),
-            &[bump],
+            inscription_metadata.inscription_account.as_ref(),
+            &[inscription_metadata.bump],
         ],
     )?;
 
     // Write the inscription metadata to the metadata account.
     sol_memcpy(
-        &mut ctx
-            .accounts
-            .inscription_metadata_account
-            .try_borrow_mut_data()?,
+        &mut inscription_metadata_account.try_borrow_mut_data()?,
         serialized_metadata,
         serialized_metadata.len(),
     );
@@ -145,13 +202,21 @@ pub(crate) fn process_initialize<'a>(accounts: &'a [AccountInfo<'a>]) -> Program
 
     // Write the shard data back to the shard account.
     sol_memcpy(
-        &mut ctx
-            .accounts
-            .inscription_shard_account
-            .try_borrow_mut_data()?,
+        &mut inscription_shard_account.try_borrow_mut_data()?,
         serialized_shard,
         serialized_shard.len(),
     );
 
+    // Record the rank so the inscription can be looked up by it.
+    if let Some(inscription_rank_account) = inscription_rank_account {
+        create_inscription_rank(
+            inscription_rank_account,
+            inscription_metadata_account,
+            inscription_metadata.inscription_rank,
+            payer,
+            system_program,
+        )?;
+    }
+
     Ok(())
 }
@@ -4,7 +4,9 @@ use mpl_utils::{
     resize_or_reallocate_account_raw,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+    account_info::AccountInfo,
+    entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
+    program_memory::sol_memcpy,
     system_program,
 };
 
@@ -13,7 +15,10 @@ use crate::{
     instruction::{
         accounts::InitializeAssociatedInscriptionAccounts, AssociateInscriptionAccountArgs,
     },
-    state::{AssociatedInscription, DataType, InscriptionMetadata, ASSOCIATION, PREFIX},
+    state::{
+        AssociatedInscription, AuthorityRole, DataType, InscriptionMetadata, ASSOCIATION,
+        MAX_CONTENT_TYPE_LENGTH, PREFIX,
+    },
 };
 
 pub(crate) fn process_initialize_associated_inscription<'a>(
@@ -46,6 +51,11 @@ pub(crate) fn process_initialize_associated_inscription<'a>(
         &ctx.accounts.inscription_metadata_account.data.borrow(),
     )?;
 
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
     let _metadata_bump = assert_derivation(
         &crate::ID,
         ctx.accounts.inscription_metadata_account,
@@ -67,6 +77,18 @@ pub(crate) fn process_initialize_associated_inscription<'a>(
         return Err(MplInscriptionError::AssociationTagTooLong.into());
     }
 
+    // Associated inscriptions start out empty, JSON is labeled with SetDataType once written.
+    if args.data_type == DataType::Json {
+        return Err(MplInscriptionError::InvalidJson.into());
+    }
+
+    // Content types are short MIME types like image/webp.
+    if let Some(content_type) = &args.content_type {
+        if content_type.len() > MAX_CONTENT_TYPE_LENGTH {
+            return Err(MplInscriptionError::ContentTypeTooLong.into());
+        }
+    }
+
     // Verify that the derived address is correct for the metadata account.
     let inscription_bump = assert_derivation(
         &crate::ID,
@@ -90,10 +112,7 @@ pub(crate) fn process_initialize_associated_inscription<'a>(
     };
     assert_signer(ctx.accounts.payer)?;
 
-    if !inscription_metadata
-        .update_authorities
-        .contains(authority.key)
-    {
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Tagger) {
         return Err(MplInscriptionError::InvalidAuthority.into());
     }
 
@@ -101,13 +120,19 @@ pub(crate) fn process_initialize_associated_inscription<'a>(
         return Err(MplInscriptionError::InvalidSystemProgram.into());
     }
 
+    // PDAs can't be created with more than 10 KiB of data.
+    let initial_size = args.initial_size.unwrap_or(0);
+    if initial_size > MAX_PERMITTED_DATA_INCREASE {
+        return Err(MplInscriptionError::InitialSizeTooLarge.into());
+    }
+
     // Initialize the associated inscription account.
     create_or_allocate_account_raw(
         crate::ID,
         ctx.accounts.associated_inscription_account,
         ctx.accounts.system_program,
         ctx.accounts.payer,
-        0,
+        initial_size,
         &[
             PREFIX.as_bytes(),
             ASSOCIATION.as_bytes(),
@@ -118,12 +143,13 @@ pub(crate) fn process_initialize_associated_inscription<'a>(
     )?;
 
     // Update the metadata to include the new associated inscription.
+    inscription_metadata.set_content_type(&args.association_tag, args.content_type);
     inscription_metadata
         .associated_inscriptions
         .push(AssociatedInscription {
             tag: args.association_tag,
             bump: inscription_bump,
-            data_type: DataType::Uninitialized,
+            data_type: args.data_type,
         });
 
     let serialized_metadata = &inscription_metadata.try_to_vec()?;
@@ -0,0 +1,251 @@
+use borsh::BorshDeserialize;
+use mpl_token_metadata::accounts::Metadata;
+use mpl_utils::{
+    assert_derivation, assert_owned_by, assert_signer, create_or_allocate_account_raw,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    keccak,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use super::initialize::create_inscription_metadata;
+use crate::{
+    error::MplInscriptionError,
+    instruction::{accounts::InitializeFromAssetAccounts, InitializeFromAssetArgs},
+    state::{AuthorityRole, InscriptionMetadata, Key, PREFIX},
+};
+
+const BUBBLEGUM_PROGRAM_ID: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+const SPL_ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey =
+    pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+const ASSET_PREFIX: &str = "asset";
+const LEAF_SCHEMA_V1: u8 = 1;
+const VERIFY_LEAF_DISCRIMINATOR: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+const TREE_CONFIG_IS_PUBLIC_OFFSET: usize = 88;
+
+pub(crate) fn process_initialize_from_asset<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: InitializeFromAssetArgs,
+) -> ProgramResult {
+    let ctx = &InitializeFromAssetAccounts::context(accounts)?;
+
+    // Check that the account isn't already initialized.
+    if (ctx.accounts.asset_inscription_account.owner != &system_program::ID)
+        || !ctx.accounts.asset_inscription_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::AlreadyInitialized.into());
+    }
+
+    if ctx.accounts.compression_program.key != &SPL_ACCOUNT_COMPRESSION_PROGRAM_ID {
+        return Err(MplInscriptionError::InvalidCompressionProgram.into());
+    }
+
+    // The asset id is derived from the tree and the leaf nonce.
+    assert_derivation(
+        &BUBBLEGUM_PROGRAM_ID,
+        ctx.accounts.asset_id,
+        &[
+            ASSET_PREFIX.as_bytes(),
+            ctx.accounts.merkle_tree.key.as_ref(),
+            args.nonce.to_le_bytes().as_ref(),
+        ],
+        MplInscriptionError::DerivedKeyInvalid,
+    )?;
+
+    // Do the standard Bubblegum tree config checks.
+    assert_owned_by(
+        ctx.accounts.tree_config,
+        &BUBBLEGUM_PROGRAM_ID,
+        MplInscriptionError::IncorrectOwner,
+    )?;
+
+    assert_derivation(
+        &BUBBLEGUM_PROGRAM_ID,
+        ctx.accounts.tree_config,
+        &[ctx.accounts.merkle_tree.key.as_ref()],
+        MplInscriptionError::DerivedKeyInvalid,
+    )?;
+
+    // The tree creator, delegate and public flag follow the 8 byte account discriminator.
+    let (tree_creator, tree_delegate, tree_is_public) = {
+        let tree_config_data = ctx.accounts.tree_config.try_borrow_data()?;
+        if tree_config_data.len() <= TREE_CONFIG_IS_PUBLIC_OFFSET {
+            return Err(MplInscriptionError::IncorrectOwner.into());
+        }
+        (
+            Pubkey::try_from(&tree_config_data[8..40])
+                .map_err(|_| MplInscriptionError::IncorrectOwner)?,
+            Pubkey::try_from(&tree_config_data[40..72])
+                .map_err(|_| MplInscriptionError::IncorrectOwner)?,
+            tree_config_data[TREE_CONFIG_IS_PUBLIC_OFFSET] != 0,
+        )
+    };
+
+    // Verify that the derived address is correct for the metadata account.
+    let inscription_bump = assert_derivation(
+        &crate::ID,
+        ctx.accounts.asset_inscription_account,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            ctx.accounts.asset_id.key.as_ref(),
+        ],
+        MplInscriptionError::DerivedKeyInvalid,
+    )?;
+
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
+    assert_signer(ctx.accounts.payer)?;
+
+    // Only the update authority can inscribe the asset, since the owner can't be followed
+    // after a transfer, and it can only be found in the metadata of the asset.
+    let (seller_fee_basis_points, collection) = read_asset_metadata(&args.metadata)?;
+
+    // The metadata must be the one the leaf was hashed with.
+    let data_hash = keccak::hashv(&[
+        keccak::hash(&args.metadata).as_ref(),
+        &seller_fee_basis_points.to_le_bytes(),
+    ]);
+    if data_hash.to_bytes() != args.data_hash {
+        return Err(MplInscriptionError::InvalidAssetMetadata.into());
+    }
+
+    match collection {
+        // Assets in a verified collection are updated by the collection's update authority.
+        Some((true, collection)) => {
+            let collection_metadata = ctx
+                .accounts
+                .collection_metadata
+                .ok_or(MplInscriptionError::InvalidAuthority)?;
+
+            assert_owned_by(
+                collection_metadata,
+                &mpl_token_metadata::ID,
+                MplInscriptionError::IncorrectOwner,
+            )?;
+
+            let collection_metadata_data = collection_metadata.try_borrow_data()?;
+            let collection_metadata: Metadata =
+                Metadata::safe_deserialize(&collection_metadata_data)?;
+
+            if collection_metadata.mint != collection {
+                return Err(MplInscriptionError::MintMismatch.into());
+            }
+
+            if collection_metadata.update_authority != *authority.key {
+                return Err(MplInscriptionError::InvalidAuthority.into());
+            }
+        }
+        // Other assets are updated by the tree authority, unless anyone can mint into the tree.
+        _ => {
+            if tree_is_public || (*authority.key != tree_creator && *authority.key != tree_delegate)
+            {
+                return Err(MplInscriptionError::InvalidAuthority.into());
+            }
+        }
+    }
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplInscriptionError::InvalidSystemProgram.into());
+    }
+
+    // Rebuild the leaf and verify it against the tree with the proof in the remaining accounts.
+    let leaf = keccak::hashv(&[
+        &[LEAF_SCHEMA_V1],
+        ctx.accounts.asset_id.key.as_ref(),
+        ctx.accounts.leaf_owner.key.as_ref(),
+        ctx.accounts.leaf_delegate.key.as_ref(),
+        args.nonce.to_le_bytes().as_ref(),
+        args.data_hash.as_ref(),
+        args.creator_hash.as_ref(),
+    ]);
+
+    let mut data = VERIFY_LEAF_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&args.root);
+    data.extend_from_slice(&leaf.to_bytes());
+    data.extend_from_slice(&args.index.to_le_bytes());
+
+    let mut account_metas = vec![AccountMeta::new_readonly(
+        *ctx.accounts.merkle_tree.key,
+        false,
+    )];
+    let mut account_infos = vec![ctx.accounts.merkle_tree.clone()];
+    for proof_account in ctx.remaining_accounts {
+        account_metas.push(AccountMeta::new_readonly(*proof_account.key, false));
+        account_infos.push(proof_account.clone());
+    }
+
+    invoke(
+        &Instruction {
+            program_id: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+            accounts: account_metas,
+            data,
+        },
+        &account_infos,
+    )?;
+
+    // Initialize the inscription account.
+    create_or_allocate_account_raw(
+        crate::ID,
+        ctx.accounts.asset_inscription_account,
+        ctx.accounts.system_program,
+        ctx.accounts.payer,
+        0,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            ctx.accounts.asset_id.key.as_ref(),
+            &[inscription_bump],
+        ],
+    )?;
+
+    // Initialize the inscription metadata.
+    create_inscription_metadata(
+        ctx.accounts.inscription_metadata_account,
+        ctx.accounts.inscription_shard_account,
+        ctx.accounts.inscription_rank_account,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        InscriptionMetadata {
+            key: Key::MintInscriptionMetadataAccount,
+            inscription_account: *ctx.accounts.asset_inscription_account.key,
+            inscription_bump: Some(inscription_bump),
+            update_authorities: vec![*authority.key],
+            authority_roles: vec![AuthorityRole::Admin],
+            rent_payer: Some(*ctx.accounts.payer.key),
+            mint: Some(*ctx.accounts.asset_id.key),
+            ..InscriptionMetadata::default()
+        },
+    )
+}
+
+/// Read the seller fee and the collection from the Bubblegum metadata of the asset.
+fn read_asset_metadata(metadata: &[u8]) -> Result<(u16, Option<(bool, Pubkey)>), ProgramError> {
+    let mut buf = metadata;
+
+    // The name, symbol and uri come first.
+    for _ in 0..3 {
+        String::deserialize(&mut buf)?;
+    }
+    let seller_fee_basis_points = u16::deserialize(&mut buf)?;
+    // Then primary_sale_happened, is_mutable, edition_nonce and token_standard.
+    <[bool; 2]>::deserialize(&mut buf)?;
+    <[Option<u8>; 2]>::deserialize(&mut buf)?;
+    let collection = Option::<(bool, Pubkey)>::deserialize(&mut buf)?;
+
+    Ok((seller_fee_basis_points, collection))
+}
@@ -0,0 +1,155 @@
+use borsh::BorshDeserialize;
+use mpl_utils::{
+    assert_derivation, assert_owned_by, assert_signer, create_or_allocate_account_raw,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, pubkey, pubkey::Pubkey, system_program,
+};
+
+use super::initialize::create_inscription_metadata;
+use crate::{
+    error::MplInscriptionError,
+    instruction::accounts::InitializeFromCoreAssetAccounts,
+    state::{AuthorityRole, InscriptionMetadata, Key, PREFIX},
+};
+
+const MPL_CORE_PROGRAM_ID: Pubkey = pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+
+// The account keys used by mpl-core.
+const CORE_ASSET_V1_KEY: u8 = 1;
+const CORE_COLLECTION_V1_KEY: u8 = 5;
+
+// The update authority of an mpl-core asset, stored after the key and owner.
+#[derive(BorshDeserialize)]
+enum CoreUpdateAuthority {
+    None,
+    Address(Pubkey),
+    Collection(Pubkey),
+}
+
+pub(crate) fn process_initialize_from_core_asset<'a>(
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let ctx = &InitializeFromCoreAssetAccounts::context(accounts)?;
+
+    // Check that the account isn't already initialized.
+    if (ctx.accounts.mint_inscription_account.owner != &system_program::ID)
+        || !ctx.accounts.mint_inscription_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::AlreadyInitialized.into());
+    }
+
+    // Do the standard mpl-core checks.
+    assert_owned_by(
+        ctx.accounts.asset,
+        &MPL_CORE_PROGRAM_ID,
+        MplInscriptionError::IncorrectOwner,
+    )?;
+
+    let update_authority = {
+        let asset_data = ctx.accounts.asset.try_borrow_data()?;
+        if asset_data.len() < 33 || asset_data[0] != CORE_ASSET_V1_KEY {
+            return Err(MplInscriptionError::InvalidCoreAsset.into());
+        }
+
+        let mut update_authority_data = &asset_data[33..];
+        CoreUpdateAuthority::deserialize(&mut update_authority_data)
+            .map_err(|_| MplInscriptionError::InvalidCoreAsset)?
+    };
+
+    // Resolve the update authority, which might be the collection's.
+    let asset_update_authority = match update_authority {
+        CoreUpdateAuthority::None => None,
+        CoreUpdateAuthority::Address(address) => Some(address),
+        CoreUpdateAuthority::Collection(collection_address) => {
+            let collection = ctx
+                .accounts
+                .collection
+                .ok_or(MplInscriptionError::InvalidCoreAsset)?;
+
+            if *collection.key != collection_address {
+                return Err(MplInscriptionError::InvalidCoreAsset.into());
+            }
+
+            assert_owned_by(
+                collection,
+                &MPL_CORE_PROGRAM_ID,
+                MplInscriptionError::IncorrectOwner,
+            )?;
+
+            let collection_data = collection.try_borrow_data()?;
+            if collection_data.len() < 33 || collection_data[0] != CORE_COLLECTION_V1_KEY {
+                return Err(MplInscriptionError::InvalidCoreAsset.into());
+            }
+
+            Some(
+                Pubkey::try_from(&collection_data[1..33])
+                    .map_err(|_| MplInscriptionError::InvalidCoreAsset)?,
+            )
+        }
+    };
+
+    // Verify that the derived address is correct for the metadata account.
+    let inscription_bump = assert_derivation(
+        &crate::ID,
+        ctx.accounts.mint_inscription_account,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            ctx.accounts.asset.key.as_ref(),
+        ],
+        MplInscriptionError::DerivedKeyInvalid,
+    )?;
+
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
+    assert_signer(ctx.accounts.payer)?;
+
+    if asset_update_authority != Some(*authority.key) {
+        return Err(MplInscriptionError::InvalidAuthority.into());
+    }
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplInscriptionError::InvalidSystemProgram.into());
+    }
+
+    // Initialize the inscription account.
+    create_or_allocate_account_raw(
+        crate::ID,
+        ctx.accounts.mint_inscription_account,
+        ctx.accounts.system_program,
+        ctx.accounts.payer,
+        0,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            ctx.accounts.asset.key.as_ref(),
+            &[inscription_bump],
+        ],
+    )?;
+
+    // Initialize the inscription metadata.
+    create_inscription_metadata(
+        ctx.accounts.inscription_metadata_account,
+        ctx.accounts.inscription_shard_account,
+        ctx.accounts.inscription_rank_account,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        InscriptionMetadata {
+            key: Key::MintInscriptionMetadataAccount,
+            inscription_account: *ctx.accounts.mint_inscription_account.key,
+            inscription_bump: Some(inscription_bump),
+            update_authorities: vec![*authority.key],
+            authority_roles: vec![AuthorityRole::Admin],
+            rent_payer: Some(*ctx.accounts.payer.key),
+            mint: Some(*ctx.accounts.asset.key),
+            ..InscriptionMetadata::default()
+        },
+    )
+}
@@ -1,18 +1,22 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use mpl_token_metadata::accounts::Metadata;
+use mpl_token_metadata::{accounts::Metadata, types::TokenStandard};
 use mpl_utils::{
     assert_derivation, assert_owned_by, assert_owner_in, assert_signer,
     create_or_allocate_account_raw, token::SPL_TOKEN_PROGRAM_IDS,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
-    system_program,
+    account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey, system_program,
 };
+use spl_token_2022::{
+    extension::{metadata_pointer::MetadataPointer, BaseStateWithExtensions, StateWithExtensions},
+    state::{Account, Mint},
+};
+use spl_token_metadata_interface::state::TokenMetadata;
 
+use super::initialize::create_inscription_metadata;
 use crate::{
     error::MplInscriptionError,
     instruction::accounts::InitializeFromMintAccounts,
-    state::{InscriptionMetadata, InscriptionShard, Key, PREFIX, SHARD_COUNT, SHARD_PREFIX},
+    state::{AuthorityRole, InscriptionMetadata, Key, PREFIX},
 };
 
 pub(crate) fn process_initialize_from_mint<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
@@ -25,31 +29,70 @@ pub(crate) fn process_initialize_from_mint<'a>(accounts: &'a [AccountInfo<'a>])
         return Err(MplInscriptionError::AlreadyInitialized.into());
     }
 
-    // Check that the account isn't already initialized.
-    if (ctx.accounts.inscription_metadata_account.owner != &system_program::ID)
-        || !ctx.accounts.inscription_metadata_account.data_is_empty()
-    {
-        return Err(MplInscriptionError::AlreadyInitialized.into());
-    }
-
-    // Do the standard Token Metadata checks.
-    assert_owned_by(
-        ctx.accounts.token_metadata_account,
-        &mpl_token_metadata::ID,
-        MplInscriptionError::IncorrectOwner,
-    )?;
-
     assert_owner_in(
         ctx.accounts.mint_account,
         &SPL_TOKEN_PROGRAM_IDS,
         MplInscriptionError::IncorrectOwner,
     )?;
 
-    let token_metadata_data = ctx.accounts.token_metadata_account.try_borrow_data()?;
-    let token_metadata: Metadata = Metadata::safe_deserialize(&token_metadata_data)?;
-
-    if token_metadata.mint != *ctx.accounts.mint_account.key {
-        return Err(MplInscriptionError::MintMismatch.into());
+    let mint_data = ctx.accounts.mint_account.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+
+    // Token-2022 mints can embed their own metadata, in which case the mint is
+    // passed as the metadata account.
+    let metadata_update_authority =
+        if ctx.accounts.token_metadata_account.key == ctx.accounts.mint_account.key {
+            if ctx.accounts.mint_account.owner != &spl_token_2022::ID {
+                return Err(MplInscriptionError::IncorrectOwner.into());
+            }
+
+            // The metadata pointer must point back to the mint itself.
+            let metadata_pointer = mint.get_extension::<MetadataPointer>()?;
+            if Option::<Pubkey>::from(metadata_pointer.metadata_address)
+                != Some(*ctx.accounts.mint_account.key)
+            {
+                return Err(MplInscriptionError::MintMismatch.into());
+            }
+
+            let token_metadata = mint.get_variable_len_extension::<TokenMetadata>()?;
+            if token_metadata.mint != *ctx.accounts.mint_account.key {
+                return Err(MplInscriptionError::MintMismatch.into());
+            }
+
+            Option::<Pubkey>::from(token_metadata.update_authority)
+        } else {
+            // Do the standard Token Metadata checks.
+            assert_owned_by(
+                ctx.accounts.token_metadata_account,
+                &mpl_token_metadata::ID,
+                MplInscriptionError::IncorrectOwner,
+            )?;
+
+            let token_metadata_data = ctx.accounts.token_metadata_account.try_borrow_data()?;
+            let token_metadata: Metadata = Metadata::safe_deserialize(&token_metadata_data)?;
+
+            if token_metadata.mint != *ctx.accounts.mint_account.key {
+                return Err(MplInscriptionError::MintMismatch.into());
+            }
+
+            // Only non-fungible tokens (including programmables and editions) can be inscribed.
+            match token_metadata.token_standard {
+                Some(TokenStandard::NonFungible)
+                | Some(TokenStandard::NonFungibleEdition)
+                | Some(TokenStandard::ProgrammableNonFungible)
+                | Some(TokenStandard::ProgrammableNonFungibleEdition)
+                | None => {}
+                Some(TokenStandard::Fungible) | Some(TokenStandard::FungibleAsset) => {
+                    return Err(MplInscriptionError::InvalidTokenStandard.into());
+                }
+            }
+
+            Some(token_metadata.update_authority)
+        };
+
+    // The mint itself must also look like a non-fungible token.
+    if mint.base.decimals != 0 || mint.base.supply > 1 {
+        return Err(MplInscriptionError::InvalidTokenStandard.into());
     }
 
     // Verify that the derived address is correct for the metadata account.
@@ -64,18 +107,6 @@ pub(crate) fn process_initialize_from_mint<'a>(accounts: &'a [AccountInfo<'a>])
         MplInscriptionError::DerivedKeyInvalid,
     )?;
 
-    // Verify that the derived address is correct for the metadata account.
-    let bump = assert_derivation(
-        &crate::ID,
-        ctx.accounts.inscription_metadata_account,
-        &[
-            PREFIX.as_bytes(),
-            crate::ID.as_ref(),
-            ctx.accounts.mint_inscription_account.key.as_ref(),
-        ],
-        MplInscriptionError::DerivedKeyInvalid,
-    )?;
-
     // The payer must sign as well as the authority, if present.
     let authority = match ctx.accounts.authority {
         Some(authority) => {
@@ -86,9 +117,40 @@ pub(crate) fn process_initialize_from_mint<'a>(accounts: &'a [AccountInfo<'a>])
     };
     assert_signer(ctx.accounts.payer)?;
 
-    if token_metadata.update_authority != *authority.key {
-        return Err(MplInscriptionError::InvalidAuthority.into());
-    }
+    // The authority is either the holder of the NFT or its update authority.
+    let mint_holder = match ctx.accounts.token_account {
+        Some(token_account) => {
+            assert_owner_in(
+                token_account,
+                &SPL_TOKEN_PROGRAM_IDS,
+                MplInscriptionError::IncorrectOwner,
+            )?;
+
+            let token_account_data = token_account.try_borrow_data()?;
+            let token = StateWithExtensions::<Account>::unpack(&token_account_data)?;
+
+            if token.base.mint != *ctx.accounts.mint_account.key {
+                return Err(MplInscriptionError::MintMismatch.into());
+            }
+
+            if token.base.owner != *authority.key {
+                return Err(MplInscriptionError::InvalidAuthority.into());
+            }
+
+            if token.base.amount < 1 {
+                return Err(MplInscriptionError::NotEnoughTokens.into());
+            }
+
+            Some(*authority.key)
+        }
+        None => {
+            if metadata_update_authority != Some(*authority.key) {
+                return Err(MplInscriptionError::InvalidAuthority.into());
+            }
+
+            None
+        }
+    };
 
     if ctx.accounts.system_program.key != &system_program::ID {
         return Err(MplInscriptionError::InvalidSystemProgram.into());
@@ -110,94 +172,28 @@ pub(crate) fn process_initialize_from_mint<'a>(accounts: &'a [AccountInfo<'a>])
     )?;
 
     // Initialize the inscription metadata.
-    let mut inscription_metadata = InscriptionMetadata {
-        key: Key::MintInscriptionMetadataAccount,
-        inscription_account: *ctx.accounts.mint_inscription_account.key,
-        bump,
-        inscription_bump: Some(inscription_bump),
-        update_authorities: vec![token_metadata.update_authority],
-        mint: Some(*ctx.accounts.mint_account.key),
-        ..InscriptionMetadata::default()
-    };
-
-    if (ctx.accounts.inscription_shard_account.owner != &crate::ID)
-        || ctx.accounts.inscription_shard_account.data_is_empty()
-    {
-        return Err(MplInscriptionError::InvalidShardAccount.into());
-    }
-
-    let mut shard =
-        InscriptionShard::try_from_slice(&ctx.accounts.inscription_shard_account.data.borrow())?;
-    if shard.key != Key::InscriptionShardAccount {
-        return Err(MplInscriptionError::InvalidShardAccount.into());
-    }
-
-    let shard_bump = assert_derivation(
-        &crate::ID,
-        ctx.accounts.inscription_shard_account,
-        &[
-            PREFIX.as_bytes(),
-            SHARD_PREFIX.as_bytes(),
-            crate::ID.as_ref(),
-            shard.shard_number.to_le_bytes().as_ref(),
-        ],
-        MplInscriptionError::DerivedKeyInvalid,
-    )?;
-
-    if shard_bump != shard.bump {
-        return Err(MplInscriptionError::DerivedKeyInvalid.into());
-    }
-
-    inscription_metadata.inscription_rank = shard
-        .count
-        .checked_mul(SHARD_COUNT as u64)
-        .ok_or(MplInscriptionError::NumericalOverflow)?
-        .checked_add(shard.shard_number as u64)
-        .ok_or(MplInscriptionError::NumericalOverflow)?;
-
-    shard.count = shard
-        .count
-        .checked_add(1)
-        .ok_or(MplInscriptionError::NumericalOverflow)?;
-
-    let serialized_metadata = &inscription_metadata.try_to_vec()?;
-
-    // Initialize the inscription metadata account.
-    create_or_allocate_account_raw(
-        crate::ID,
+    create_inscription_metadata(
         ctx.accounts.inscription_metadata_account,
-        ctx.accounts.system_program,
+        ctx.accounts.inscription_shard_account,
+        ctx.accounts.inscription_rank_account,
         ctx.accounts.payer,
-        serialized_metadata.len(),
-        &[
-            PREFIX.as_bytes(),
-            crate::ID.as_ref(),
-            ctx.accounts.mint_inscription_account.key.as_ref(),
-            &[bump],
-        ],
-    )?;
-
-    // Write the inscription metadata to the metadata account.
-    sol_memcpy(
-        &mut ctx
-            .accounts
-            .inscription_metadata_account
-            .try_borrow_mut_data()?,
-        serialized_metadata,
-        serialized_metadata.len(),
-    );
-
-    let serialized_shard = &shard.try_to_vec()?;
-
-    // Write the shard data back to the shard account.
-    sol_memcpy(
-        &mut ctx
-            .accounts
-            .inscription_shard_account
-            .try_borrow_mut_data()?,
-        serialized_shard,
-        serialized_shard.len(),
-    );
-
-    Ok(())
+        ctx.accounts.system_program,
+        InscriptionMetadata {
+            key: Key::MintInscriptionMetadataAccount,
+            inscription_account: *ctx.accounts.mint_inscription_account.key,
+            inscription_bump: Some(inscription_bump),
+            // The update authority of the NFT stays in charge even when the holder inscribes it,
+            // and the holder stays a Writer until SyncAuthority hands that over to the next one.
+            update_authorities: metadata_update_authority.into_iter().collect(),
+            authority_roles: metadata_update_authority
+                .map(|_| AuthorityRole::Admin)
+                .into_iter()
+                .collect(),
+            rent_payer: Some(*ctx.accounts.payer.key),
+            mint: Some(*ctx.accounts.mint_account.key),
+            mint_update_authority: metadata_update_authority,
+            mint_holder,
+            ..InscriptionMetadata::default()
+        },
+    )
 }
@@ -3,28 +3,64 @@ use borsh::BorshDeserialize;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
 
 mod add_authority;
+mod add_write_delegate;
 mod allocate;
 mod clear_data;
 mod close;
+mod close_all;
+mod close_staging_buffer;
+mod commit_hash;
+mod copy_data;
 mod create_shard;
+mod freeze;
 mod initialize;
 mod initialize_associated_inscription;
+mod initialize_from_asset;
+mod initialize_from_core_asset;
 mod initialize_from_mint;
+mod prune_write_delegates;
+mod reclaim_rent;
+mod record_inscription_rank;
 mod remove_authority;
+mod replace_authority;
+mod set_authority_threshold;
+mod set_content_type;
+mod set_data_type;
 mod set_mint;
+mod sync_authority;
 mod write_data;
+mod write_segments;
+mod write_staging_buffer;
 
 use add_authority::*;
+use add_write_delegate::*;
 use allocate::*;
 use clear_data::*;
 use close::*;
+use close_all::*;
+use close_staging_buffer::*;
+use commit_hash::*;
+use copy_data::*;
 use create_shard::*;
+use freeze::*;
 use initialize::*;
 use initialize_associated_inscription::*;
+use initialize_from_asset::*;
+use initialize_from_core_asset::*;
 use initialize_from_mint::*;
+use prune_write_delegates::*;
+use reclaim_rent::*;
+use record_inscription_rank::*;
 use remove_authority::*;
+use replace_authority::*;
+use set_authority_threshold::*;
+use set_content_type::*;
+use set_data_type::*;
 use set_mint::*;
+use sync_authority::*;
 use write_data::*;
+use write_segments::*;
+use write_staging_buffer::*;
 
 pub struct Processor;
 impl Processor {
@@ -36,9 +72,9 @@ impl Processor {
         let instruction: MplInscriptionInstruction =
             MplInscriptionInstruction::try_from_slice(instruction_data)?;
         match instruction {
-            MplInscriptionInstruction::Initialize => {
+            MplInscriptionInstruction::Initialize(args) => {
                 msg!("Instruction: Initialize");
-                process_initialize(accounts)
+                process_initialize(accounts, args)
             }
             MplInscriptionInstruction::InitializeFromMint => {
                 msg!("Instruction: InitializeFromMint");
@@ -60,9 +96,9 @@ impl Processor {
                 msg!("Instruction: AddAuthority");
                 process_add_authority(accounts, args)
             }
-            MplInscriptionInstruction::RemoveAuthority => {
+            MplInscriptionInstruction::RemoveAuthority(args) => {
                 msg!("Instruction: RemoveAuthority");
-                process_remove_authority(accounts)
+                process_remove_authority(accounts, args)
             }
             MplInscriptionInstruction::CreateShard(args) => {
                 msg!("Instruction: CreateShard");
@@ -76,9 +112,81 @@ impl Processor {
                 msg!("Instruction: Allocate");
                 process_allocate(accounts, args)
             }
-            MplInscriptionInstruction::SetMint => {
+            MplInscriptionInstruction::SetMint(args) => {
                 msg!("Instruction: SetMint");
-                process_set_mint(accounts)
+                process_set_mint(accounts, args)
+            }
+            MplInscriptionInstruction::SetDataType(args) => {
+                msg!("Instruction: SetDataType");
+                process_set_data_type(accounts, args)
+            }
+            MplInscriptionInstruction::Freeze => {
+                msg!("Instruction: Freeze");
+                process_freeze(accounts)
+            }
+            MplInscriptionInstruction::InitializeFromAsset(args) => {
+                msg!("Instruction: InitializeFromAsset");
+                process_initialize_from_asset(accounts, args)
+            }
+            MplInscriptionInstruction::InitializeFromCoreAsset => {
+                msg!("Instruction: InitializeFromCoreAsset");
+                process_initialize_from_core_asset(accounts)
+            }
+            MplInscriptionInstruction::CommitHash => {
+                msg!("Instruction: CommitHash");
+                process_commit_hash(accounts)
+            }
+            MplInscriptionInstruction::WriteSegments(args) => {
+                msg!("Instruction: WriteSegments");
+                process_write_segments(accounts, args)
+            }
+            MplInscriptionInstruction::CopyData(args) => {
+                msg!("Instruction: CopyData");
+                process_copy_data(accounts, args)
+            }
+            MplInscriptionInstruction::WriteStagingBuffer(args) => {
+                msg!("Instruction: WriteStagingBuffer");
+                process_write_staging_buffer(accounts, args)
+            }
+            MplInscriptionInstruction::ReplaceAuthority(args) => {
+                msg!("Instruction: ReplaceAuthority");
+                process_replace_authority(accounts, args)
+            }
+            MplInscriptionInstruction::AddWriteDelegate(args) => {
+                msg!("Instruction: AddWriteDelegate");
+                process_add_write_delegate(accounts, args)
+            }
+            MplInscriptionInstruction::PruneWriteDelegates => {
+                msg!("Instruction: PruneWriteDelegates");
+                process_prune_write_delegates(accounts)
+            }
+            MplInscriptionInstruction::SetAuthorityThreshold(args) => {
+                msg!("Instruction: SetAuthorityThreshold");
+                process_set_authority_threshold(accounts, args)
+            }
+            MplInscriptionInstruction::SyncAuthority => {
+                msg!("Instruction: SyncAuthority");
+                process_sync_authority(accounts)
+            }
+            MplInscriptionInstruction::CloseAll(args) => {
+                msg!("Instruction: CloseAll");
+                process_close_all(accounts, args)
+            }
+            MplInscriptionInstruction::ReclaimRent(args) => {
+                msg!("Instruction: ReclaimRent");
+                process_reclaim_rent(accounts, args)
+            }
+            MplInscriptionInstruction::SetContentType(args) => {
+                msg!("Instruction: SetContentType");
+                process_set_content_type(accounts, args)
+            }
+            MplInscriptionInstruction::CloseStagingBuffer => {
+                msg!("Instruction: CloseStagingBuffer");
+                process_close_staging_buffer(accounts)
+            }
+            MplInscriptionInstruction::RecordInscriptionRank => {
+                msg!("Instruction: RecordInscriptionRank");
+                process_record_inscription_rank(accounts)
             }
         }
     }
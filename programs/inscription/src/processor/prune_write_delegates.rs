@@ -0,0 +1,103 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::{assert_derivation, assert_signer};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, program_memory::sol_memcpy,
+    rent::Rent, sysvar::Sysvar,
+};
+
+use crate::{
+    error::MplInscriptionError,
+    instruction::accounts::PruneWriteDelegatesAccounts,
+    state::{InscriptionMetadata, PREFIX},
+};
+
+pub(crate) fn process_prune_write_delegates<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = &PruneWriteDelegatesAccounts::context(accounts)?;
+
+    // Check that the account is already initialized.
+    if (ctx.accounts.inscription_metadata_account.owner != &crate::ID)
+        || ctx.accounts.inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+    let mut inscription_metadata = InscriptionMetadata::try_from_slice(
+        &ctx.accounts.inscription_metadata_account.data.borrow(),
+    )?;
+
+    // Verify that the derived address is correct for the metadata account.
+    let bump = assert_derivation(
+        &crate::ID,
+        ctx.accounts.inscription_metadata_account,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            inscription_metadata.inscription_account.as_ref(),
+        ],
+        MplInscriptionError::DerivedKeyInvalid,
+    )?;
+    if bump != inscription_metadata.bump {
+        return Err(MplInscriptionError::DerivedKeyInvalid.into());
+    }
+
+    // Anyone can prune, so only the payer has to sign.
+    assert_signer(ctx.accounts.payer)?;
+
+    // Drop every delegate that has expired.
+    let now = Clock::get()?.unix_timestamp;
+    let delegate_count = inscription_metadata.write_delegates.len();
+    inscription_metadata
+        .write_delegates
+        .retain(|delegate| delegate.expires_at > now);
+
+    // Nothing to do if no delegate has expired.
+    if inscription_metadata.write_delegates.len() == delegate_count {
+        return Ok(());
+    }
+
+    // Write the updated inscription metadata account back to the account.
+    let old_len = ctx.accounts.inscription_metadata_account.data_len();
+    let serialized_data = inscription_metadata.try_to_vec()?;
+
+    // Shrink the account now that the expired delegates are gone.
+    ctx.accounts
+        .inscription_metadata_account
+        .realloc(serialized_data.len(), false)?;
+
+    // Write the inscription metadata to the metadata account.
+    sol_memcpy(
+        &mut ctx
+            .accounts
+            .inscription_metadata_account
+            .try_borrow_mut_data()?,
+        &serialized_data,
+        serialized_data.len(),
+    );
+
+    // Refund only the rent freed by the pruned delegates to the payer.
+    let rent = Rent::get()?;
+    let rent_amount = rent.minimum_balance(serialized_data.len());
+    let freed_lamports = rent
+        .minimum_balance(old_len)
+        .saturating_sub(rent_amount)
+        .min(
+            ctx.accounts
+                .inscription_metadata_account
+                .lamports()
+                .saturating_sub(rent_amount),
+        );
+
+    let payer_lamports = ctx
+        .accounts
+        .payer
+        .lamports()
+        .checked_add(freed_lamports)
+        .ok_or(MplInscriptionError::NumericalOverflow)?;
+
+    **ctx
+        .accounts
+        .inscription_metadata_account
+        .try_borrow_mut_lamports()? -= freed_lamports;
+    **ctx.accounts.payer.try_borrow_mut_lamports()? = payer_lamports;
+
+    Ok(())
+}
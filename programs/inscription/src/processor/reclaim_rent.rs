@@ -0,0 +1,141 @@
+use borsh::BorshDeserialize;
+use mpl_utils::{assert_derivation, assert_signer};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, rent::Rent, sysvar::Sysvar,
+};
+
+use crate::{
+    error::MplInscriptionError,
+    instruction::{accounts::ReclaimRentAccounts, ReclaimRentArgs},
+    state::{AuthorityRole, InscriptionMetadata, Key, ASSOCIATION, PREFIX},
+};
+
+pub(crate) fn process_reclaim_rent<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: ReclaimRentArgs,
+) -> ProgramResult {
+    let ctx = &ReclaimRentAccounts::context(accounts)?;
+
+    // Check that the metadata account is already initialized.
+    if (ctx.accounts.inscription_metadata_account.owner != &crate::ID)
+        || ctx.accounts.inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+    let inscription_metadata = InscriptionMetadata::try_from_slice(
+        &ctx.accounts.inscription_metadata_account.data.borrow(),
+    )?;
+
+    // Check that the account is a valid inscription metadata account.
+    if inscription_metadata.key != Key::InscriptionMetadataAccount
+        && inscription_metadata.key != Key::MintInscriptionMetadataAccount
+    {
+        return Err(MplInscriptionError::InvalidInscriptionMetadataAccount.into());
+    }
+
+    // Verify that the derived address is correct for the metadata account.
+    let bump = assert_derivation(
+        &crate::ID,
+        ctx.accounts.inscription_metadata_account,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            inscription_metadata.inscription_account.as_ref(),
+        ],
+        MplInscriptionError::DerivedKeyInvalid,
+    )?;
+    if bump != inscription_metadata.bump {
+        return Err(MplInscriptionError::DerivedKeyInvalid.into());
+    }
+
+    // Check that the target account is already initialized.
+    if ctx.accounts.target_account.owner != &crate::ID {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
+
+    assert_signer(ctx.accounts.payer)?;
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Admin) {
+        return Err(MplInscriptionError::InvalidAuthority.into());
+    }
+
+    // The target must belong to this inscription.
+    match args.associated_tag {
+        Some(tag) => {
+            // We don't allow empty tags.
+            if tag.is_empty() {
+                return Err(MplInscriptionError::AssociationTagCannotBeBlank.into());
+            }
+
+            // A tag can't be greater than the seed size.
+            if tag.len() > 32 {
+                return Err(MplInscriptionError::AssociationTagTooLong.into());
+            }
+
+            let bump = assert_derivation(
+                &crate::ID,
+                ctx.accounts.target_account,
+                &[
+                    PREFIX.as_bytes(),
+                    ASSOCIATION.as_bytes(),
+                    tag.as_bytes(),
+                    ctx.accounts.inscription_metadata_account.key.as_ref(),
+                ],
+                MplInscriptionError::DerivedKeyInvalid,
+            )?;
+
+            // Find the tag in the associated inscriptions and check the bump.
+            if !inscription_metadata
+                .associated_inscriptions
+                .iter()
+                .any(|associated_inscription| {
+                    associated_inscription.tag == tag && associated_inscription.bump == bump
+                })
+            {
+                return Err(MplInscriptionError::DerivedKeyInvalid.into());
+            }
+        }
+        None => {
+            if ctx.accounts.target_account.key != ctx.accounts.inscription_metadata_account.key
+                && ctx.accounts.target_account.key != &inscription_metadata.inscription_account
+            {
+                return Err(MplInscriptionError::DerivedKeyInvalid.into());
+            }
+        }
+    }
+
+    // Rent goes back to whoever paid for it, or the destination if they are reclaiming.
+    let rent_destination = inscription_metadata
+        .rent_destination(
+            ctx.accounts.payer,
+            ctx.accounts.rent_payer,
+            ctx.accounts.destination,
+        )
+        .ok_or(MplInscriptionError::InvalidRentPayer)?;
+
+    // Refund everything above the rent-exempt minimum.
+    let rent_amount = Rent::get()?.minimum_balance(ctx.accounts.target_account.data_len());
+    let excess_lamports = ctx
+        .accounts
+        .target_account
+        .lamports()
+        .saturating_sub(rent_amount);
+
+    let destination_lamports = rent_destination
+        .lamports()
+        .checked_add(excess_lamports)
+        .ok_or(MplInscriptionError::NumericalOverflow)?;
+
+    **ctx.accounts.target_account.try_borrow_mut_lamports()? -= excess_lamports;
+    **rent_destination.try_borrow_mut_lamports()? = destination_lamports;
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::{assert_derivation, assert_signer, create_or_allocate_account_raw};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+    system_program,
+};
+
+use crate::{
+    error::MplInscriptionError,
+    instruction::accounts::RecordInscriptionRankAccounts,
+    state::{InscriptionMetadata, InscriptionRank, PREFIX, RANK_PREFIX},
+};
+
+pub(crate) fn process_record_inscription_rank<'a>(
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let ctx = &RecordInscriptionRankAccounts::context(accounts)?;
+
+    // Check that the account is already initialized.
+    if (ctx.accounts.inscription_metadata_account.owner != &crate::ID)
+        || ctx.accounts.inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+    let inscription_metadata = InscriptionMetadata::try_from_slice(
+        &ctx.accounts.inscription_metadata_account.data.borrow(),
+    )?;
+
+    // Verify that the derived address is correct for the metadata account.
+    let bump = assert_derivation(
+        &crate::ID,
+        ctx.accounts.inscription_metadata_account,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            inscription_metadata.inscription_account.as_ref(),
+        ],
+        MplInscriptionError::DerivedKeyInvalid,
+    )?;
+    if bump != inscription_metadata.bump {
+        return Err(MplInscriptionError::DerivedKeyInvalid.into());
+    }
+
+    // Anyone can record the rank since it only restates the metadata.
+    assert_signer(ctx.accounts.payer)?;
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplInscriptionError::InvalidSystemProgram.into());
+    }
+
+    create_inscription_rank(
+        ctx.accounts.inscription_rank_account,
+        ctx.accounts.inscription_metadata_account,
+        inscription_metadata.inscription_rank,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )
+}
+
+/// Create the account pointing from a rank back to the inscription metadata account.
+pub(crate) fn create_inscription_rank<'a>(
+    inscription_rank_account: &AccountInfo<'a>,
+    inscription_metadata_account: &AccountInfo<'a>,
+    inscription_rank: u64,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    // Check that the account isn't already initialized.
+    if (inscription_rank_account.owner != &system_program::ID)
+        || !inscription_rank_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::AlreadyInitialized.into());
+    }
+
+    let rank_bump = assert_derivation(
+        &crate::ID,
+        inscription_rank_account,
+        &[
+            PREFIX.as_bytes(),
+            RANK_PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            inscription_rank.to_le_bytes().as_ref(),
+        ],
+        MplInscriptionError::DerivedKeyInvalid,
+    )?;
+
+    let serialized_rank = &InscriptionRank {
+        bump: rank_bump,
+        inscription_metadata_account: *inscription_metadata_account.key,
+        ..InscriptionRank::default()
+    }
+    .try_to_vec()?;
+
+    // Initialize the inscription rank account.
+    create_or_allocate_account_raw(
+        crate::ID,
+        inscription_rank_account,
+        system_program,
+        payer,
+        serialized_rank.len(),
+        &[
+            PREFIX.as_bytes(),
+            RANK_PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            inscription_rank.to_le_bytes().as_ref(),
+            &[rank_bump],
+        ],
+    )?;
+
+    // Write the rank data to the inscription rank account.
+    sol_memcpy(
+        &mut inscription_rank_account.try_borrow_mut_data()?,
+        serialized_rank,
+        serialized_rank.len(),
+    );
+
+    Ok(())
+}
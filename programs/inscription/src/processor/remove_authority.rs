@@ -6,11 +6,15 @@ use solana_program::{
 };
 
 use crate::{
-    error::MplInscriptionError, instruction::accounts::RemoveAuthorityAccounts,
-    state::InscriptionMetadata,
+    error::MplInscriptionError,
+    instruction::{accounts::RemoveAuthorityAccounts, RemoveAuthorityArgs},
+    state::{AuthorityRole, InscriptionMetadata},
 };
 
-pub(crate) fn process_remove_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+pub(crate) fn process_remove_authority<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: RemoveAuthorityArgs,
+) -> ProgramResult {
     let ctx = &RemoveAuthorityAccounts::context(accounts)?;
 
     // Check that the account isn't already initialized.
@@ -44,13 +48,42 @@ pub(crate) fn process_remove_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> P
         return Err(MplInscriptionError::InvalidSystemProgram.into());
     }
 
-    // Remove the authority if they're the signer.
+    // Remove the target authority, or the signer if no target is given.
+    let target = args.target.unwrap_or(*authority.key);
+
+    // Only Admins can remove an authority other than themselves.
+    if target != *authority.key
+        && !inscription_metadata.has_role(authority.key, AuthorityRole::Admin)
+    {
+        return Err(MplInscriptionError::InvalidAuthority.into());
+    }
+
+    // Managing other authorities needs enough authorities to sign when a threshold is set.
+    if target != *authority.key
+        && !inscription_metadata.meets_threshold(authority, ctx.remaining_accounts)
+    {
+        return Err(MplInscriptionError::AuthorityThresholdNotMet.into());
+    }
+
     let index = inscription_metadata
         .update_authorities
         .iter()
-        .position(|x| x == authority.key)
+        .position(|x| x == &target)
         .ok_or(MplInscriptionError::InvalidAuthority)?;
     inscription_metadata.update_authorities.swap_remove(index);
+    inscription_metadata.authority_roles.swap_remove(index);
+
+    // Removing the last authority makes the inscription immutable, so it has to be explicit.
+    if inscription_metadata.update_authorities.is_empty() && !args.allow_last_authority {
+        return Err(MplInscriptionError::CannotRemoveLastAuthority.into());
+    }
+
+    // The threshold must remain reachable with the authorities left.
+    if inscription_metadata.authority_threshold as usize
+        > inscription_metadata.update_authorities.len()
+    {
+        return Err(MplInscriptionError::InvalidAuthorityThreshold.into());
+    }
 
     // Write the updated inscription metadata account back to the account.
     let serialized_data = inscription_metadata.try_to_vec()?;
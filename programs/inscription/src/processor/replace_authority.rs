@@ -0,0 +1,94 @@
+use borsh::BorshDeserialize;
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, system_program};
+
+use super::commit_hash::write_inscription_metadata;
+use crate::{
+    error::MplInscriptionError,
+    instruction::{accounts::ReplaceAuthorityAccounts, ReplaceAuthorityArgs},
+    state::{AuthorityRole, InscriptionMetadata},
+};
+
+pub(crate) fn process_replace_authority<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: ReplaceAuthorityArgs,
+) -> ProgramResult {
+    let ctx = &ReplaceAuthorityAccounts::context(accounts)?;
+
+    // Check that the account is already initialized.
+    if (ctx.accounts.inscription_metadata_account.owner != &crate::ID)
+        || ctx.accounts.inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+    let mut inscription_metadata = InscriptionMetadata::try_from_slice(
+        &ctx.accounts.inscription_metadata_account.data.borrow(),
+    )?;
+
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
+
+    assert_signer(ctx.accounts.payer)?;
+
+    if !inscription_metadata
+        .update_authorities
+        .contains(authority.key)
+    {
+        return Err(MplInscriptionError::InvalidAuthority.into());
+    }
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplInscriptionError::InvalidSystemProgram.into());
+    }
+
+    if inscription_metadata
+        .update_authorities
+        .contains(&args.new_authority)
+    {
+        return Err(MplInscriptionError::AuthorityAlreadyExists.into());
+    }
+
+    // Replace the target authority, or the signer if no target is given.
+    let target = args.target.unwrap_or(*authority.key);
+
+    // Only Admins can replace an authority other than themselves.
+    if target != *authority.key
+        && !inscription_metadata.has_role(authority.key, AuthorityRole::Admin)
+    {
+        return Err(MplInscriptionError::InvalidAuthority.into());
+    }
+
+    // Managing other authorities needs enough authorities to sign when a threshold is set.
+    if target != *authority.key
+        && !inscription_metadata.meets_threshold(authority, ctx.remaining_accounts)
+    {
+        return Err(MplInscriptionError::AuthorityThresholdNotMet.into());
+    }
+
+    let index = inscription_metadata
+        .update_authorities
+        .iter()
+        .position(|x| x == &target)
+        .ok_or(MplInscriptionError::InvalidAuthority)?;
+
+    // The new authority takes over the role of the one it replaces.
+    inscription_metadata.update_authorities[index] = args.new_authority;
+
+    write_inscription_metadata(
+        ctx.accounts.inscription_metadata_account,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        &inscription_metadata,
+    )
+}
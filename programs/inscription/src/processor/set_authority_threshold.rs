@@ -0,0 +1,69 @@
+use borsh::BorshDeserialize;
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, system_program};
+
+use super::commit_hash::write_inscription_metadata;
+use crate::{
+    error::MplInscriptionError,
+    instruction::{accounts::SetAuthorityThresholdAccounts, SetAuthorityThresholdArgs},
+    state::{AuthorityRole, InscriptionMetadata},
+};
+
+pub(crate) fn process_set_authority_threshold<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: SetAuthorityThresholdArgs,
+) -> ProgramResult {
+    let ctx = &SetAuthorityThresholdAccounts::context(accounts)?;
+
+    // Check that the account is already initialized.
+    if (ctx.accounts.inscription_metadata_account.owner != &crate::ID)
+        || ctx.accounts.inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+    let mut inscription_metadata = InscriptionMetadata::try_from_slice(
+        &ctx.accounts.inscription_metadata_account.data.borrow(),
+    )?;
+
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
+
+    assert_signer(ctx.accounts.payer)?;
+
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Admin) {
+        return Err(MplInscriptionError::InvalidAuthority.into());
+    }
+
+    // Changing an existing threshold needs it to be met first.
+    if !inscription_metadata.meets_threshold(authority, ctx.remaining_accounts) {
+        return Err(MplInscriptionError::AuthorityThresholdNotMet.into());
+    }
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplInscriptionError::InvalidSystemProgram.into());
+    }
+
+    if args.threshold as usize > inscription_metadata.update_authorities.len() {
+        return Err(MplInscriptionError::InvalidAuthorityThreshold.into());
+    }
+
+    inscription_metadata.authority_threshold = args.threshold;
+
+    write_inscription_metadata(
+        ctx.accounts.inscription_metadata_account,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        &inscription_metadata,
+    )
+}
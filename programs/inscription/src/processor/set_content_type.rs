@@ -0,0 +1,124 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::{assert_derivation, assert_signer, resize_or_reallocate_account_raw};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+    system_program,
+};
+
+use crate::{
+    error::MplInscriptionError,
+    instruction::{accounts::SetContentTypeAccounts, SetContentTypeArgs},
+    state::{AuthorityRole, InscriptionMetadata, ASSOCIATION, MAX_CONTENT_TYPE_LENGTH, PREFIX},
+};
+
+pub(crate) fn process_set_content_type<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: SetContentTypeArgs,
+) -> ProgramResult {
+    let ctx = &SetContentTypeAccounts::context(accounts)?;
+
+    // Check that the associated inscription account is already initialized.
+    if ctx.accounts.associated_inscription_account.owner != &crate::ID {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+
+    // Check that the metadata account is already initialized.
+    if (ctx.accounts.inscription_metadata_account.owner != &crate::ID)
+        || ctx.accounts.inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+    let mut inscription_metadata = InscriptionMetadata::try_from_slice(
+        &ctx.accounts.inscription_metadata_account.data.borrow(),
+    )?;
+
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
+
+    assert_signer(ctx.accounts.payer)?;
+
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Tagger) {
+        return Err(MplInscriptionError::InvalidAuthority.into());
+    }
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplInscriptionError::InvalidSystemProgram.into());
+    }
+
+    // Content types are short MIME types like image/webp.
+    if let Some(content_type) = &args.content_type {
+        if content_type.len() > MAX_CONTENT_TYPE_LENGTH {
+            return Err(MplInscriptionError::ContentTypeTooLong.into());
+        }
+    }
+
+    // We don't allow empty tags.
+    if args.association_tag.is_empty() {
+        return Err(MplInscriptionError::AssociationTagCannotBeBlank.into());
+    }
+
+    // A tag can't be greater than the seed size.
+    if args.association_tag.len() > 32 {
+        return Err(MplInscriptionError::AssociationTagTooLong.into());
+    }
+
+    let bump = assert_derivation(
+        &crate::ID,
+        ctx.accounts.associated_inscription_account,
+        &[
+            PREFIX.as_bytes(),
+            ASSOCIATION.as_bytes(),
+            args.association_tag.as_bytes(),
+            ctx.accounts.inscription_metadata_account.key.as_ref(),
+        ],
+        MplInscriptionError::DerivedKeyInvalid,
+    )?;
+
+    // Find the tag in the associated inscriptions and check the bump.
+    if !inscription_metadata
+        .associated_inscriptions
+        .iter()
+        .any(|associated_inscription| {
+            associated_inscription.tag == args.association_tag
+                && associated_inscription.bump == bump
+        })
+    {
+        return Err(MplInscriptionError::DerivedKeyInvalid.into());
+    }
+
+    inscription_metadata.set_content_type(&args.association_tag, args.content_type);
+
+    // Write the updated inscription metadata account back to the account.
+    let serialized_metadata = &inscription_metadata.try_to_vec()?;
+
+    // Resize the account to fit the new content type.
+    resize_or_reallocate_account_raw(
+        ctx.accounts.inscription_metadata_account,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        serialized_metadata.len(),
+    )?;
+
+    // Write the inscription metadata to the metadata account.
+    sol_memcpy(
+        &mut ctx
+            .accounts
+            .inscription_metadata_account
+            .try_borrow_mut_data()?,
+        serialized_metadata,
+        serialized_metadata.len(),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use borsh::BorshDeserialize;
+use mpl_utils::{assert_derivation, assert_signer};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, system_program};
+
+use super::commit_hash::write_inscription_metadata;
+use crate::{
+    error::MplInscriptionError,
+    instruction::{accounts::SetDataTypeAccounts, SetDataTypeArgs},
+    state::{AuthorityRole, DataType, InscriptionMetadata, ASSOCIATION, PREFIX},
+};
+
+pub(crate) fn process_set_data_type<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: SetDataTypeArgs,
+) -> ProgramResult {
+    let ctx = &SetDataTypeAccounts::context(accounts)?;
+
+    // Check that the inscription account is already initialized.
+    if ctx.accounts.inscription_account.owner != &crate::ID {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+
+    // Check that the metadata account is already initialized.
+    if (ctx.accounts.inscription_metadata_account.owner != &crate::ID)
+        || ctx.accounts.inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+    let mut inscription_metadata = InscriptionMetadata::try_from_slice(
+        &ctx.accounts.inscription_metadata_account.data.borrow(),
+    )?;
+
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
+
+    assert_signer(ctx.accounts.payer)?;
+
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Writer) {
+        return Err(MplInscriptionError::InvalidAuthority.into());
+    }
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplInscriptionError::InvalidSystemProgram.into());
+    }
+
+    // JSON data must parse before it can be labeled as such.
+    if args.data_type == DataType::Json
+        && serde_json::from_slice::<serde_json::Value>(
+            &ctx.accounts.inscription_account.data.borrow(),
+        )
+        .is_err()
+    {
+        return Err(MplInscriptionError::InvalidJson.into());
+    }
+
+    // Verify that the derived address is correct and update the matching data type.
+    match args.associated_tag {
+        Some(tag) => {
+            // We don't allow empty tags.
+            if tag.is_empty() {
+                return Err(MplInscriptionError::AssociationTagCannotBeBlank.into());
+            }
+
+            // A tag can't be greater than the seed size.
+            if tag.len() > 32 {
+                return Err(MplInscriptionError::AssociationTagTooLong.into());
+            }
+
+            let bump = assert_derivation(
+                &crate::ID,
+                ctx.accounts.inscription_account,
+                &[
+                    PREFIX.as_bytes(),
+                    ASSOCIATION.as_bytes(),
+                    tag.as_bytes(),
+                    ctx.accounts.inscription_metadata_account.key.as_ref(),
+                ],
+                MplInscriptionError::DerivedKeyInvalid,
+            )?;
+
+            // Find the tag in the associated inscriptions and check the bump.
+            let associated_inscription = inscription_metadata
+                .associated_inscriptions
+                .iter_mut()
+                .find(|associated_inscription| {
+                    associated_inscription.tag == tag && associated_inscription.bump == bump
+                })
+                .ok_or(MplInscriptionError::DerivedKeyInvalid)?;
+
+            associated_inscription.data_type = args.data_type;
+        }
+        None => {
+            let bump = assert_derivation(
+                &crate::ID,
+                ctx.accounts.inscription_metadata_account,
+                &[
+                    PREFIX.as_bytes(),
+                    crate::ID.as_ref(),
+                    ctx.accounts.inscription_account.key.as_ref(),
+                ],
+                MplInscriptionError::DerivedKeyInvalid,
+            )?;
+            if bump != inscription_metadata.bump {
+                return Err(MplInscriptionError::DerivedKeyInvalid.into());
+            }
+
+            inscription_metadata.data_type = args.data_type;
+        }
+    }
+
+    write_inscription_metadata(
+        ctx.accounts.inscription_metadata_account,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        &inscription_metadata,
+    )
+}
@@ -8,13 +8,17 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
 };
 
+use super::sync_authority::read_mint_update_authority;
 use crate::{
     error::MplInscriptionError,
-    instruction::accounts::SetMintAccounts,
-    state::{InscriptionMetadata, Key, PREFIX},
+    instruction::{accounts::SetMintAccounts, SetMintArgs},
+    state::{AuthorityRole, InscriptionMetadata, Key, PREFIX},
 };
 
-pub(crate) fn process_set_mint<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+pub(crate) fn process_set_mint<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: SetMintArgs,
+) -> ProgramResult {
     let ctx = &SetMintAccounts::context(accounts)?;
 
     // Check that the system program is correct.
@@ -41,7 +45,7 @@ pub(crate) fn process_set_mint<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramRe
     )?;
 
     // Verify that the derived address is correct for the mint inscription account.
-    let _inscription_bump = assert_derivation(
+    let inscription_bump = assert_derivation(
         &crate::ID,
         ctx.accounts.mint_inscription_account,
         &[
@@ -64,6 +68,14 @@ pub(crate) fn process_set_mint<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramRe
         MplInscriptionError::DerivedKeyInvalid,
     )?;
 
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
     assert_signer(ctx.accounts.payer)?;
 
     // Deserialize the inscription metadata.
@@ -71,13 +83,38 @@ pub(crate) fn process_set_mint<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramRe
         &ctx.accounts.inscription_metadata_account.data.borrow(),
     )?;
 
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
     // Check that the account is a valid inscription metadata account.
     if inscription_metadata.key != Key::MintInscriptionMetadataAccount {
         return Err(MplInscriptionError::InvalidInscriptionMetadataAccount.into());
     }
 
+    // The authority is either an admin of the inscription or the update authority of the mint.
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Admin) {
+        let token_metadata_account = ctx
+            .accounts
+            .token_metadata_account
+            .ok_or(MplInscriptionError::InvalidAuthority)?;
+
+        let mint_update_authority =
+            read_mint_update_authority(ctx.accounts.mint_account, token_metadata_account)?;
+
+        if mint_update_authority != Some(*authority.key) {
+            return Err(MplInscriptionError::InvalidAuthority.into());
+        }
+    }
+
     inscription_metadata.mint = Some(*ctx.accounts.mint_account.key);
 
+    // Legacy accounts were created before the inscription bump was stored.
+    if args.backfill_inscription_bump {
+        inscription_metadata.inscription_bump = Some(inscription_bump);
+    }
+
     let serialized_metadata = &inscription_metadata.try_to_vec()?;
 
     resize_or_reallocate_account_raw(
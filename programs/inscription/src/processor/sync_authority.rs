@@ -0,0 +1,237 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_token_metadata::accounts::Metadata;
+use mpl_utils::{
+    assert_owned_by, assert_owner_in, assert_signer, resize_or_reallocate_account_raw,
+    token::SPL_TOKEN_PROGRAM_IDS,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    program_memory::sol_memcpy, pubkey::Pubkey, system_program,
+};
+use spl_token_2022::{
+    extension::{metadata_pointer::MetadataPointer, BaseStateWithExtensions, StateWithExtensions},
+    state::{Account, Mint},
+};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+use crate::{
+    error::MplInscriptionError,
+    instruction::accounts::SyncAuthorityAccounts,
+    state::{AuthorityRole, InscriptionMetadata, Key},
+};
+
+pub(crate) fn process_sync_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = &SyncAuthorityAccounts::context(accounts)?;
+
+    // Check that the account is already initialized.
+    if (ctx.accounts.inscription_metadata_account.owner != &crate::ID)
+        || ctx.accounts.inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+    let mut inscription_metadata = InscriptionMetadata::try_from_slice(
+        &ctx.accounts.inscription_metadata_account.data.borrow(),
+    )?;
+
+    if inscription_metadata.key != Key::MintInscriptionMetadataAccount {
+        return Err(MplInscriptionError::InvalidInscriptionMetadataAccount.into());
+    }
+
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
+    if inscription_metadata.mint != Some(*ctx.accounts.mint_account.key) {
+        return Err(MplInscriptionError::MintMismatch.into());
+    }
+
+    // Inscriptions created before the update authority was recorded start following it once
+    // its current update authority is confirmed to be one of theirs.
+    if inscription_metadata.mint_update_authority.is_none() && ctx.accounts.token_account.is_none()
+    {
+        let current_authority = read_mint_update_authority(
+            ctx.accounts.mint_account,
+            ctx.accounts.token_metadata_account,
+        )?
+        .ok_or(MplInscriptionError::InvalidAuthority)?;
+
+        // Otherwise an Admin, usually the previous update authority, hands its place over to it.
+        if !inscription_metadata
+            .update_authorities
+            .contains(&current_authority)
+        {
+            let authority = ctx
+                .accounts
+                .authority
+                .ok_or(MplInscriptionError::InvalidAuthority)?;
+            assert_signer(authority)?;
+
+            if !inscription_metadata.has_role(authority.key, AuthorityRole::Admin) {
+                return Err(MplInscriptionError::InvalidAuthority.into());
+            }
+
+            let index = inscription_metadata
+                .update_authorities
+                .iter()
+                .position(|x| x == authority.key)
+                .ok_or(MplInscriptionError::InvalidAuthority)?;
+            inscription_metadata.update_authorities[index] = current_authority;
+        }
+
+        inscription_metadata.mint_update_authority = Some(current_authority);
+    } else if let Some(old_authority) = inscription_metadata.mint_update_authority {
+        let new_authority = read_mint_update_authority(
+            ctx.accounts.mint_account,
+            ctx.accounts.token_metadata_account,
+        )?
+        .ok_or(MplInscriptionError::InvalidAuthority)?;
+
+        // Replace the old authority with the new one, keeping its role.
+        if let Some(index) = inscription_metadata
+            .update_authorities
+            .iter()
+            .position(|x| x == &old_authority)
+        {
+            if inscription_metadata
+                .update_authorities
+                .contains(&new_authority)
+            {
+                // The new authority is already present, so just drop the old one.
+                inscription_metadata.update_authorities.swap_remove(index);
+                inscription_metadata.authority_roles.swap_remove(index);
+            } else {
+                inscription_metadata.update_authorities[index] = new_authority;
+            }
+        }
+
+        inscription_metadata.mint_update_authority = Some(new_authority);
+
+        // Dropping the old authority must not leave the threshold out of reach.
+        inscription_metadata.authority_threshold = std::cmp::min(
+            inscription_metadata.authority_threshold,
+            inscription_metadata.update_authorities.len() as u8,
+        );
+    }
+
+    // The holder follows whoever holds the NFT now.
+    if let Some(token_account) = ctx.accounts.token_account {
+        if inscription_metadata.mint_holder.is_none() {
+            return Err(MplInscriptionError::InvalidAuthority.into());
+        }
+
+        assert_owner_in(
+            token_account,
+            &SPL_TOKEN_PROGRAM_IDS,
+            MplInscriptionError::IncorrectOwner,
+        )?;
+
+        let token_account_data = token_account.try_borrow_data()?;
+        let token = StateWithExtensions::<Account>::unpack(&token_account_data)?;
+
+        if token.base.mint != *ctx.accounts.mint_account.key {
+            return Err(MplInscriptionError::MintMismatch.into());
+        }
+
+        if token.base.amount < 1 {
+            return Err(MplInscriptionError::NotEnoughTokens.into());
+        }
+
+        inscription_metadata.mint_holder = Some(token.base.owner);
+    }
+
+    // Write the updated inscription metadata account back to the account.
+    let serialized_data = inscription_metadata.try_to_vec()?;
+
+    // Older accounts grow to fit the new fields, otherwise shrink in case the old authority
+    // was dropped.
+    if serialized_data.len() > ctx.accounts.inscription_metadata_account.data_len() {
+        let payer = ctx
+            .accounts
+            .payer
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let system_program = ctx
+            .accounts
+            .system_program
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        assert_signer(payer)?;
+
+        if system_program.key != &system_program::ID {
+            return Err(MplInscriptionError::InvalidSystemProgram.into());
+        }
+
+        resize_or_reallocate_account_raw(
+            ctx.accounts.inscription_metadata_account,
+            payer,
+            system_program,
+            serialized_data.len(),
+        )?;
+    } else {
+        ctx.accounts
+            .inscription_metadata_account
+            .realloc(serialized_data.len(), false)?;
+    }
+
+    // Write the inscription metadata to the metadata account.
+    sol_memcpy(
+        &mut ctx
+            .accounts
+            .inscription_metadata_account
+            .try_borrow_mut_data()?,
+        &serialized_data,
+        serialized_data.len(),
+    );
+
+    Ok(())
+}
+
+/// Read the current update authority of the mint from its Token-2022 or Token Metadata metadata.
+pub(crate) fn read_mint_update_authority(
+    mint_account: &AccountInfo,
+    token_metadata_account: &AccountInfo,
+) -> Result<Option<Pubkey>, ProgramError> {
+    assert_owner_in(
+        mint_account,
+        &SPL_TOKEN_PROGRAM_IDS,
+        MplInscriptionError::IncorrectOwner,
+    )?;
+
+    if token_metadata_account.key == mint_account.key {
+        if mint_account.owner != &spl_token_2022::ID {
+            return Err(MplInscriptionError::IncorrectOwner.into());
+        }
+
+        let mint_data = mint_account.try_borrow_data()?;
+        let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+
+        // The metadata pointer must point back to the mint itself.
+        let metadata_pointer = mint.get_extension::<MetadataPointer>()?;
+        if Option::<Pubkey>::from(metadata_pointer.metadata_address) != Some(*mint_account.key) {
+            return Err(MplInscriptionError::MintMismatch.into());
+        }
+
+        let token_metadata = mint.get_variable_len_extension::<TokenMetadata>()?;
+        if token_metadata.mint != *mint_account.key {
+            return Err(MplInscriptionError::MintMismatch.into());
+        }
+
+        Ok(Option::<Pubkey>::from(token_metadata.update_authority))
+    } else {
+        // Do the standard Token Metadata checks.
+        assert_owned_by(
+            token_metadata_account,
+            &mpl_token_metadata::ID,
+            MplInscriptionError::IncorrectOwner,
+        )?;
+
+        let token_metadata_data = token_metadata_account.try_borrow_data()?;
+        let token_metadata: Metadata = Metadata::safe_deserialize(&token_metadata_data)?;
+
+        if token_metadata.mint != *mint_account.key {
+            return Err(MplInscriptionError::MintMismatch.into());
+        }
+
+        Ok(Some(token_metadata.update_authority))
+    }
+}
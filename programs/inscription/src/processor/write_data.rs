@@ -1,14 +1,19 @@
 use borsh::BorshDeserialize;
-use mpl_utils::{assert_derivation, assert_signer, resize_or_reallocate_account_raw};
+use mpl_utils::{
+    assert_derivation, assert_signer, close_account_raw, resize_or_reallocate_account_raw,
+};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
-    system_program,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, hash::hash,
+    program_error::ProgramError, program_memory::sol_memcpy, system_program, sysvar::Sysvar,
 };
 
+use super::commit_hash::write_inscription_metadata;
 use crate::{
     error::MplInscriptionError,
     instruction::{accounts::WriteDataAccounts, WriteDataArgs},
-    state::{InscriptionMetadata, ASSOCIATION, PREFIX},
+    state::{
+        AuthorityRole, InscriptionMetadata, ASSOCIATION, PREFIX, STAGING, STAGING_HEADER_SIZE,
+    },
 };
 
 pub(crate) fn process_write_data<'a>(
@@ -28,10 +33,15 @@ pub(crate) fn process_write_data<'a>(
     {
         return Err(MplInscriptionError::NotInitialized.into());
     }
-    let inscription_metadata = InscriptionMetadata::try_from_slice(
+    let mut inscription_metadata = InscriptionMetadata::try_from_slice(
         &ctx.accounts.inscription_metadata_account.data.borrow(),
     )?;
 
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
     // Verify that the derived address is correct for the metadata account.
     match args.associated_tag {
         Some(tag) => {
@@ -96,9 +106,9 @@ pub(crate) fn process_write_data<'a>(
 
     assert_signer(ctx.accounts.payer)?;
 
-    if !inscription_metadata
-        .update_authorities
-        .contains(authority.key)
+    // Write delegates can write until they expire.
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Writer)
+        && !inscription_metadata.is_write_delegate(authority.key, Clock::get()?.unix_timestamp)
     {
         return Err(MplInscriptionError::InvalidAuthority.into());
     }
@@ -107,12 +117,60 @@ pub(crate) fn process_write_data<'a>(
         return Err(MplInscriptionError::InvalidSystemProgram.into());
     }
 
+    // The data comes from the staging buffer, if present, in place of the value.
+    let value_len = match ctx.accounts.staging_account {
+        Some(staging_account) => {
+            if !args.value.is_empty() {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+
+            // Check that the staging buffer is already initialized.
+            if staging_account.owner != &crate::ID {
+                return Err(MplInscriptionError::NotInitialized.into());
+            }
+
+            assert_derivation(
+                &crate::ID,
+                staging_account,
+                &[
+                    PREFIX.as_bytes(),
+                    STAGING.as_bytes(),
+                    ctx.accounts.inscription_metadata_account.key.as_ref(),
+                    authority.key.as_ref(),
+                ],
+                MplInscriptionError::DerivedKeyInvalid,
+            )?;
+
+            staging_account
+                .data_len()
+                .saturating_sub(STAGING_HEADER_SIZE)
+        }
+        None => args.value.len(),
+    };
+
     let old_size = ctx.accounts.inscription_account.data_len();
     let write_end = args
         .offset
-        .checked_add(args.value.len())
+        .checked_add(value_len)
         .ok_or(MplInscriptionError::NumericalOverflow)?;
 
+    // Check that the data is still in the state the writer expects.
+    if let Some(precondition) = &args.precondition {
+        if let Some(expected_length) = precondition.expected_length {
+            if old_size as u64 != expected_length {
+                return Err(MplInscriptionError::WritePreconditionFailed.into());
+            }
+        }
+
+        if let Some(expected_hash) = precondition.expected_hash {
+            let data = ctx.accounts.inscription_account.data.borrow();
+            let range = &data[old_size.min(args.offset)..old_size.min(write_end)];
+            if hash(range).to_bytes() != expected_hash {
+                return Err(MplInscriptionError::WritePreconditionFailed.into());
+            }
+        }
+    }
+
     // Resize the account to fit the new data if necessary.
     if write_end > old_size {
         resize_or_reallocate_account_raw(
@@ -123,12 +181,39 @@ pub(crate) fn process_write_data<'a>(
         )?;
     }
 
-    // Write the inscription metadata to the metadata account.
-    sol_memcpy(
-        &mut ctx.accounts.inscription_account.try_borrow_mut_data()?[args.offset..],
-        &args.value,
-        args.value.len(),
-    );
+    match ctx.accounts.staging_account {
+        Some(staging_account) => {
+            // Commit the staged data to the inscription account.
+            sol_memcpy(
+                &mut ctx.accounts.inscription_account.try_borrow_mut_data()?[args.offset..],
+                &staging_account.try_borrow_data()?[STAGING_HEADER_SIZE..],
+                value_len,
+            );
+
+            // The staging buffer is no longer needed once committed.
+            close_account_raw(ctx.accounts.payer, staging_account)?;
+        }
+        None => {
+            // Write the inscription metadata to the metadata account.
+            sol_memcpy(
+                &mut ctx.accounts.inscription_account.try_borrow_mut_data()?[args.offset..],
+                &args.value,
+                args.value.len(),
+            );
+        }
+    }
+
+    // Any change to the inscription's data invalidates its content hash commitment.
+    if ctx.accounts.inscription_account.key == &inscription_metadata.inscription_account
+        && inscription_metadata.invalidate_content_hash()
+    {
+        write_inscription_metadata(
+            ctx.accounts.inscription_metadata_account,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            &inscription_metadata,
+        )?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,183 @@
+use borsh::BorshDeserialize;
+use mpl_utils::{assert_signer, resize_or_reallocate_account_raw};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, program_memory::sol_memcpy, pubkey::Pubkey, system_program,
+    sysvar::Sysvar,
+};
+
+use super::commit_hash::write_inscription_metadata;
+use crate::{
+    error::MplInscriptionError,
+    instruction::{accounts::WriteSegmentsAccounts, WriteSegmentsArgs},
+    state::{AuthorityRole, InscriptionMetadata, ASSOCIATION, PREFIX},
+};
+
+pub(crate) fn process_write_segments<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: WriteSegmentsArgs,
+) -> ProgramResult {
+    let ctx = &WriteSegmentsAccounts::context(accounts)?;
+
+    // Check that the metadata account is already initialized.
+    if (ctx.accounts.inscription_metadata_account.owner != &crate::ID)
+        || ctx.accounts.inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+    let mut inscription_metadata = InscriptionMetadata::try_from_slice(
+        &ctx.accounts.inscription_metadata_account.data.borrow(),
+    )?;
+
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
+    // Verify that the derived address is correct for the metadata account.
+    let metadata_key = Pubkey::create_program_address(
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            inscription_metadata.inscription_account.as_ref(),
+            &[inscription_metadata.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| MplInscriptionError::DerivedKeyInvalid)?;
+    if metadata_key != *ctx.accounts.inscription_metadata_account.key {
+        return Err(MplInscriptionError::DerivedKeyInvalid.into());
+    }
+
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
+
+    assert_signer(ctx.accounts.payer)?;
+
+    // Write delegates can write until they expire.
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Writer)
+        && !inscription_metadata.is_write_delegate(authority.key, Clock::get()?.unix_timestamp)
+    {
+        return Err(MplInscriptionError::InvalidAuthority.into());
+    }
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplInscriptionError::InvalidSystemProgram.into());
+    }
+
+    // Resolve the target of each segment and the furthest end written in each target.
+    let mut targets: Vec<(&AccountInfo<'a>, usize)> = vec![];
+    let mut segment_targets = Vec::with_capacity(args.segments.len());
+    for segment in &args.segments {
+        let target_key = match &segment.associated_tag {
+            Some(tag) => {
+                // We don't allow empty tags.
+                if tag.is_empty() {
+                    return Err(MplInscriptionError::AssociationTagCannotBeBlank.into());
+                }
+
+                // A tag can't be greater than the seed size.
+                if tag.len() > 32 {
+                    return Err(MplInscriptionError::AssociationTagTooLong.into());
+                }
+
+                // Find the tag in the associated inscriptions and derive its address.
+                let associated_inscription = inscription_metadata
+                    .associated_inscriptions
+                    .iter()
+                    .find(|associated_inscription| associated_inscription.tag == *tag)
+                    .ok_or(MplInscriptionError::DerivedKeyInvalid)?;
+
+                Pubkey::create_program_address(
+                    &[
+                        PREFIX.as_bytes(),
+                        ASSOCIATION.as_bytes(),
+                        tag.as_bytes(),
+                        ctx.accounts.inscription_metadata_account.key.as_ref(),
+                        &[associated_inscription.bump],
+                    ],
+                    &crate::ID,
+                )
+                .map_err(|_| MplInscriptionError::DerivedKeyInvalid)?
+            }
+            None => inscription_metadata.inscription_account,
+        };
+
+        let write_end = segment
+            .offset
+            .checked_add(segment.value.len())
+            .ok_or(MplInscriptionError::NumericalOverflow)?;
+
+        let target_index = match targets
+            .iter()
+            .position(|(account, _)| *account.key == target_key)
+        {
+            Some(index) => {
+                targets[index].1 = targets[index].1.max(write_end);
+                index
+            }
+            None => {
+                // The inscription accounts are passed in the remaining accounts.
+                let account = ctx
+                    .remaining_accounts
+                    .iter()
+                    .find(|account| *account.key == target_key)
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+                // Check that the inscription account is already initialized.
+                if account.owner != &crate::ID {
+                    return Err(MplInscriptionError::NotInitialized.into());
+                }
+
+                targets.push((account, write_end));
+                targets.len() - 1
+            }
+        };
+
+        segment_targets.push(target_index);
+    }
+
+    // Resize each account once to fit the new data if necessary.
+    for (account, write_end) in &targets {
+        if *write_end > account.data_len() {
+            resize_or_reallocate_account_raw(
+                account,
+                ctx.accounts.payer,
+                ctx.accounts.system_program,
+                *write_end,
+            )?;
+        }
+    }
+
+    // Write each segment to its inscription account.
+    for (segment, target_index) in args.segments.iter().zip(segment_targets) {
+        sol_memcpy(
+            &mut targets[target_index].0.try_borrow_mut_data()?[segment.offset..],
+            &segment.value,
+            segment.value.len(),
+        );
+    }
+
+    // Any change to the inscription's data invalidates its content hash commitment.
+    if args
+        .segments
+        .iter()
+        .any(|segment| segment.associated_tag.is_none())
+        && inscription_metadata.invalidate_content_hash()
+    {
+        write_inscription_metadata(
+            ctx.accounts.inscription_metadata_account,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            &inscription_metadata,
+        )?;
+    }
+
+    Ok(())
+}
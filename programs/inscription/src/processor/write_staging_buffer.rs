@@ -0,0 +1,120 @@
+use borsh::BorshDeserialize;
+use mpl_utils::{
+    assert_derivation, assert_signer, create_or_allocate_account_raw,
+    resize_or_reallocate_account_raw,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+    system_program,
+};
+
+use crate::{
+    error::MplInscriptionError,
+    instruction::{accounts::WriteStagingBufferAccounts, WriteStagingBufferArgs},
+    state::{AuthorityRole, InscriptionMetadata, Key, PREFIX, STAGING, STAGING_HEADER_SIZE},
+};
+
+pub(crate) fn process_write_staging_buffer<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: WriteStagingBufferArgs,
+) -> ProgramResult {
+    let ctx = &WriteStagingBufferAccounts::context(accounts)?;
+
+    // Check that the metadata account is already initialized.
+    if (ctx.accounts.inscription_metadata_account.owner != &crate::ID)
+        || ctx.accounts.inscription_metadata_account.data_is_empty()
+    {
+        return Err(MplInscriptionError::NotInitialized.into());
+    }
+    let inscription_metadata = InscriptionMetadata::try_from_slice(
+        &ctx.accounts.inscription_metadata_account.data.borrow(),
+    )?;
+
+    // Frozen inscriptions can no longer be modified.
+    if inscription_metadata.frozen {
+        return Err(MplInscriptionError::InscriptionFrozen.into());
+    }
+
+    // The payer must sign as well as the authority, if present.
+    let authority = match ctx.accounts.authority {
+        Some(authority) => {
+            assert_signer(authority)?;
+            authority
+        }
+        None => ctx.accounts.payer,
+    };
+
+    assert_signer(ctx.accounts.payer)?;
+
+    if !inscription_metadata.has_role(authority.key, AuthorityRole::Writer) {
+        return Err(MplInscriptionError::InvalidAuthority.into());
+    }
+
+    if ctx.accounts.system_program.key != &system_program::ID {
+        return Err(MplInscriptionError::InvalidSystemProgram.into());
+    }
+
+    // Each authority has its own staging buffer for the inscription.
+    let bump = assert_derivation(
+        &crate::ID,
+        ctx.accounts.staging_account,
+        &[
+            PREFIX.as_bytes(),
+            STAGING.as_bytes(),
+            ctx.accounts.inscription_metadata_account.key.as_ref(),
+            authority.key.as_ref(),
+        ],
+        MplInscriptionError::DerivedKeyInvalid,
+    )?;
+
+    // Initialize the staging buffer if it doesn't exist yet.
+    if ctx.accounts.staging_account.owner == &system_program::ID {
+        create_or_allocate_account_raw(
+            crate::ID,
+            ctx.accounts.staging_account,
+            ctx.accounts.system_program,
+            ctx.accounts.payer,
+            STAGING_HEADER_SIZE,
+            &[
+                PREFIX.as_bytes(),
+                STAGING.as_bytes(),
+                ctx.accounts.inscription_metadata_account.key.as_ref(),
+                authority.key.as_ref(),
+                &[bump],
+            ],
+        )?;
+
+        // The key marks the account as a staging buffer so it can't pass as anything else.
+        ctx.accounts.staging_account.try_borrow_mut_data()?[0] = Key::StagingBufferAccount as u8;
+    } else if ctx.accounts.staging_account.owner != &crate::ID {
+        return Err(MplInscriptionError::IncorrectOwner.into());
+    }
+
+    // Staged data starts after the header.
+    let write_start = args
+        .offset
+        .checked_add(STAGING_HEADER_SIZE)
+        .ok_or(MplInscriptionError::NumericalOverflow)?;
+    let write_end = write_start
+        .checked_add(args.value.len())
+        .ok_or(MplInscriptionError::NumericalOverflow)?;
+
+    // Resize the account to fit the new data if necessary.
+    if write_end > ctx.accounts.staging_account.data_len() {
+        resize_or_reallocate_account_raw(
+            ctx.accounts.staging_account,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            write_end,
+        )?;
+    }
+
+    // Write the data to the staging buffer.
+    sol_memcpy(
+        &mut ctx.accounts.staging_account.try_borrow_mut_data()?[write_start..],
+        &args.value,
+        args.value.len(),
+    );
+
+    Ok(())
+}
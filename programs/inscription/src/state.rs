@@ -1,11 +1,15 @@
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::{maybestd::io, BorshDeserialize, BorshSerialize};
 use shank::ShankAccount;
-use solana_program::pubkey::Pubkey;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 pub const PREFIX: &str = "Inscription";
 pub const ASSOCIATION: &str = "Association";
 pub const SHARD_COUNT: u8 = 32;
 pub const SHARD_PREFIX: &str = "Shard";
+pub const STAGING: &str = "Staging";
+pub const STAGING_HEADER_SIZE: usize = 1;
+pub const RANK_PREFIX: &str = "Rank";
+pub const MAX_CONTENT_TYPE_LENGTH: usize = 64;
 
 #[repr(C)]
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
@@ -14,16 +18,34 @@ pub enum Key {
     InscriptionMetadataAccount,
     MintInscriptionMetadataAccount,
     InscriptionShardAccount,
+    InscriptionRankAccount,
+    InscriptionTombstoneAccount,
+    StagingBufferAccount,
 }
 
 #[repr(C)]
-#[derive(Clone, BorshSerialize, BorshDeserialize, Debug)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
 pub enum DataType {
     Uninitialized,
     Binary,
     Json,
 }
 
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum AuthorityRole {
+    Admin,
+    Writer,
+    Tagger,
+}
+
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug)]
+pub struct WriteDelegate {
+    pub address: Pubkey,
+    pub expires_at: i64,
+}
+
 #[repr(C)]
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug)]
 pub struct AssociatedInscription {
@@ -33,7 +55,14 @@ pub struct AssociatedInscription {
 }
 
 #[repr(C)]
-#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug)]
+pub struct AssociatedContentType {
+    pub tag: String,
+    pub content_type: String,
+}
+
+#[repr(C)]
+#[derive(Clone, BorshSerialize, Debug, ShankAccount)]
 pub struct InscriptionMetadata {
     pub key: Key,
     pub inscription_account: Pubkey,
@@ -44,7 +73,18 @@ pub struct InscriptionMetadata {
     pub update_authorities: Vec<Pubkey>,
     pub associated_inscriptions: Vec<AssociatedInscription>,
     pub mint: Option<Pubkey>,
-    pub _padding: [u8; 7],
+    pub frozen: bool,
+    pub _padding: [u8; 6],
+    // Fields below were added after the original layout and are missing from older accounts.
+    pub content_hash: Option<[u8; 32]>,
+    pub content_length: u64,
+    pub authority_roles: Vec<AuthorityRole>,
+    pub write_delegates: Vec<WriteDelegate>,
+    pub authority_threshold: u8,
+    pub mint_update_authority: Option<Pubkey>,
+    pub rent_payer: Option<Pubkey>,
+    pub associated_content_types: Vec<AssociatedContentType>,
+    pub mint_holder: Option<Pubkey>,
 }
 
 impl Default for InscriptionMetadata {
@@ -59,7 +99,168 @@ impl Default for InscriptionMetadata {
             update_authorities: vec![],
             associated_inscriptions: vec![],
             mint: None,
-            _padding: [0; 7],
+            frozen: false,
+            _padding: [0; 6],
+            content_hash: None,
+            content_length: 0,
+            authority_roles: vec![],
+            write_delegates: vec![],
+            authority_threshold: 0,
+            mint_update_authority: None,
+            rent_payer: None,
+            associated_content_types: vec![],
+            mint_holder: None,
+        }
+    }
+}
+
+impl BorshDeserialize for InscriptionMetadata {
+    fn deserialize(buf: &mut &[u8]) -> io::Result<Self> {
+        // Other program accounts, such as staging buffers, must never pass as metadata.
+        let key = Key::deserialize(buf)?;
+        if key != Key::InscriptionMetadataAccount && key != Key::MintInscriptionMetadataAccount {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Not an inscription metadata account",
+            ));
+        }
+
+        let mut metadata = Self {
+            key,
+            inscription_account: Pubkey::deserialize(buf)?,
+            bump: u8::deserialize(buf)?,
+            data_type: DataType::deserialize(buf)?,
+            inscription_rank: u64::deserialize(buf)?,
+            inscription_bump: Option::<u8>::deserialize(buf)?,
+            update_authorities: Vec::<Pubkey>::deserialize(buf)?,
+            associated_inscriptions: Vec::<AssociatedInscription>::deserialize(buf)?,
+            mint: Option::<Pubkey>::deserialize(buf)?,
+            frozen: bool::deserialize(buf)?,
+            _padding: <[u8; 6]>::deserialize(buf)?,
+            content_hash: deserialize_or_default(buf)?,
+            content_length: deserialize_or_default(buf)?,
+            authority_roles: deserialize_or_default(buf)?,
+            write_delegates: deserialize_or_default(buf)?,
+            authority_threshold: deserialize_or_default(buf)?,
+            mint_update_authority: deserialize_or_default(buf)?,
+            rent_payer: deserialize_or_default(buf)?,
+            associated_content_types: deserialize_or_default(buf)?,
+            mint_holder: deserialize_or_default(buf)?,
+        };
+
+        // Authorities from before roles existed are Admins.
+        if metadata.authority_roles.len() < metadata.update_authorities.len() {
+            metadata
+                .authority_roles
+                .resize(metadata.update_authorities.len(), AuthorityRole::Admin);
+        }
+
+        Ok(metadata)
+    }
+
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        Self::deserialize(&mut data.as_slice())
+    }
+}
+
+/// Read a field that was appended to an account layout, older accounts end before it.
+fn deserialize_or_default<T: BorshDeserialize + Default>(buf: &mut &[u8]) -> io::Result<T> {
+    if buf.is_empty() {
+        Ok(T::default())
+    } else {
+        T::deserialize(buf)
+    }
+}
+
+impl InscriptionMetadata {
+    /// Check that the authority holds the role, Admins hold every role.
+    /// Authorities without a recorded role are Admins, and the holder of the NFT is a Writer.
+    pub fn has_role(&self, authority: &Pubkey, role: AuthorityRole) -> bool {
+        if role == AuthorityRole::Writer && self.mint_holder == Some(*authority) {
+            return true;
+        }
+
+        self.update_authorities
+            .iter()
+            .enumerate()
+            .any(|(index, update_authority)| {
+                let authority_role = self
+                    .authority_roles
+                    .get(index)
+                    .unwrap_or(&AuthorityRole::Admin);
+                update_authority == authority
+                    && (*authority_role == AuthorityRole::Admin || *authority_role == role)
+            })
+    }
+
+    /// Check that the address is a write delegate that hasn't expired yet.
+    pub fn is_write_delegate(&self, address: &Pubkey, now: i64) -> bool {
+        self.write_delegates
+            .iter()
+            .any(|delegate| delegate.address == *address && delegate.expires_at > now)
+    }
+
+    /// Check that enough update authorities signed when a threshold is set.
+    pub fn meets_threshold<'a>(
+        &self,
+        authority: &AccountInfo<'a>,
+        remaining_accounts: &[AccountInfo<'a>],
+    ) -> bool {
+        if self.authority_threshold == 0 {
+            return true;
+        }
+
+        let signers = self
+            .update_authorities
+            .iter()
+            .filter(|update_authority| {
+                std::iter::once(authority)
+                    .chain(remaining_accounts.iter())
+                    .any(|account| account.is_signer && account.key == *update_authority)
+            })
+            .count();
+
+        signers >= self.authority_threshold as usize
+    }
+
+    /// Drop the content hash commitment, returning whether there was one to drop.
+    pub fn invalidate_content_hash(&mut self) -> bool {
+        if self.content_hash.is_none() {
+            return false;
+        }
+
+        self.content_hash = None;
+        self.content_length = 0;
+        true
+    }
+
+    /// Set or clear the content type of an associated inscription.
+    pub fn set_content_type(&mut self, tag: &str, content_type: Option<String>) {
+        self.associated_content_types
+            .retain(|associated| associated.tag != tag);
+        if let Some(content_type) = content_type {
+            self.associated_content_types.push(AssociatedContentType {
+                tag: tag.to_string(),
+                content_type,
+            });
+        }
+    }
+
+    /// Pick the account to refund rent to, which must be the recorded rent payer if there is one.
+    /// Only the rent payer itself can send the rent to another destination.
+    pub fn rent_destination<'a, 'b>(
+        &self,
+        payer: &'b AccountInfo<'a>,
+        rent_payer: Option<&'b AccountInfo<'a>>,
+        destination: Option<&'b AccountInfo<'a>>,
+    ) -> Option<&'b AccountInfo<'a>> {
+        match self.rent_payer {
+            Some(recorded) if payer.key != &recorded => {
+                rent_payer.filter(|account| account.key == &recorded)
+            }
+            _ => Some(destination.unwrap_or(payer)),
         }
     }
 }
@@ -83,3 +284,119 @@ impl Default for InscriptionShard {
         }
     }
 }
+
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct InscriptionRank {
+    pub key: Key,
+    pub bump: u8,
+    pub inscription_metadata_account: Pubkey,
+}
+
+impl Default for InscriptionRank {
+    fn default() -> Self {
+        Self {
+            key: Key::InscriptionRankAccount,
+            bump: 0,
+            inscription_metadata_account: Pubkey::default(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct InscriptionTombstone {
+    pub key: Key,
+    pub bump: u8,
+    pub inscription_account: Pubkey,
+    pub inscription_rank: u64,
+    pub closed_slot: u64,
+    pub content_hash: Option<[u8; 32]>,
+}
+
+impl Default for InscriptionTombstone {
+    fn default() -> Self {
+        Self {
+            key: Key::InscriptionTombstoneAccount,
+            bump: 0,
+            inscription_account: Pubkey::default(),
+            inscription_rank: 0,
+            closed_slot: 0,
+            content_hash: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The metadata layout written by the first release of the program.
+    #[derive(BorshSerialize)]
+    struct BaselineInscriptionMetadata {
+        key: Key,
+        inscription_account: Pubkey,
+        bump: u8,
+        data_type: DataType,
+        inscription_rank: u64,
+        inscription_bump: Option<u8>,
+        update_authorities: Vec<Pubkey>,
+        associated_inscriptions: Vec<AssociatedInscription>,
+        mint: Option<Pubkey>,
+        _padding: [u8; 7],
+    }
+
+    #[test]
+    fn deserialize_baseline_metadata() {
+        let authority = Pubkey::new_unique();
+        let baseline = BaselineInscriptionMetadata {
+            key: Key::MintInscriptionMetadataAccount,
+            inscription_account: Pubkey::new_unique(),
+            bump: 254,
+            data_type: DataType::Json,
+            inscription_rank: 42,
+            inscription_bump: Some(253),
+            update_authorities: vec![authority],
+            associated_inscriptions: vec![AssociatedInscription {
+                tag: "image".to_string(),
+                bump: 252,
+                data_type: DataType::Binary,
+            }],
+            mint: Some(Pubkey::new_unique()),
+            _padding: [0; 7],
+        };
+        let data = baseline.try_to_vec().unwrap();
+
+        let metadata = InscriptionMetadata::try_from_slice(&data).unwrap();
+
+        assert_eq!(metadata.key, Key::MintInscriptionMetadataAccount);
+        assert_eq!(metadata.inscription_account, baseline.inscription_account);
+        assert_eq!(metadata.inscription_rank, 42);
+        assert_eq!(metadata.inscription_bump, Some(253));
+        assert_eq!(metadata.update_authorities, vec![authority]);
+        assert_eq!(metadata.authority_roles, vec![AuthorityRole::Admin]);
+        assert!(metadata.has_role(&authority, AuthorityRole::Writer));
+        assert_eq!(metadata.associated_inscriptions[0].tag, "image");
+        assert_eq!(metadata.mint, baseline.mint);
+        assert!(!metadata.frozen);
+        assert_eq!(metadata.content_hash, None);
+        assert_eq!(metadata.authority_threshold, 0);
+        assert_eq!(metadata.rent_payer, None);
+
+        // Writing it back appends the new fields, which read back unchanged.
+        let mut metadata = metadata;
+        metadata.rent_payer = Some(authority);
+        let data = metadata.try_to_vec().unwrap();
+        assert!(data.len() > baseline.try_to_vec().unwrap().len());
+        let metadata = InscriptionMetadata::try_from_slice(&data).unwrap();
+        assert_eq!(metadata.rent_payer, Some(authority));
+    }
+
+    #[test]
+    fn reject_staging_buffer_as_metadata() {
+        let mut data = InscriptionMetadata::default().try_to_vec().unwrap();
+        data[0] = Key::StagingBufferAccount as u8;
+
+        assert!(InscriptionMetadata::try_from_slice(&data).is_err());
+    }
+}